@@ -0,0 +1,3 @@
+-- 사용자당 쿠폰 사용 횟수 제한 (NULL이면 무제한)
+ALTER TABLE coupons
+ADD COLUMN IF NOT EXISTS per_user_limit INT;
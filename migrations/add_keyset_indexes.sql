@@ -0,0 +1,3 @@
+-- 키셋(커서) 페이지네이션용 인덱스 - (created_at, id) 복합 정렬을 인덱스 스캔으로 처리
+CREATE INDEX IF NOT EXISTS idx_discount_infos_keyset ON discount_infos(created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_notifications_user_keyset ON notifications(user_id, created_at DESC, id DESC);
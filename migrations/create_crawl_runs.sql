@@ -0,0 +1,48 @@
+-- 크롤링 실행 기록과 페이지별 조건부 요청 상태
+-- crawl_runs: 수동/예약 크롤링 한 번의 시작/종료 시각, 읽은 페이지 수, 변경 수, 페이지 오류
+-- crawl_pages: 마지막으로 추출에 성공한 응답의 ETag/Last-Modified 와 추출 결과
+--              다음 크롤링에서 If-None-Match/If-Modified-Since 로 요청하고, 304 면 저장된 추출 결과를 다시 반영한다.
+-- (create_crawler_tables.sql 이후 실행)
+
+CREATE TABLE IF NOT EXISTS crawl_runs (
+    id BIGSERIAL PRIMARY KEY,
+    shop_id BIGINT NOT NULL REFERENCES shops(id) ON DELETE CASCADE,
+    trigger VARCHAR(20) NOT NULL DEFAULT 'manual' CHECK (trigger IN ('manual', 'scheduled')),
+    status VARCHAR(20) NOT NULL DEFAULT 'running' CHECK (status IN ('running', 'completed', 'failed')),
+    started_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    finished_at TIMESTAMPTZ,
+    pages_fetched INT NOT NULL DEFAULT 0,
+    pages_not_modified INT NOT NULL DEFAULT 0,  -- 304 응답
+    pages_skipped INT NOT NULL DEFAULT 0,       -- robots.txt 가 막은 페이지
+    products_discovered INT NOT NULL DEFAULT 0,
+    products_upserted INT NOT NULL DEFAULT 0,
+    products_created INT NOT NULL DEFAULT 0,
+    discounts_changed INT NOT NULL DEFAULT 0,
+    error_count INT NOT NULL DEFAULT 0,
+    errors JSONB NOT NULL DEFAULT '[]'::jsonb,  -- [{"url", "error"}] (앞부분만)
+    failure TEXT                                -- 실행 전체가 실패한 이유
+);
+
+CREATE INDEX IF NOT EXISTS idx_crawl_runs_shop ON crawl_runs(shop_id, started_at DESC, id DESC);
+
+-- 상점마다 실행 중(running) 기록은 하나만 - 서버를 다시 시작했거나 여러 대로 띄워도 같은 상점을 동시에 크롤링하지 않음
+-- 이미 겹쳐 있는 기록은 가장 최근 것만 남기고 failed 로 정리한 뒤 인덱스 생성
+UPDATE crawl_runs r
+SET status = 'failed', finished_at = NOW(), failure = 'Superseded by a newer running crawl'
+WHERE r.status = 'running'
+  AND EXISTS (SELECT 1 FROM crawl_runs n WHERE n.shop_id = r.shop_id AND n.status = 'running' AND n.id > r.id);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_crawl_runs_one_running ON crawl_runs(shop_id) WHERE status = 'running';
+
+CREATE TABLE IF NOT EXISTS crawl_pages (
+    id BIGSERIAL PRIMARY KEY,
+    shop_id BIGINT NOT NULL REFERENCES shops(id) ON DELETE CASCADE,
+    url TEXT NOT NULL,
+    page_type VARCHAR(20) NOT NULL CHECK (page_type IN ('listing', 'product')),
+    etag TEXT,
+    last_modified TEXT,
+    extracted JSONB,  -- product: ScrapedProduct, listing: {"product_urls", "next_page"}
+    last_status INT,
+    last_crawled_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (shop_id, url)
+);
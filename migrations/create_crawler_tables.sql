@@ -0,0 +1,139 @@
+-- 상점 크롤러
+-- 크롤러가 상품 페이지에서 추출한 이름/SKU/가격/할인가를 products 와 discount_infos 에 반영한다.
+-- 크롤러가 만든 할인은 is_auto_discovered = true 로 표시하며, 관리자가 등록한 할인은 건드리지 않는다.
+-- (create_basic_tables.sql 이후 실행)
+
+-- 크롤러가 상점별 추출 규칙을 고르는 기준 (엔티티에만 있고 테이블에 없던 컬럼)
+ALTER TABLE shops ADD COLUMN IF NOT EXISTS domain VARCHAR(255);
+ALTER TABLE shops ADD COLUMN IF NOT EXISTS platform VARCHAR(50) NOT NULL DEFAULT 'generic';
+
+UPDATE shops
+SET domain = regexp_replace(regexp_replace(lower(website_url), '^[a-z]+://(www\.)?', ''), '/.*$', '')
+WHERE domain IS NULL AND website_url IS NOT NULL;
+
+UPDATE shops SET domain = '' WHERE domain IS NULL;
+ALTER TABLE shops ALTER COLUMN domain SET DEFAULT '';
+ALTER TABLE shops ALTER COLUMN domain SET NOT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_products_shop_url ON products(shop_id, original_url);
+CREATE INDEX IF NOT EXISTS idx_products_shop_sku ON products(shop_id, sku);
+CREATE INDEX IF NOT EXISTS idx_discount_infos_auto_discovered
+    ON discount_infos(product_id) WHERE is_auto_discovered AND is_active;
+
+-- 크롤링한 상품 하나를 반영
+-- - 상품: 같은 상점에서 SKU, 없으면 original_url 로 찾아 갱신하고 없으면 생성
+-- - 할인: p_sale_price 가 있으면 진행 중인 자동 발견 할인을 만들거나 가격을 갱신하고 종료일을 p_valid_days 뒤로 연장,
+--         없으면(할인이 끝남) 진행 중인 자동 발견 할인을 비활성화
+-- 같은 상품을 동시에 크롤링해도 상품/할인이 중복 생성되지 않도록 (상점, SKU/URL) 단위 advisory lock 을 건다.
+--
+-- 반환값 (JSONB):
+--   {"product_id", "product_created", "discount_id", "discount_change": "created" | "updated" | "extended" | "ended" | "none"}
+CREATE OR REPLACE FUNCTION upsert_crawled_product(
+    p_shop_id BIGINT,
+    p_url TEXT,
+    p_name TEXT,
+    p_sku TEXT,
+    p_image_url TEXT,
+    p_price NUMERIC,
+    p_sale_price NUMERIC,
+    p_currency TEXT,
+    p_valid_days INT DEFAULT 7
+)
+RETURNS JSONB AS $$
+DECLARE
+    v_product_id BIGINT;
+    v_product_created BOOLEAN := false;
+    v_discount discount_infos%ROWTYPE;
+    v_discount_id BIGINT;
+    v_change TEXT := 'none';
+    v_end_at TIMESTAMPTZ := NOW() + make_interval(days => p_valid_days);
+BEGIN
+    PERFORM pg_advisory_xact_lock(hashtextextended(p_shop_id || ':' || COALESCE(NULLIF(p_sku, ''), p_url), 0));
+
+    IF NULLIF(p_sku, '') IS NOT NULL THEN
+        SELECT id INTO v_product_id
+        FROM products
+        WHERE shop_id = p_shop_id AND sku = p_sku AND NOT COALESCE(is_deleted, false)
+        ORDER BY id
+        LIMIT 1;
+    END IF;
+
+    IF v_product_id IS NULL THEN
+        SELECT id INTO v_product_id
+        FROM products
+        WHERE shop_id = p_shop_id AND original_url = p_url AND NOT COALESCE(is_deleted, false)
+        ORDER BY id
+        LIMIT 1;
+    END IF;
+
+    IF v_product_id IS NULL THEN
+        INSERT INTO products (shop_id, name, sku, original_url, image_url)
+        VALUES (p_shop_id, p_name, NULLIF(p_sku, ''), p_url, p_image_url)
+        RETURNING id INTO v_product_id;
+        v_product_created := true;
+    ELSE
+        UPDATE products
+        SET name = p_name,
+            sku = COALESCE(NULLIF(p_sku, ''), sku),
+            original_url = p_url,
+            image_url = COALESCE(p_image_url, image_url)
+        WHERE id = v_product_id;
+    END IF;
+
+    SELECT * INTO v_discount
+    FROM discount_infos
+    WHERE product_id = v_product_id AND is_auto_discovered AND is_active AND end_at > NOW()
+    ORDER BY id DESC
+    LIMIT 1
+    FOR UPDATE;
+
+    IF p_sale_price IS NOT NULL AND p_sale_price < p_price THEN
+        IF v_discount.id IS NULL THEN
+            INSERT INTO discount_infos (
+                product_id, shop_id, original_price, discount_price, discount_rate, currency,
+                start_at, end_at, is_active, source_url, is_auto_discovered
+            )
+            VALUES (
+                v_product_id, p_shop_id, p_price, p_sale_price, ROUND((1 - p_sale_price / p_price) * 100, 2), p_currency,
+                NOW(), v_end_at, true, p_url, true
+            )
+            RETURNING id INTO v_discount_id;
+            v_change := 'created';
+        ELSE
+            v_discount_id := v_discount.id;
+            v_change := CASE
+                WHEN v_discount.original_price <> p_price OR v_discount.discount_price <> p_sale_price THEN 'updated'
+                ELSE 'extended'
+            END;
+
+            UPDATE discount_infos
+            SET original_price = p_price,
+                discount_price = p_sale_price,
+                discount_rate = ROUND((1 - p_sale_price / p_price) * 100, 2),
+                currency = p_currency,
+                end_at = GREATEST(end_at, v_end_at),
+                source_url = p_url
+            WHERE id = v_discount.id;
+        END IF;
+    ELSIF v_discount.id IS NOT NULL THEN
+        UPDATE discount_infos
+        SET is_active = false, end_at = LEAST(end_at, NOW())
+        WHERE id = v_discount.id;
+        v_discount_id := v_discount.id;
+        v_change := 'ended';
+    END IF;
+
+    RETURN jsonb_build_object(
+        'product_id', v_product_id,
+        'product_created', v_product_created,
+        'discount_id', v_discount_id,
+        'discount_change', v_change
+    );
+END;
+$$ LANGUAGE plpgsql SECURITY DEFINER SET search_path = public;
+
+-- 크롤러(service_role)에서만 실행 허용
+REVOKE EXECUTE ON FUNCTION upsert_crawled_product(BIGINT, TEXT, TEXT, TEXT, TEXT, NUMERIC, NUMERIC, TEXT, INT) FROM PUBLIC;
+-- anon/authenticated 는 Supabase 기본 권한으로 직접 실행 권한을 받으므로 PUBLIC 회수만으로는 막히지 않음
+REVOKE EXECUTE ON FUNCTION upsert_crawled_product(BIGINT, TEXT, TEXT, TEXT, TEXT, NUMERIC, NUMERIC, TEXT, INT) FROM anon, authenticated;
+GRANT EXECUTE ON FUNCTION upsert_crawled_product(BIGINT, TEXT, TEXT, TEXT, TEXT, NUMERIC, NUMERIC, TEXT, INT) TO service_role;
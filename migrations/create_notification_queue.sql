@@ -0,0 +1,42 @@
+-- 알림 발송 큐 / 발송 로그
+-- notifications 에 저장된 알림을 채널(push, email, sms)별로 한 행씩 큐에 넣고,
+-- 서버의 백그라운드 워커가 DATABASE_URL 직접 연결(sqlx)로 FOR UPDATE SKIP LOCKED 클레임 후 발송한다.
+-- 실패하면 지수 백오프로 next_attempt_at 을 미루고, max_attempts 를 넘기면 failed 로 종료한다.
+-- 시도 결과는 매번 notification_logs 에 남긴다 (sent / pending: 재시도 예정 / failed).
+-- (fix_notifications_table.sql 이후 실행)
+CREATE TABLE IF NOT EXISTS notification_queue (
+    id BIGSERIAL PRIMARY KEY,
+    notification_id BIGINT NOT NULL REFERENCES notifications(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES auth.users(id) ON DELETE CASCADE,
+    channel VARCHAR(20) NOT NULL, -- push, email, sms
+    status VARCHAR(20) NOT NULL DEFAULT 'pending', -- pending, processing, sent, failed
+    attempts INT NOT NULL DEFAULT 0,
+    max_attempts INT NOT NULL DEFAULT 5,
+    -- 다음 시도 시각 (processing 상태에서는 클레임 만료 시각 - 워커가 죽으면 이후 다시 클레임됨)
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_error TEXT,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW(),
+    UNIQUE(notification_id, channel)
+);
+
+CREATE INDEX IF NOT EXISTS idx_notification_queue_due
+    ON notification_queue(next_attempt_at, id) WHERE status IN ('pending', 'processing');
+
+CREATE TABLE IF NOT EXISTS notification_logs (
+    id BIGSERIAL PRIMARY KEY,
+    queue_id BIGINT REFERENCES notification_queue(id) ON DELETE SET NULL,
+    user_id UUID NOT NULL REFERENCES auth.users(id) ON DELETE CASCADE,
+    notification_type VARCHAR(50) NOT NULL,
+    channel VARCHAR(20) NOT NULL,
+    title VARCHAR(500) NOT NULL,
+    content TEXT NOT NULL,
+    status VARCHAR(20) NOT NULL, -- sent, failed, pending
+    attempt INT NOT NULL,
+    error_message TEXT,
+    sent_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_notification_logs_user ON notification_logs(user_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_notification_logs_queue ON notification_logs(queue_id);
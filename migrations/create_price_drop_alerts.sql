@@ -0,0 +1,180 @@
+-- 가격 인하 알림 매칭
+-- discount_infos 가 생성되거나 할인가 인하/할인율 상승/재활성화로 변경되면 discount_change_events 에 기록하고,
+-- 서버의 백그라운드 매처가 claim_price_drop_alerts() 로 가져가 구독자에게 알림을 만든다.
+-- 같은 할인에 대해 한 사용자는 한 번만 알림을 받는다 (price_drop_alert_deliveries).
+-- (create_basic_tables.sql 이후 실행)
+
+-- 상품 구독 테이블 (엔티티만 있고 테이블이 없던 부분)
+CREATE TABLE IF NOT EXISTS product_subscriptions (
+    id BIGSERIAL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES auth.users(id) ON DELETE CASCADE,
+    product_id BIGINT NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    UNIQUE(user_id, product_id)
+);
+
+-- 카테고리 구독 최소 할인율 (NULL이면 모든 할인)
+ALTER TABLE category_subscriptions ADD COLUMN IF NOT EXISTS min_discount_rate DECIMAL(5,2);
+
+CREATE TABLE IF NOT EXISTS discount_change_events (
+    id BIGSERIAL PRIMARY KEY,
+    discount_info_id BIGINT NOT NULL REFERENCES discount_infos(id) ON DELETE CASCADE,
+    change_type VARCHAR(20) NOT NULL, -- created, price_drop, reactivated
+    previous_price DECIMAL(12,2),
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    processed_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_discount_change_events_pending
+    ON discount_change_events(created_at, id) WHERE processed_at IS NULL;
+
+CREATE TABLE IF NOT EXISTS price_drop_alert_deliveries (
+    id BIGSERIAL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES auth.users(id) ON DELETE CASCADE,
+    discount_info_id BIGINT NOT NULL REFERENCES discount_infos(id) ON DELETE CASCADE,
+    matched_by VARCHAR(20) NOT NULL, -- product, brand, shop, category
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    UNIQUE(user_id, discount_info_id)
+);
+
+CREATE OR REPLACE FUNCTION record_discount_change_event()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        INSERT INTO discount_change_events (discount_info_id, change_type)
+        VALUES (NEW.id, 'created');
+    ELSIF NEW.discount_price < OLD.discount_price OR NEW.discount_rate > OLD.discount_rate THEN
+        INSERT INTO discount_change_events (discount_info_id, change_type, previous_price)
+        VALUES (NEW.id, 'price_drop', OLD.discount_price);
+    ELSIF NEW.is_active AND NOT COALESCE(OLD.is_active, false) THEN
+        INSERT INTO discount_change_events (discount_info_id, change_type)
+        VALUES (NEW.id, 'reactivated');
+    END IF;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS record_discount_change ON discount_infos;
+CREATE TRIGGER record_discount_change
+    AFTER INSERT OR UPDATE OF discount_price, discount_rate, is_active ON discount_infos
+    FOR EACH ROW EXECUTE FUNCTION record_discount_change_event();
+
+-- 대기 중인 변경 이벤트를 가져가 구독자를 매칭하고 발송 기록을 남긴다.
+-- - 아직 시작 전인 할인의 이벤트는 시작될 때까지 대기
+-- - 종료/비활성 할인의 이벤트는 알림 없이 처리 완료
+-- - 매칭: 상품 > 브랜드 > 매장 > 카테고리(상위 카테고리 구독 포함, min_discount_rate 충족) 순으로 하나만
+-- - notification_settings 의 'discount' 또는 매칭된 구독 종류 설정이 꺼져 있으면 제외
+-- 여러 서버가 동시에 실행해도 SKIP LOCKED 와 발송 기록 UNIQUE 제약으로 중복 알림이 생기지 않는다.
+--
+-- 반환값 (JSONB 배열):
+--   [{"user_id", "discount_info_id", "product_id", "product_name", "matched_by", "change_type",
+--     "original_price", "discount_price", "previous_price", "discount_rate", "currency", "end_at"}]
+CREATE OR REPLACE FUNCTION claim_price_drop_alerts(p_limit INT DEFAULT 100)
+RETURNS JSONB
+LANGUAGE sql
+SECURITY DEFINER
+SET search_path = public
+AS $$
+    WITH RECURSIVE picked AS (
+        SELECT e.id, e.discount_info_id, e.change_type, e.previous_price
+        FROM discount_change_events e
+        JOIN discount_infos di ON di.id = e.discount_info_id
+        WHERE e.processed_at IS NULL
+          AND (di.start_at <= NOW() OR NOT COALESCE(di.is_active, false) OR di.end_at < NOW())
+        ORDER BY e.created_at, e.id
+        LIMIT p_limit
+        FOR UPDATE OF e SKIP LOCKED
+    ),
+    processed AS (
+        UPDATE discount_change_events e
+        SET processed_at = NOW()
+        FROM picked
+        WHERE e.id = picked.id
+        RETURNING e.id
+    ),
+    live AS (
+        -- 진행 중인 할인만 알림 대상 (같은 할인의 이벤트가 여러 개면 가장 최근 것 기준)
+        SELECT DISTINCT ON (di.id)
+            di.id, di.product_id, di.shop_id, COALESCE(di.brand_id, pr.brand_id) AS brand_id, pr.category_id,
+            pr.name AS product_name, di.original_price, di.discount_price, di.discount_rate, di.currency, di.end_at,
+            p.change_type, p.previous_price
+        FROM picked p
+        JOIN discount_infos di ON di.id = p.discount_info_id
+        JOIN products pr ON pr.id = di.product_id
+        WHERE di.is_active
+          AND NOW() BETWEEN di.start_at AND di.end_at
+          AND NOT pr.is_deleted
+        ORDER BY di.id, p.id DESC
+    ),
+    category_path AS (
+        -- 상품 카테고리와 그 상위 카테고리들
+        SELECT l.id AS discount_info_id, l.category_id, 0 AS depth
+        FROM live l
+        WHERE l.category_id IS NOT NULL
+        UNION ALL
+        SELECT cp.discount_info_id, c.parent_id, cp.depth + 1
+        FROM category_path cp
+        JOIN categories c ON c.id = cp.category_id
+        WHERE c.parent_id IS NOT NULL AND cp.depth < 10
+    ),
+    candidates AS (
+        SELECT ps.user_id, l.id AS discount_info_id, 'product' AS matched_by, 1 AS priority
+        FROM live l
+        JOIN product_subscriptions ps ON ps.product_id = l.product_id
+        UNION ALL
+        SELECT bs.user_id, l.id, 'brand', 2
+        FROM live l
+        JOIN brand_subscriptions bs ON bs.brand_id = l.brand_id
+        WHERE COALESCE(bs.is_active, true)
+        UNION ALL
+        SELECT ss.user_id, l.id, 'shop', 3
+        FROM live l
+        JOIN shop_subscriptions ss ON ss.shop_id = l.shop_id
+        WHERE COALESCE(ss.is_active, true)
+        UNION ALL
+        SELECT cs.user_id, l.id, 'category', 4
+        FROM live l
+        JOIN category_path cp ON cp.discount_info_id = l.id
+        JOIN category_subscriptions cs ON cs.category_id = cp.category_id
+        WHERE COALESCE(cs.is_active, true)
+          AND (cs.min_discount_rate IS NULL OR l.discount_rate >= cs.min_discount_rate)
+    ),
+    matched AS (
+        SELECT DISTINCT ON (c.user_id, c.discount_info_id) c.user_id, c.discount_info_id, c.matched_by
+        FROM candidates c
+        WHERE NOT EXISTS (
+            SELECT 1 FROM notification_settings ns
+            WHERE ns.user_id = c.user_id
+              AND ns.setting_type IN ('discount', c.matched_by)
+              AND NOT ns.is_enabled
+        )
+        ORDER BY c.user_id, c.discount_info_id, c.priority
+    ),
+    delivered AS (
+        INSERT INTO price_drop_alert_deliveries (user_id, discount_info_id, matched_by)
+        SELECT user_id, discount_info_id, matched_by FROM matched
+        ON CONFLICT (user_id, discount_info_id) DO NOTHING
+        RETURNING user_id, discount_info_id, matched_by
+    )
+    SELECT COALESCE(jsonb_agg(jsonb_build_object(
+        'user_id', d.user_id,
+        'discount_info_id', l.id,
+        'product_id', l.product_id,
+        'product_name', l.product_name,
+        'matched_by', d.matched_by,
+        'change_type', l.change_type,
+        'original_price', l.original_price,
+        'discount_price', l.discount_price,
+        'previous_price', l.previous_price,
+        'discount_rate', l.discount_rate,
+        'currency', l.currency,
+        'end_at', l.end_at
+    ) ORDER BY l.id, d.user_id), '[]'::jsonb)
+    FROM delivered d
+    JOIN live l ON l.id = d.discount_info_id;
+$$;
+
+REVOKE EXECUTE ON FUNCTION claim_price_drop_alerts(INT) FROM PUBLIC;
+REVOKE EXECUTE ON FUNCTION claim_price_drop_alerts(INT) FROM anon, authenticated;
+GRANT EXECUTE ON FUNCTION claim_price_drop_alerts(INT) TO service_role;
@@ -0,0 +1,106 @@
+-- 상품 가격 이력
+-- discount_infos 의 할인가/정가가 바뀔 때마다(INSERT 포함) 한 행씩 추가한다.
+-- 직전에 기록된 상품 가격과 같으면 기록하지 않는다.
+CREATE TABLE IF NOT EXISTS price_history (
+    id BIGSERIAL PRIMARY KEY,
+    product_id BIGINT NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    discount_info_id BIGINT REFERENCES discount_infos(id) ON DELETE SET NULL,
+    original_price DECIMAL(12,2) NOT NULL,
+    price DECIMAL(12,2) NOT NULL,
+    currency VARCHAR(10) DEFAULT 'KRW',
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_price_history_product ON price_history(product_id, recorded_at DESC, id DESC);
+
+CREATE OR REPLACE FUNCTION record_price_history()
+RETURNS TRIGGER AS $$
+DECLARE
+    v_last price_history%ROWTYPE;
+BEGIN
+    SELECT * INTO v_last
+    FROM price_history
+    WHERE product_id = NEW.product_id
+    ORDER BY recorded_at DESC, id DESC
+    LIMIT 1;
+
+    IF NOT FOUND
+       OR v_last.price IS DISTINCT FROM NEW.discount_price
+       OR v_last.original_price IS DISTINCT FROM NEW.original_price
+    THEN
+        INSERT INTO price_history (product_id, discount_info_id, original_price, price, currency)
+        VALUES (NEW.product_id, NEW.id, NEW.original_price, NEW.discount_price, NEW.currency);
+    END IF;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS record_discount_price_history ON discount_infos;
+CREATE TRIGGER record_discount_price_history
+    AFTER INSERT OR UPDATE OF discount_price, original_price ON discount_infos
+    FOR EACH ROW EXECUTE FUNCTION record_price_history();
+
+-- 기존 할인 정보로 초기 이력 생성 (이력이 없는 상품만)
+INSERT INTO price_history (product_id, discount_info_id, original_price, price, currency, recorded_at)
+SELECT di.product_id, di.id, di.original_price, di.discount_price, di.currency, COALESCE(di.created_at, NOW())
+FROM discount_infos di
+WHERE NOT EXISTS (SELECT 1 FROM price_history ph WHERE ph.product_id = di.product_id);
+
+-- 가격 요약
+-- 현재가는 진행 중인 할인 중 가장 낮은 할인가이며, 진행 중인 할인이 없으면 NULL.
+-- 기록된 가격은 다음 기록 또는 해당 할인 종료 시점까지 유지된 것으로 본다.
+-- lowest_in_days: 현재가보다 낮은 가격이 마지막으로 유지되던 시점 이후 경과 일수
+--                 (더 낮은 가격이 없었다면 첫 기록 이후 경과 일수, 현재가가 없으면 NULL)
+--
+-- 반환값 (JSONB):
+--   {"current_price": 70000, "all_time_low": 65000, "low_30d": 70000, "low_90d": 65000,
+--    "is_all_time_low": false, "lowest_in_days": 45}
+CREATE OR REPLACE FUNCTION product_price_summary(p_product_id BIGINT)
+RETURNS JSONB AS $$
+    WITH current_price AS (
+        SELECT MIN(discount_price) AS price
+        FROM discount_infos
+        WHERE product_id = p_product_id
+          AND is_active
+          AND NOW() BETWEEN start_at AND end_at
+    ),
+    history AS (
+        -- 각 가격이 유지된 구간 (다음 기록 또는 해당 할인 종료 전까지)
+        SELECT
+            ph.price,
+            ph.recorded_at,
+            LEAST(
+                LEAD(ph.recorded_at) OVER (ORDER BY ph.recorded_at, ph.id),
+                di.end_at
+            ) AS ended_at
+        FROM price_history ph
+        LEFT JOIN discount_infos di ON di.id = ph.discount_info_id
+        WHERE ph.product_id = p_product_id
+    ),
+    stats AS (
+        SELECT
+            MIN(price) AS all_time_low,
+            MIN(price) FILTER (WHERE COALESCE(ended_at, NOW()) >= NOW() - INTERVAL '30 days') AS low_30d,
+            MIN(price) FILTER (WHERE COALESCE(ended_at, NOW()) >= NOW() - INTERVAL '90 days') AS low_90d,
+            MIN(recorded_at) AS first_recorded_at
+        FROM history
+    ),
+    last_lower AS (
+        SELECT MAX(COALESCE(h.ended_at, NOW())) AS ended_at
+        FROM history h, current_price c
+        WHERE h.price < c.price
+    )
+    SELECT jsonb_build_object(
+        'current_price', c.price,
+        'all_time_low', s.all_time_low,
+        'low_30d', s.low_30d,
+        'low_90d', s.low_90d,
+        'is_all_time_low', COALESCE(c.price <= s.all_time_low, false),
+        'lowest_in_days', CASE
+            WHEN c.price IS NULL THEN NULL
+            ELSE EXTRACT(DAY FROM NOW() - COALESCE(l.ended_at, s.first_recorded_at, NOW()))::INT
+        END
+    )
+    FROM current_price c, stats s, last_lower l;
+$$ LANGUAGE sql STABLE;
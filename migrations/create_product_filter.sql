@@ -0,0 +1,177 @@
+-- 상품 목록 패싯 필터
+-- 매장, 브랜드, 카테고리(하위 카테고리 포함 가능), 할인율 범위, 진행 중 할인 여부를 조합해 필터링하고
+-- 각 차원별 패싯 카운트를 함께 반환한다.
+-- country 가 주어지면 shipping_regions 기준으로 해당 국가에 배송되는 매장의 상품만 대상으로 하며,
+-- discount_shipping 으로 지역이 제한된 할인은 그 국가에 해당할 때만 현재 할인율에 반영한다.
+-- (create_shipping_tables.sql 이후 실행)
+-- 패싯 카운트는 해당 차원을 제외한 나머지 필터만 적용한 값이다
+-- (예: brand_id=3 으로 필터링해도 brands 패싯에는 다른 브랜드의 개수가 함께 표시됨).
+--
+-- p_filter (JSONB, 모든 키 선택):
+--   {"country": "KR", "shop_id": 1, "brand_id": 2, "category_id": 3, "include_subcategories": true,
+--    "min_discount_rate": 10, "max_discount_rate": 50, "has_discount": true}
+--
+-- p_sort (JSONB 배열, 앞선 키가 우선):
+--   [{"field": "current_discount_rate", "direction": "desc"}, {"field": "name", "direction": "asc"}]
+--   허용 필드 외의 키는 무시하며, 마지막에 created_at DESC, id DESC 로 순서를 고정한다.
+--
+-- 반환값 (JSONB):
+--   {"total": 123,
+--    "items": [{...products row..., "current_discount_rate": 30.00}, ...],
+--    "facets": {"shops": [{"id", "name", "count"}], "brands": [...], "categories": [...],
+--               "discount": {"with_discount", "without_discount"},
+--               "discount_rates": [{"min", "max", "count"}]}}
+CREATE INDEX IF NOT EXISTS idx_categories_parent ON categories(parent_id);
+
+-- 정렬 파라미터 추가로 시그니처가 바뀌었으므로 이전 버전 제거 (PostgREST 오버로드 모호성 방지)
+DROP FUNCTION IF EXISTS filter_products(JSONB, INT, INT);
+
+CREATE OR REPLACE FUNCTION filter_products(
+    p_filter JSONB,
+    p_sort JSONB DEFAULT '[]'::jsonb,
+    p_limit INT DEFAULT 20,
+    p_offset INT DEFAULT 0
+)
+RETURNS JSONB AS $$
+DECLARE
+    v_order TEXT;
+    v_result JSONB;
+BEGIN
+    -- 정렬 컬럼은 동적 SQL로 들어가므로 화이트리스트로 한 번 더 제한
+    SELECT string_agg(
+        format('%I %s NULLS LAST', s->>'field', CASE WHEN s->>'direction' = 'asc' THEN 'ASC' ELSE 'DESC' END),
+        ', ' ORDER BY ord
+    )
+    INTO v_order
+    FROM jsonb_array_elements(COALESCE(p_sort, '[]'::jsonb)) WITH ORDINALITY AS t(s, ord)
+    WHERE s->>'field' IN ('created_at', 'updated_at', 'name', 'click_count', 'current_discount_rate', 'id');
+
+    v_order := COALESCE(v_order || ', ', '') || 'created_at DESC, id DESC';
+
+    EXECUTE format($query$
+        WITH RECURSIVE category_scope AS (
+            SELECT id FROM categories WHERE id = ($1->>'category_id')::BIGINT
+            UNION
+            SELECT c.id
+            FROM categories c
+            JOIN category_scope s ON c.parent_id = s.id
+            WHERE COALESCE(($1->>'include_subcategories')::BOOLEAN, false)
+        ),
+        base AS (
+            -- 진행 중인 할인 중 가장 높은 할인율을 상품의 현재 할인율로 사용
+            SELECT p.*, d.max_rate AS current_discount_rate
+            FROM products p
+            LEFT JOIN LATERAL (
+                SELECT MAX(di.discount_rate) AS max_rate
+                FROM discount_infos di
+                WHERE di.product_id = p.id
+                  AND di.is_active
+                  AND NOW() BETWEEN di.start_at AND di.end_at
+                  AND (
+                      $1->>'country' IS NULL
+                      OR NOT EXISTS (SELECT 1 FROM discount_shipping ds WHERE ds.discount_info_id = di.id)
+                      OR EXISTS (
+                          SELECT 1
+                          FROM discount_shipping ds
+                          JOIN shipping_regions sr ON sr.id = ds.shipping_region_id
+                          WHERE ds.discount_info_id = di.id AND sr.country_code = $1->>'country'
+                      )
+                  )
+            ) d ON true
+            WHERE NOT p.is_deleted
+              AND (
+                  $1->>'country' IS NULL
+                  OR EXISTS (
+                      SELECT 1 FROM shipping_regions sr
+                      WHERE sr.shop_id = p.shop_id AND sr.country_code = $1->>'country'
+                  )
+              )
+        ),
+        flagged AS (
+            SELECT
+                b.*,
+                ($1->>'shop_id' IS NULL OR b.shop_id = ($1->>'shop_id')::BIGINT) AS m_shop,
+                ($1->>'brand_id' IS NULL OR b.brand_id IS NOT DISTINCT FROM ($1->>'brand_id')::BIGINT) AS m_brand,
+                ($1->>'category_id' IS NULL OR b.category_id IN (SELECT id FROM category_scope)) AS m_category,
+                COALESCE(
+                    ($1->>'min_discount_rate' IS NULL OR b.current_discount_rate >= ($1->>'min_discount_rate')::NUMERIC)
+                    AND ($1->>'max_discount_rate' IS NULL OR b.current_discount_rate <= ($1->>'max_discount_rate')::NUMERIC),
+                    false
+                ) AS m_rate,
+                ($1->>'has_discount' IS NULL OR (b.current_discount_rate IS NOT NULL) = ($1->>'has_discount')::BOOLEAN) AS m_discount
+            FROM base b
+        ),
+        matched AS (
+            SELECT * FROM flagged
+            WHERE m_shop AND m_brand AND m_category AND m_rate AND m_discount
+        ),
+        page AS (
+            SELECT * FROM (
+                SELECT m.*, ROW_NUMBER() OVER (ORDER BY %s) AS sort_rank
+                FROM matched m
+            ) ranked
+            WHERE sort_rank > $3 AND sort_rank <= $3 + $2
+        )
+        SELECT jsonb_build_object(
+            'total', (SELECT COUNT(*) FROM matched),
+            'items', COALESCE((
+                SELECT jsonb_agg(
+                    to_jsonb(page) - 'm_shop' - 'm_brand' - 'm_category' - 'm_rate' - 'm_discount' - 'sort_rank'
+                    ORDER BY page.sort_rank
+                )
+                FROM page
+            ), '[]'::jsonb),
+            'facets', jsonb_build_object(
+                'shops', COALESCE((
+                    SELECT jsonb_agg(jsonb_build_object('id', s.id, 'name', s.name, 'count', f.cnt) ORDER BY f.cnt DESC, s.id)
+                    FROM (
+                        SELECT shop_id, COUNT(*) AS cnt FROM flagged
+                        WHERE m_brand AND m_category AND m_rate AND m_discount
+                        GROUP BY shop_id
+                    ) f
+                    JOIN shops s ON s.id = f.shop_id
+                ), '[]'::jsonb),
+                'brands', COALESCE((
+                    SELECT jsonb_agg(jsonb_build_object('id', br.id, 'name', br.name, 'count', f.cnt) ORDER BY f.cnt DESC, br.id)
+                    FROM (
+                        SELECT brand_id, COUNT(*) AS cnt FROM flagged
+                        WHERE m_shop AND m_category AND m_rate AND m_discount AND brand_id IS NOT NULL
+                        GROUP BY brand_id
+                    ) f
+                    JOIN brands br ON br.id = f.brand_id
+                ), '[]'::jsonb),
+                'categories', COALESCE((
+                    SELECT jsonb_agg(jsonb_build_object('id', c.id, 'name', c.name, 'count', f.cnt) ORDER BY f.cnt DESC, c.id)
+                    FROM (
+                        SELECT category_id, COUNT(*) AS cnt FROM flagged
+                        WHERE m_shop AND m_brand AND m_rate AND m_discount AND category_id IS NOT NULL
+                        GROUP BY category_id
+                    ) f
+                    JOIN categories c ON c.id = f.category_id
+                ), '[]'::jsonb),
+                'discount', (
+                    SELECT jsonb_build_object(
+                        'with_discount', COUNT(*) FILTER (WHERE current_discount_rate IS NOT NULL),
+                        'without_discount', COUNT(*) FILTER (WHERE current_discount_rate IS NULL)
+                    )
+                    FROM flagged
+                    WHERE m_shop AND m_brand AND m_category AND m_rate
+                ),
+                'discount_rates', (
+                    SELECT jsonb_agg(jsonb_build_object('min', r.min_rate, 'max', r.max_rate, 'count', (
+                        SELECT COUNT(*) FROM flagged
+                        WHERE m_shop AND m_brand AND m_category AND m_discount
+                          AND current_discount_rate >= r.min_rate
+                          AND (r.max_rate IS NULL OR current_discount_rate < r.max_rate)
+                    )) ORDER BY r.min_rate)
+                    FROM (VALUES (0, 10), (10, 30), (30, 50), (50, NULL)) AS r(min_rate, max_rate)
+                )
+            )
+        )
+    $query$, v_order)
+    INTO v_result
+    USING p_filter, p_limit, p_offset;
+
+    RETURN v_result;
+END;
+$$ LANGUAGE plpgsql STABLE;
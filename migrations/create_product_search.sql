@@ -0,0 +1,63 @@
+-- 상품 검색 (trigram + full-text)
+-- products.name / sku 와 요청 언어의 product_translations.name / description 을 대상으로
+-- 부분 일치(ILIKE), 유사도(pg_trgm), 전문 검색(tsvector)을 조합해 관련도 순으로 반환한다.
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX IF NOT EXISTS idx_products_name_trgm ON products USING GIN (name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_products_sku_trgm ON products USING GIN (sku gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_product_translations_name_trgm ON product_translations USING GIN (name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_product_translations_fts ON product_translations
+    USING GIN (to_tsvector('simple', name || ' ' || COALESCE(description, '')));
+
+-- 반환값 (JSONB):
+--   {"total": 123, "items": [{...products row..., "relevance": 0.87}, ...]}
+CREATE OR REPLACE FUNCTION search_products(
+    p_query TEXT,
+    p_locale TEXT,
+    p_limit INT DEFAULT 20,
+    p_offset INT DEFAULT 0
+)
+RETURNS JSONB AS $$
+    WITH params AS (
+        SELECT
+            -- 사용자 입력의 LIKE 와일드카드는 문자 그대로 취급
+            '%' || replace(replace(replace(p_query, '\', '\\'), '%', '\%'), '_', '\_') || '%' AS pattern,
+            plainto_tsquery('simple', p_query) AS tsq
+    ),
+    matches AS (
+        SELECT
+            p.*,
+            GREATEST(
+                similarity(p.name, p_query),
+                CASE WHEN lower(p.sku) = lower(p_query) THEN 1.0 ELSE COALESCE(similarity(p.sku, p_query), 0) END,
+                COALESCE(similarity(t.name, p_query), 0),
+                COALESCE(ts_rank(to_tsvector('simple', t.name || ' ' || COALESCE(t.description, '')), params.tsq), 0)
+            )
+            + CASE WHEN p.name ILIKE params.pattern OR t.name ILIKE params.pattern THEN 0.5 ELSE 0 END
+            + CASE WHEN t.description ILIKE params.pattern THEN 0.1 ELSE 0 END
+            AS relevance
+        FROM products p
+        CROSS JOIN params
+        LEFT JOIN product_translations t ON t.product_id = p.id AND t.locale = p_locale
+        WHERE NOT p.is_deleted
+          AND (
+              p.name ILIKE params.pattern
+              OR p.name % p_query
+              OR p.sku ILIKE params.pattern
+              OR t.name ILIKE params.pattern
+              OR t.name % p_query
+              -- 띄어쓰기가 없는 한국어/일본어/중국어 설명은 tsvector로 분리되지 않으므로 부분 일치도 허용
+              OR t.description ILIKE params.pattern
+              OR to_tsvector('simple', t.name || ' ' || COALESCE(t.description, '')) @@ params.tsq
+          )
+    ),
+    page AS (
+        SELECT * FROM matches
+        ORDER BY relevance DESC, id
+        LIMIT p_limit OFFSET p_offset
+    )
+    SELECT jsonb_build_object(
+        'total', (SELECT COUNT(*) FROM matches),
+        'items', COALESCE((SELECT jsonb_agg(to_jsonb(page) ORDER BY page.relevance DESC, page.id) FROM page), '[]'::jsonb)
+    );
+$$ LANGUAGE sql STABLE;
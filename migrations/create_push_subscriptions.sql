@@ -0,0 +1,16 @@
+-- 브라우저 Web Push 구독
+-- 클라이언트가 PushManager.subscribe() 결과(endpoint, keys.p256dh, keys.auth)를 등록하고,
+-- 알림 발송 큐 워커가 push 채널 발송 시 사용자의 모든 구독으로 보낸다.
+-- (create_notification_queue.sql 이후 실행)
+CREATE TABLE IF NOT EXISTS push_subscriptions (
+    id BIGSERIAL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES auth.users(id) ON DELETE CASCADE,
+    endpoint TEXT NOT NULL UNIQUE,
+    p256dh VARCHAR(200) NOT NULL,
+    auth VARCHAR(100) NOT NULL,
+    user_agent TEXT,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_push_subscriptions_user ON push_subscriptions(user_id);
@@ -0,0 +1,59 @@
+-- 쿠폰 사용 원자적 처리 함수
+-- coupons 행을 FOR UPDATE로 잠가 같은 쿠폰에 대한 동시 사용 요청을 직렬화한다.
+-- 전체 사용 한도(usage_limit)와 사용자별 한도(per_user_limit)를 잠금 안에서 다시 확인하므로
+-- 마지막 1회를 두 요청이 동시에 사용하거나 같은 사용자가 중복 사용할 수 없다.
+--
+-- 반환값 (JSONB):
+--   {"status": "redeemed", "usage": {...coupon_usages row...}}
+--   {"status": "not_found" | "unavailable" | "usage_limit_reached" | "user_limit_reached", "usage": null}
+CREATE OR REPLACE FUNCTION redeem_coupon(
+    p_coupon_id BIGINT,
+    p_user_id UUID,
+    p_order_amount NUMERIC,
+    p_discount_amount NUMERIC
+)
+RETURNS JSONB AS $$
+DECLARE
+    v_coupon coupons%ROWTYPE;
+    v_user_count INT;
+    v_usage coupon_usages%ROWTYPE;
+BEGIN
+    SELECT * INTO v_coupon FROM coupons WHERE id = p_coupon_id FOR UPDATE;
+
+    IF NOT FOUND THEN
+        RETURN jsonb_build_object('status', 'not_found', 'usage', NULL);
+    END IF;
+
+    IF NOT v_coupon.is_active OR NOW() < v_coupon.start_date OR NOW() > v_coupon.end_date THEN
+        RETURN jsonb_build_object('status', 'unavailable', 'usage', NULL);
+    END IF;
+
+    IF v_coupon.usage_limit IS NOT NULL AND v_coupon.used_count >= v_coupon.usage_limit THEN
+        RETURN jsonb_build_object('status', 'usage_limit_reached', 'usage', NULL);
+    END IF;
+
+    IF v_coupon.per_user_limit IS NOT NULL THEN
+        SELECT COUNT(*) INTO v_user_count
+        FROM coupon_usages
+        WHERE coupon_id = p_coupon_id AND user_id = p_user_id;
+
+        IF v_user_count >= v_coupon.per_user_limit THEN
+            RETURN jsonb_build_object('status', 'user_limit_reached', 'usage', NULL);
+        END IF;
+    END IF;
+
+    INSERT INTO coupon_usages (coupon_id, user_id, order_amount, discount_amount, used_at)
+    VALUES (p_coupon_id, p_user_id, p_order_amount, p_discount_amount, NOW())
+    RETURNING * INTO v_usage;
+
+    UPDATE coupons SET used_count = used_count + 1 WHERE id = p_coupon_id;
+
+    RETURN jsonb_build_object('status', 'redeemed', 'usage', to_jsonb(v_usage));
+END;
+$$ LANGUAGE plpgsql SECURITY DEFINER SET search_path = public;
+
+-- 임의 user_id로 호출할 수 없도록 서버(service_role)에서만 실행 허용
+REVOKE EXECUTE ON FUNCTION redeem_coupon(BIGINT, UUID, NUMERIC, NUMERIC) FROM PUBLIC;
+-- Supabase 기본 권한은 anon/authenticated 에 함수 실행을 직접 부여하므로 따로 회수
+REVOKE EXECUTE ON FUNCTION redeem_coupon(BIGINT, UUID, NUMERIC, NUMERIC) FROM anon, authenticated;
+GRANT EXECUTE ON FUNCTION redeem_coupon(BIGINT, UUID, NUMERIC, NUMERIC) TO service_role;
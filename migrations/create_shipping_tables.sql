@@ -0,0 +1,29 @@
+-- 배송 지역 테이블 (매장별 배송 가능 국가)
+CREATE TABLE IF NOT EXISTS shipping_regions (
+    id BIGSERIAL PRIMARY KEY,
+    shop_id BIGINT NOT NULL REFERENCES shops(id) ON DELETE CASCADE,
+    country_code VARCHAR(10) NOT NULL,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW(),
+    UNIQUE(shop_id, country_code)
+);
+
+-- 할인 배송 지역 (행이 없으면 매장의 모든 배송 지역에 적용)
+CREATE TABLE IF NOT EXISTS discount_shipping (
+    id BIGSERIAL PRIMARY KEY,
+    discount_info_id BIGINT NOT NULL REFERENCES discount_infos(id) ON DELETE CASCADE,
+    shipping_region_id BIGINT NOT NULL REFERENCES shipping_regions(id) ON DELETE CASCADE,
+    UNIQUE(discount_info_id, shipping_region_id)
+);
+
+-- 쿠폰 배송 지역 (행이 없으면 매장의 모든 배송 지역에 적용)
+CREATE TABLE IF NOT EXISTS coupon_shipping (
+    id BIGSERIAL PRIMARY KEY,
+    coupon_id BIGINT NOT NULL REFERENCES coupons(id) ON DELETE CASCADE,
+    shipping_region_id BIGINT NOT NULL REFERENCES shipping_regions(id) ON DELETE CASCADE,
+    UNIQUE(coupon_id, shipping_region_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_shipping_regions_country ON shipping_regions(country_code, shop_id);
+CREATE INDEX IF NOT EXISTS idx_discount_shipping_region ON discount_shipping(shipping_region_id);
+CREATE INDEX IF NOT EXISTS idx_coupon_shipping_region ON coupon_shipping(shipping_region_id);
@@ -0,0 +1,24 @@
+-- Supabase Realtime 구독 대상 테이블 등록
+-- 서버의 Realtime 수신기(SUPABASE_REALTIME=true)가 notifications INSERT, discount_infos INSERT/UPDATE 를 받아
+-- /api/v1/ws 로 연결한 사용자에게 전달한다.
+-- supabase_realtime publication 이 없는 환경(로컬 Postgres 등)에서는 아무것도 하지 않는다.
+-- (create_basic_tables.sql, fix_notifications_table.sql 이후 실행)
+DO $$
+DECLARE
+    v_table TEXT;
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_publication WHERE pubname = 'supabase_realtime') THEN
+        RAISE NOTICE 'supabase_realtime publication not found, skipping';
+        RETURN;
+    END IF;
+
+    FOREACH v_table IN ARRAY ARRAY['notifications', 'discount_infos'] LOOP
+        IF NOT EXISTS (
+            SELECT 1 FROM pg_publication_tables
+            WHERE pubname = 'supabase_realtime' AND schemaname = 'public' AND tablename = v_table
+        ) THEN
+            EXECUTE format('ALTER PUBLICATION supabase_realtime ADD TABLE public.%I', v_table);
+        END IF;
+    END LOOP;
+END;
+$$;
@@ -0,0 +1,30 @@
+-- 알림 테이블 보정
+-- create_basic_tables.sql 의 notifications 정의는 컬럼명 오타("r#type")로 생성이 실패하므로 이 스크립트가 테이블을 만든다.
+-- 테이블이 없으면 만들고, 따옴표 식별자로 생성된 경우에는 컬럼명을 type 으로 바꾼다.
+-- (create_basic_tables.sql 이후 실행)
+CREATE TABLE IF NOT EXISTS notifications (
+    id BIGSERIAL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES auth.users(id) ON DELETE CASCADE,
+    type VARCHAR(50) NOT NULL,
+    title VARCHAR(500),
+    message TEXT NOT NULL,
+    data JSONB,
+    is_read BOOLEAN DEFAULT false,
+    created_at TIMESTAMPTZ DEFAULT NOW()
+);
+
+DO $$
+BEGIN
+    IF EXISTS (
+        SELECT 1 FROM information_schema.columns
+        WHERE table_schema = 'public' AND table_name = 'notifications' AND column_name = 'r#type'
+    ) THEN
+        ALTER TABLE notifications RENAME COLUMN "r#type" TO type;
+    END IF;
+END;
+$$;
+
+CREATE INDEX IF NOT EXISTS idx_notifications_user ON notifications(user_id, is_read);
+CREATE INDEX IF NOT EXISTS idx_notifications_user_keyset ON notifications(user_id, created_at DESC, id DESC);
+-- 읽지 않은 알림 개수 조회용
+CREATE INDEX IF NOT EXISTS idx_notifications_user_unread ON notifications(user_id) WHERE NOT is_read;
@@ -0,0 +1,123 @@
+-- 알림 설정 스키마 정리
+-- 사용자당 한 행인 sql/notification_settings.sql 구조(push_enabled, discount_updates, subscription_changes)를 기준으로 하고
+-- 채널별 수신 여부(email_enabled, sms_enabled)와 채널별 방해 금지 시간(quiet_hours)을 추가한다.
+-- create_basic_tables.sql 의 이전 구조(user_id + setting_type 행)로 만들어진 테이블은
+-- notification_settings_legacy 로 옮기고 값을 변환해 복사한다 (확인 후 legacy 테이블은 수동 삭제).
+-- 알림 발송 큐 워커는 클레임 시 notification_quiet_until() 로 채널별 방해 금지 시간에 걸린 항목을 끝나는 시각으로 미룬다.
+-- (create_basic_tables.sql, create_notification_queue.sql 이후, 워커 실행 전 실행)
+-- create_price_drop_alerts.sql 의 claim_price_drop_alerts() 는 이전 구조를 읽으므로 이후 retry_price_drop_alerts.sql 을 실행해 새 구조로 교체한다.
+--
+-- quiet_hours (JSONB, 채널별 선택, 시각은 profiles.timezone 기준 HH:MM, start > end 면 자정을 넘기는 구간):
+--   {"push": {"start": "22:00", "end": "08:00"}, "email": {"start": "00:00", "end": "07:00"}}
+DO $$
+BEGIN
+    IF EXISTS (
+        SELECT 1 FROM information_schema.columns
+        WHERE table_schema = 'public' AND table_name = 'notification_settings' AND column_name = 'setting_type'
+    ) THEN
+        ALTER TABLE notification_settings RENAME TO notification_settings_legacy;
+    END IF;
+END;
+$$;
+
+CREATE TABLE IF NOT EXISTS notification_settings (
+    user_id UUID PRIMARY KEY REFERENCES auth.users(id) ON DELETE CASCADE,
+    push_enabled BOOLEAN NOT NULL DEFAULT true,
+    discount_updates BOOLEAN NOT NULL DEFAULT true,
+    subscription_changes BOOLEAN NOT NULL DEFAULT true,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW()
+);
+
+ALTER TABLE notification_settings ADD COLUMN IF NOT EXISTS email_enabled BOOLEAN NOT NULL DEFAULT true;
+ALTER TABLE notification_settings ADD COLUMN IF NOT EXISTS sms_enabled BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE notification_settings ADD COLUMN IF NOT EXISTS quiet_hours JSONB NOT NULL DEFAULT '{}';
+
+-- 이전 구조 변환: push/discount 는 그대로, shop/brand/category 중 하나라도 꺼져 있으면 subscription_changes 끔
+DO $$
+BEGIN
+    IF to_regclass('public.notification_settings_legacy') IS NOT NULL THEN
+        INSERT INTO notification_settings (user_id, push_enabled, discount_updates, subscription_changes)
+        SELECT
+            user_id,
+            COALESCE(bool_and(is_enabled) FILTER (WHERE setting_type = 'push'), true),
+            COALESCE(bool_and(is_enabled) FILTER (WHERE setting_type = 'discount'), true),
+            COALESCE(bool_and(is_enabled) FILTER (WHERE setting_type IN ('shop', 'brand', 'category')), true)
+        FROM notification_settings_legacy
+        GROUP BY user_id
+        ON CONFLICT (user_id) DO NOTHING;
+    END IF;
+END;
+$$;
+
+-- 행 수준 보안: 본인 설정만 접근 (서버는 service_role 로 접근하므로 영향 없음)
+ALTER TABLE notification_settings ENABLE ROW LEVEL SECURITY;
+
+DROP POLICY IF EXISTS "Users can view own notification settings" ON notification_settings;
+CREATE POLICY "Users can view own notification settings" ON notification_settings
+    FOR SELECT TO authenticated USING (auth.uid() = user_id);
+
+DROP POLICY IF EXISTS "Users can insert own notification settings" ON notification_settings;
+CREATE POLICY "Users can insert own notification settings" ON notification_settings
+    FOR INSERT TO authenticated WITH CHECK (auth.uid() = user_id);
+
+DROP POLICY IF EXISTS "Users can update own notification settings" ON notification_settings;
+CREATE POLICY "Users can update own notification settings" ON notification_settings
+    FOR UPDATE TO authenticated USING (auth.uid() = user_id) WITH CHECK (auth.uid() = user_id);
+
+DROP POLICY IF EXISTS "Users can delete own notification settings" ON notification_settings;
+CREATE POLICY "Users can delete own notification settings" ON notification_settings
+    FOR DELETE TO authenticated USING (auth.uid() = user_id);
+
+REVOKE ALL ON notification_settings FROM anon;
+
+DROP TRIGGER IF EXISTS update_notification_settings_updated_at ON notification_settings;
+CREATE TRIGGER update_notification_settings_updated_at BEFORE UPDATE ON notification_settings
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at();
+
+-- 사용자의 채널 방해 금지 시간이 p_now 에 해당하면 끝나는 시각, 아니면 NULL
+-- 시간대는 profiles.timezone (없거나 잘못된 값이면 UTC)
+CREATE OR REPLACE FUNCTION notification_quiet_until(p_user_id UUID, p_channel TEXT, p_now TIMESTAMPTZ DEFAULT NOW())
+RETURNS TIMESTAMPTZ AS $$
+DECLARE
+    v_window JSONB;
+    v_timezone TEXT;
+    v_start TIME;
+    v_end TIME;
+    v_local TIMESTAMP;
+BEGIN
+    SELECT ns.quiet_hours -> p_channel INTO v_window
+    FROM notification_settings ns
+    WHERE ns.user_id = p_user_id;
+
+    IF v_window IS NULL OR v_window->>'start' IS NULL OR v_window->>'end' IS NULL THEN
+        RETURN NULL;
+    END IF;
+
+    v_start := (v_window->>'start')::TIME;
+    v_end := (v_window->>'end')::TIME;
+    IF v_start = v_end THEN
+        RETURN NULL;
+    END IF;
+
+    SELECT p.timezone INTO v_timezone FROM profiles p WHERE p.user_id = p_user_id LIMIT 1;
+    BEGIN
+        v_local := p_now AT TIME ZONE COALESCE(v_timezone, 'UTC');
+    EXCEPTION WHEN invalid_parameter_value THEN
+        v_timezone := 'UTC';
+        v_local := p_now AT TIME ZONE 'UTC';
+    END;
+
+    IF v_start < v_end THEN
+        IF v_local::TIME >= v_start AND v_local::TIME < v_end THEN
+            RETURN (v_local::DATE + v_end) AT TIME ZONE COALESCE(v_timezone, 'UTC');
+        END IF;
+    ELSIF v_local::TIME >= v_start THEN
+        RETURN (v_local::DATE + 1 + v_end) AT TIME ZONE COALESCE(v_timezone, 'UTC');
+    ELSIF v_local::TIME < v_end THEN
+        RETURN (v_local::DATE + v_end) AT TIME ZONE COALESCE(v_timezone, 'UTC');
+    END IF;
+
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql STABLE;
@@ -0,0 +1,185 @@
+-- 가격 인하 알림 재시도 (at-least-once)
+-- 기존 claim_price_drop_alerts() 는 이벤트 처리 완료와 발송 기록을 먼저 남겨서 알림 생성이 실패하면 그 알림은 다시 만들어지지 않았다.
+-- 이제 발송 기록은 processing 상태로 클레임되고, 서버가 알림을 만든 뒤 complete 로 sent 처리한다.
+-- 알림 생성이 실패하면 서버가 시도 횟수에 따른 대기 시간 뒤로 pending 으로 되돌리고(release), 서버가 결과를 남기지 못하면 p_lock_secs 후 다시 클레임된다.
+-- p_max_attempts 를 넘기거나 할인이 끝나면 failed 로 종료한다.
+-- (create_price_drop_alerts.sql, reconcile_notification_settings.sql 이후 실행)
+
+-- 기존 발송 기록은 이미 알림이 만들어진 것으로 보고 sent
+ALTER TABLE price_drop_alert_deliveries ADD COLUMN IF NOT EXISTS status VARCHAR(20) NOT NULL DEFAULT 'sent'; -- pending, processing, sent, failed
+ALTER TABLE price_drop_alert_deliveries ALTER COLUMN status SET DEFAULT 'pending';
+ALTER TABLE price_drop_alert_deliveries ADD COLUMN IF NOT EXISTS change_type VARCHAR(20);
+ALTER TABLE price_drop_alert_deliveries ADD COLUMN IF NOT EXISTS previous_price DECIMAL(12,2);
+ALTER TABLE price_drop_alert_deliveries ADD COLUMN IF NOT EXISTS attempts INT NOT NULL DEFAULT 0;
+-- 다음 클레임 가능 시각 (processing 상태에서는 클레임 만료 시각)
+ALTER TABLE price_drop_alert_deliveries ADD COLUMN IF NOT EXISTS next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+ALTER TABLE price_drop_alert_deliveries ADD COLUMN IF NOT EXISTS notification_id BIGINT REFERENCES notifications(id) ON DELETE SET NULL;
+ALTER TABLE price_drop_alert_deliveries ADD COLUMN IF NOT EXISTS last_error TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_price_drop_alert_deliveries_due
+    ON price_drop_alert_deliveries(next_attempt_at, id) WHERE status IN ('pending', 'processing');
+
+DROP FUNCTION IF EXISTS claim_price_drop_alerts(INT);
+
+-- 대기 중인 변경 이벤트를 가져가 구독자를 매칭하고, 새 발송 기록과 재시도할 발송 기록을 클레임한다.
+-- 매칭 규칙은 create_price_drop_alerts.sql 과 같고, 수신 설정은 reconcile_notification_settings.sql 의 사용자당 한 행 구조를 읽는다.
+-- - notification_settings.discount_updates 가 꺼져 있거나, 브랜드/매장/카테고리 매칭인데 subscription_changes 가 꺼져 있으면 제외
+-- - 새 매칭: processing 상태로 발송 기록 생성 (사용자/할인당 한 번, UNIQUE 제약)
+-- - 재시도: pending 이거나 클레임이 만료된 processing 기록 중 진행 중인 할인만 다시 클레임
+-- - 시도 횟수를 넘겼거나 할인이 끝난 기록은 failed
+-- 여러 서버가 동시에 실행해도 SKIP LOCKED 와 발송 기록 UNIQUE 제약으로 같은 기록을 함께 클레임하지 않는다.
+--
+-- 반환값 (JSONB 배열):
+--   [{"user_id", "discount_info_id", "product_id", "product_name", "matched_by", "change_type",
+--     "original_price", "discount_price", "previous_price", "discount_rate", "currency", "end_at", "attempts"}]
+-- attempts 는 이번 클레임을 포함한 시도 횟수 (서버가 release 할 때 재시도 간격 계산에 사용)
+CREATE OR REPLACE FUNCTION claim_price_drop_alerts(p_limit INT DEFAULT 100, p_lock_secs INT DEFAULT 300, p_max_attempts INT DEFAULT 5)
+RETURNS JSONB
+LANGUAGE sql
+SECURITY DEFINER
+SET search_path = public
+AS $$
+    WITH RECURSIVE picked AS (
+        SELECT e.id, e.discount_info_id, e.change_type, e.previous_price
+        FROM discount_change_events e
+        JOIN discount_infos di ON di.id = e.discount_info_id
+        WHERE e.processed_at IS NULL
+          AND (di.start_at <= NOW() OR NOT COALESCE(di.is_active, false) OR di.end_at < NOW())
+        ORDER BY e.created_at, e.id
+        LIMIT p_limit
+        FOR UPDATE OF e SKIP LOCKED
+    ),
+    processed AS (
+        UPDATE discount_change_events e
+        SET processed_at = NOW()
+        FROM picked
+        WHERE e.id = picked.id
+        RETURNING e.id
+    ),
+    live AS (
+        -- 진행 중인 할인만 알림 대상 (같은 할인의 이벤트가 여러 개면 가장 최근 것 기준)
+        SELECT DISTINCT ON (di.id)
+            di.id, di.product_id, di.shop_id, COALESCE(di.brand_id, pr.brand_id) AS brand_id, pr.category_id, di.discount_rate,
+            p.change_type, p.previous_price
+        FROM picked p
+        JOIN discount_infos di ON di.id = p.discount_info_id
+        JOIN products pr ON pr.id = di.product_id
+        WHERE di.is_active
+          AND NOW() BETWEEN di.start_at AND di.end_at
+          AND NOT pr.is_deleted
+        ORDER BY di.id, p.id DESC
+    ),
+    category_path AS (
+        -- 상품 카테고리와 그 상위 카테고리들
+        SELECT l.id AS discount_info_id, l.category_id, 0 AS depth
+        FROM live l
+        WHERE l.category_id IS NOT NULL
+        UNION ALL
+        SELECT cp.discount_info_id, c.parent_id, cp.depth + 1
+        FROM category_path cp
+        JOIN categories c ON c.id = cp.category_id
+        WHERE c.parent_id IS NOT NULL AND cp.depth < 10
+    ),
+    candidates AS (
+        SELECT ps.user_id, l.id AS discount_info_id, 'product' AS matched_by, 1 AS priority
+        FROM live l
+        JOIN product_subscriptions ps ON ps.product_id = l.product_id
+        UNION ALL
+        SELECT bs.user_id, l.id, 'brand', 2
+        FROM live l
+        JOIN brand_subscriptions bs ON bs.brand_id = l.brand_id
+        WHERE COALESCE(bs.is_active, true)
+        UNION ALL
+        SELECT ss.user_id, l.id, 'shop', 3
+        FROM live l
+        JOIN shop_subscriptions ss ON ss.shop_id = l.shop_id
+        WHERE COALESCE(ss.is_active, true)
+        UNION ALL
+        SELECT cs.user_id, l.id, 'category', 4
+        FROM live l
+        JOIN category_path cp ON cp.discount_info_id = l.id
+        JOIN category_subscriptions cs ON cs.category_id = cp.category_id
+        WHERE COALESCE(cs.is_active, true)
+          AND (cs.min_discount_rate IS NULL OR l.discount_rate >= cs.min_discount_rate)
+    ),
+    matched AS (
+        SELECT DISTINCT ON (c.user_id, c.discount_info_id) c.user_id, c.discount_info_id, c.matched_by
+        FROM candidates c
+        WHERE NOT EXISTS (
+            SELECT 1 FROM notification_settings ns
+            WHERE ns.user_id = c.user_id
+              AND (NOT ns.discount_updates OR (c.matched_by <> 'product' AND NOT ns.subscription_changes))
+        )
+        ORDER BY c.user_id, c.discount_info_id, c.priority
+    ),
+    inserted AS (
+        INSERT INTO price_drop_alert_deliveries
+            (user_id, discount_info_id, matched_by, change_type, previous_price, status, attempts, next_attempt_at)
+        SELECT m.user_id, m.discount_info_id, m.matched_by, l.change_type, l.previous_price,
+               'processing', 1, NOW() + make_interval(secs => p_lock_secs)
+        FROM matched m
+        JOIN live l ON l.id = m.discount_info_id
+        ON CONFLICT (user_id, discount_info_id) DO NOTHING
+        RETURNING user_id, discount_info_id, matched_by, change_type, previous_price, attempts
+    ),
+    due AS (
+        -- 재시도 대상 (이번 호출에서 새로 만든 기록은 아직 보이지 않음)
+        SELECT d.id, d.attempts,
+               (di.is_active AND NOW() BETWEEN di.start_at AND di.end_at AND NOT pr.is_deleted) AS is_live
+        FROM price_drop_alert_deliveries d
+        JOIN discount_infos di ON di.id = d.discount_info_id
+        JOIN products pr ON pr.id = di.product_id
+        WHERE d.status IN ('pending', 'processing')
+          AND d.next_attempt_at <= NOW()
+        ORDER BY d.next_attempt_at, d.id
+        LIMIT p_limit
+        FOR UPDATE OF d SKIP LOCKED
+    ),
+    retried AS (
+        UPDATE price_drop_alert_deliveries d
+        SET status = 'processing',
+            attempts = d.attempts + 1,
+            next_attempt_at = NOW() + make_interval(secs => p_lock_secs)
+        FROM due
+        WHERE d.id = due.id
+          AND due.is_live
+          AND due.attempts < p_max_attempts
+        RETURNING d.user_id, d.discount_info_id, d.matched_by, d.change_type, d.previous_price, d.attempts
+    ),
+    failed AS (
+        UPDATE price_drop_alert_deliveries d
+        SET status = 'failed',
+            last_error = CASE WHEN due.is_live THEN COALESCE(d.last_error, 'Max attempts reached') ELSE 'Discount ended' END
+        FROM due
+        WHERE d.id = due.id
+          AND (NOT due.is_live OR due.attempts >= p_max_attempts)
+        RETURNING d.id
+    ),
+    claimed AS (
+        SELECT * FROM inserted
+        UNION ALL
+        SELECT * FROM retried
+    )
+    SELECT COALESCE(jsonb_agg(jsonb_build_object(
+        'user_id', c.user_id,
+        'discount_info_id', di.id,
+        'product_id', di.product_id,
+        'product_name', pr.name,
+        'matched_by', c.matched_by,
+        'change_type', COALESCE(c.change_type, 'created'),
+        'original_price', di.original_price,
+        'discount_price', di.discount_price,
+        'previous_price', c.previous_price,
+        'discount_rate', di.discount_rate,
+        'currency', di.currency,
+        'end_at', di.end_at,
+        'attempts', c.attempts
+    ) ORDER BY di.id, c.user_id), '[]'::jsonb)
+    FROM claimed c
+    JOIN discount_infos di ON di.id = c.discount_info_id
+    JOIN products pr ON pr.id = di.product_id;
+$$;
+
+REVOKE EXECUTE ON FUNCTION claim_price_drop_alerts(INT, INT, INT) FROM PUBLIC;
+REVOKE EXECUTE ON FUNCTION claim_price_drop_alerts(INT, INT, INT) FROM anon, authenticated;
+GRANT EXECUTE ON FUNCTION claim_price_drop_alerts(INT, INT, INT) TO service_role;
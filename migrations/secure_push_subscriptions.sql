@@ -0,0 +1,24 @@
+-- Web Push 구독 행 수준 보안 (RLS)
+-- endpoint 와 암호화 키(p256dh, auth)는 그 구독으로 푸시를 보낼 수 있는 값이므로 본인 행만 접근 가능하게 한다.
+-- 서버는 service_role 로 접근하므로 영향 없고, anon 키로는 접근할 수 없다.
+-- (create_push_subscriptions.sql 이후 실행)
+ALTER TABLE push_subscriptions ENABLE ROW LEVEL SECURITY;
+
+DROP POLICY IF EXISTS "Users can view own push subscriptions" ON push_subscriptions;
+CREATE POLICY "Users can view own push subscriptions" ON push_subscriptions
+    FOR SELECT TO authenticated USING (auth.uid() = user_id);
+
+DROP POLICY IF EXISTS "Users can insert own push subscriptions" ON push_subscriptions;
+CREATE POLICY "Users can insert own push subscriptions" ON push_subscriptions
+    FOR INSERT TO authenticated WITH CHECK (auth.uid() = user_id);
+
+DROP POLICY IF EXISTS "Users can update own push subscriptions" ON push_subscriptions;
+CREATE POLICY "Users can update own push subscriptions" ON push_subscriptions
+    FOR UPDATE TO authenticated USING (auth.uid() = user_id) WITH CHECK (auth.uid() = user_id);
+
+DROP POLICY IF EXISTS "Users can delete own push subscriptions" ON push_subscriptions;
+CREATE POLICY "Users can delete own push subscriptions" ON push_subscriptions
+    FOR DELETE TO authenticated USING (auth.uid() = user_id);
+
+REVOKE ALL ON push_subscriptions FROM anon;
+REVOKE ALL ON SEQUENCE push_subscriptions_id_seq FROM anon;
@@ -2,8 +2,11 @@
 CREATE TABLE IF NOT EXISTS notification_settings (
     user_id UUID PRIMARY KEY REFERENCES auth.users(id) ON DELETE CASCADE,
     push_enabled BOOLEAN NOT NULL DEFAULT true,
+    email_enabled BOOLEAN NOT NULL DEFAULT true,
+    sms_enabled BOOLEAN NOT NULL DEFAULT false,
     discount_updates BOOLEAN NOT NULL DEFAULT true,
     subscription_changes BOOLEAN NOT NULL DEFAULT true,
+    quiet_hours JSONB NOT NULL DEFAULT '{}',
     created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
     updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
 );
@@ -45,5 +48,8 @@ CREATE TRIGGER update_notification_settings_updated_at
 COMMENT ON TABLE notification_settings IS '사용자별 실시간 알림 설정';
 COMMENT ON COLUMN notification_settings.user_id IS '사용자 ID (auth.users 참조)';
 COMMENT ON COLUMN notification_settings.push_enabled IS '푸시 알림 전체 활성화 여부';
+COMMENT ON COLUMN notification_settings.email_enabled IS '이메일 알림 수신 여부';
+COMMENT ON COLUMN notification_settings.sms_enabled IS 'SMS 알림 수신 여부';
 COMMENT ON COLUMN notification_settings.discount_updates IS '할인 정보 업데이트 알림 수신 여부';
-COMMENT ON COLUMN notification_settings.subscription_changes IS '구독 변경 알림 수신 여부 (브랜드/카테고리/매장)';
\ No newline at end of file
+COMMENT ON COLUMN notification_settings.subscription_changes IS '구독 변경 알림 수신 여부 (브랜드/카테고리/매장)';
+COMMENT ON COLUMN notification_settings.quiet_hours IS '채널별 방해 금지 시간 {"push": {"start": "22:00", "end": "08:00"}} (profiles.timezone 기준)';
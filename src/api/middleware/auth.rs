@@ -0,0 +1,250 @@
+use std::sync::Arc;
+
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Request, State},
+    http::{header::AUTHORIZATION, request::Parts, HeaderMap},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::auth::{extract_user_from_token, AuthUser};
+use crate::error::{AppError, AppResult};
+use crate::utils::constants::ADMIN_ROLE;
+use crate::utils::log_security_event;
+use crate::AppState;
+
+// Authorization: Bearer <token> 헤더에서 토큰 추출
+pub fn bearer_token(headers: &HeaderMap) -> AppResult<&str> {
+    let value = headers
+        .get(AUTHORIZATION)
+        .ok_or_else(|| AppError::authentication("Missing Authorization header"))?
+        .to_str()
+        .map_err(|_| AppError::authentication("Invalid Authorization header"))?;
+
+    value
+        .strip_prefix("Bearer ")
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .ok_or_else(|| AppError::authentication("Authorization header must be 'Bearer <token>'"))
+}
+
+// 핸들러에서 `user: AuthUser`로 현재 사용자 주입
+#[async_trait]
+impl FromRequestParts<Arc<AppState>> for AuthUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &Arc<AppState>) -> Result<Self, Self::Rejection> {
+        // require_auth 미들웨어가 이미 검증했다면 재사용
+        if let Some(user) = parts.extensions.get::<AuthUser>() {
+            return Ok(user.clone());
+        }
+
+        let token = bearer_token(&parts.headers)?;
+        extract_user_from_token(&state.jwt_verifier, token).await
+    }
+}
+
+// 인증 필요 라우트 그룹용 미들웨어 - 검증된 사용자를 request extension에 저장
+pub async fn require_auth(
+    State(state): State<Arc<AppState>>,
+    mut request: Request,
+    next: Next,
+) -> AppResult<Response> {
+    let token = bearer_token(request.headers())?.to_string();
+    let user = extract_user_from_token(&state.jwt_verifier, &token).await?;
+
+    request.extensions_mut().insert(user);
+    Ok(next.run(request).await)
+}
+
+// 관리자 라우트 그룹용 미들웨어 - JWT 역할 클레임 또는 user_roles 테이블로 확인
+pub async fn require_admin(
+    State(state): State<Arc<AppState>>,
+    mut request: Request,
+    next: Next,
+) -> AppResult<Response> {
+    let token = bearer_token(request.headers())?.to_string();
+    let user = extract_user_from_token(&state.jwt_verifier, &token).await?;
+
+    if !user.has_admin_claim() {
+        let roles = state.role_lookup.roles_for(&user.id).await?;
+        if !roles.iter().any(|role| role == ADMIN_ROLE) {
+            log_security_event("admin_access_denied", Some(&user.id), None, request.uri().path());
+            return Err(AppError::authorization("Admin role required"));
+        }
+    }
+
+    request.extensions_mut().insert(user);
+    Ok(next.run(request).await)
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use axum::{http::StatusCode, middleware, routing::get, Router};
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde_json::json;
+
+    use crate::auth::{JwtVerifier, RoleLookup};
+    use crate::config::SupabaseConfig;
+    use crate::realtime::RealtimeBus;
+    use crate::service::{CouponService, DiscountService, MonitoringService, NotificationService, ProductService, ShopService, UserService};
+    use crate::utils::constants::JWT_DEFAULT_AUDIENCE;
+
+    const SECRET: &str = "test-secret";
+    const ISSUER: &str = "http://127.0.0.1:54321/auth/v1";
+    const ADMIN_USER: &str = "00000000-0000-0000-0000-00000000000a";
+    const NORMAL_USER: &str = "00000000-0000-0000-0000-00000000000b";
+
+    // user_roles 대신 고정 역할 (조회 횟수 기록)
+    struct StubRoles {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl RoleLookup for StubRoles {
+        async fn roles_for(&self, user_id: &str) -> AppResult<Vec<String>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(match user_id {
+                ADMIN_USER => vec!["editor".to_string(), ADMIN_ROLE.to_string()],
+                _ => vec!["editor".to_string()],
+            })
+        }
+    }
+
+    fn test_state(role_lookup: Arc<StubRoles>) -> Arc<AppState> {
+        let config = SupabaseConfig {
+            client: postgrest::Postgrest::new("http://127.0.0.1:9/rest/v1"),
+            url: "http://127.0.0.1:9".to_string(),
+            anon_key: "anon-key".to_string(),
+            service_key: "service-key".to_string(),
+            jwt_secret: None,
+            database_url: None,
+        };
+
+        Arc::new(AppState {
+            discount_service: DiscountService::new(config.clone()),
+            shop_service: ShopService::new(config.clone()),
+            product_service: ProductService::new(config.clone()),
+            user_service: UserService::new(config.clone()),
+            notification_service: NotificationService::new(config.clone()),
+            monitoring_service: MonitoringService::new(config.clone()),
+            coupon_service: CouponService::new(config),
+            crawler_service: None,
+            realtime_bus: RealtimeBus::new(),
+            jwt_verifier: JwtVerifier::with_secret(Some(SECRET), JWT_DEFAULT_AUDIENCE, ISSUER),
+            role_lookup,
+        })
+    }
+
+    fn token(user_id: &str, extra: serde_json::Value) -> String {
+        let mut claims = json!({
+            "sub": user_id,
+            "role": "authenticated",
+            "aud": JWT_DEFAULT_AUDIENCE,
+            "iss": ISSUER,
+            "exp": chrono::Utc::now().timestamp() + 3600,
+        });
+        for (key, value) in extra.as_object().unwrap() {
+            claims[key] = value.clone();
+        }
+        encode(&Header::default(), &claims, &EncodingKey::from_secret(SECRET.as_bytes())).unwrap()
+    }
+
+    // require_admin 뒤의 라우트 - 통과하면 검증된 사용자 id 를 돌려줌
+    async fn admin_request(roles: Arc<StubRoles>, authorization: Option<String>) -> Result<String, AppError> {
+        let state = test_state(roles);
+        let app = Router::new()
+            .route("/admin", get(|user: AuthUser| async move { user.id }))
+            .route_layer(middleware::from_fn_with_state(state.clone(), require_admin))
+            .with_state(state);
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let mut request = reqwest::Client::new().get(format!("http://{}/admin", addr));
+        if let Some(authorization) = authorization {
+            request = request.header(AUTHORIZATION.as_str(), authorization);
+        }
+        let response = request.send().await.unwrap();
+        let status = StatusCode::from_u16(response.status().as_u16()).unwrap();
+        let body = response.text().await.unwrap();
+
+        match status {
+            StatusCode::OK => Ok(body),
+            StatusCode::UNAUTHORIZED => Err(AppError::authentication(body)),
+            StatusCode::FORBIDDEN => Err(AppError::authorization(body)),
+            status => panic!("unexpected status {}: {}", status, body),
+        }
+    }
+
+    fn stub_roles() -> Arc<StubRoles> {
+        Arc::new(StubRoles { calls: AtomicUsize::new(0) })
+    }
+
+    #[tokio::test]
+    async fn admin_claim_passes_without_role_lookup() {
+        let roles = stub_roles();
+        let bearer = format!("Bearer {}", token(NORMAL_USER, json!({ "app_metadata": { "role": ADMIN_ROLE } })));
+
+        assert_eq!(admin_request(roles.clone(), Some(bearer)).await.unwrap(), NORMAL_USER);
+        assert_eq!(roles.calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn user_roles_admin_passes() {
+        let roles = stub_roles();
+        let bearer = format!("Bearer {}", token(ADMIN_USER, json!({})));
+
+        assert_eq!(admin_request(roles.clone(), Some(bearer)).await.unwrap(), ADMIN_USER);
+        assert_eq!(roles.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn non_admin_is_forbidden() {
+        let bearer = format!("Bearer {}", token(NORMAL_USER, json!({})));
+
+        let error = admin_request(stub_roles(), Some(bearer)).await.unwrap_err();
+        assert!(matches!(error, AppError::Authorization(_)));
+    }
+
+    #[tokio::test]
+    async fn missing_or_invalid_bearer_token_is_rejected() {
+        let roles = stub_roles();
+        let expired = token(ADMIN_USER, json!({ "exp": chrono::Utc::now().timestamp() - 3600 }));
+        let wrong_secret = encode(
+            &Header::default(),
+            &json!({ "sub": ADMIN_USER, "aud": JWT_DEFAULT_AUDIENCE, "iss": ISSUER, "exp": chrono::Utc::now().timestamp() + 3600 }),
+            &EncodingKey::from_secret(b"other-secret"),
+        )
+        .unwrap();
+
+        for authorization in [
+            None,
+            Some("Basic dXNlcjpwYXNz".to_string()),
+            Some("Bearer ".to_string()),
+            Some("Bearer not-a-jwt".to_string()),
+            Some(format!("Bearer {}", expired)),
+            Some(format!("Bearer {}", wrong_secret)),
+        ] {
+            let error = admin_request(roles.clone(), authorization.clone()).await.unwrap_err();
+            assert!(matches!(error, AppError::Authentication(_)), "{:?}", authorization);
+        }
+        assert_eq!(roles.calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn bearer_token_requires_bearer_scheme() {
+        let mut headers = HeaderMap::new();
+        assert!(bearer_token(&headers).is_err());
+
+        headers.insert(AUTHORIZATION, "Bearer  abc ".parse().unwrap());
+        assert_eq!(bearer_token(&headers).unwrap(), "abc");
+
+        headers.insert(AUTHORIZATION, "bearer abc".parse().unwrap());
+        assert!(bearer_token(&headers).is_err());
+    }
+}
@@ -1,7 +1,7 @@
-// pub mod auth;
+pub mod auth;
 // pub mod i18n;
 // pub mod cors;
 
-// pub use auth::*;
+pub use auth::*;
 // pub use i18n::*;
 // pub use cors::*;
\ No newline at end of file
@@ -0,0 +1,302 @@
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::config::SupabaseConfig;
+use crate::error::{AppError, AppResult};
+use crate::utils::constants::{JWKS_REFRESH_MIN_INTERVAL_SECS, JWT_DEFAULT_AUDIENCE};
+
+// Supabase access token 클레임
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: String,
+    pub email: Option<String>,
+    pub role: Option<String>,
+    pub aud: String,
+    pub iss: String,
+    pub exp: i64,
+    pub iat: Option<i64>,
+    pub app_metadata: Option<AppMetadata>,
+}
+
+// 서버에서만 수정 가능한 app_metadata (관리자 역할 부여용)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppMetadata {
+    pub role: Option<String>,
+}
+
+// Supabase JWT 검증기 - HS256(JWT secret) 또는 RS256/ES256(JWKS) 지원
+#[derive(Clone)]
+pub struct JwtVerifier {
+    secret: Option<DecodingKey>,
+    jwks: Arc<RwLock<JwkSet>>,
+    jwks_url: Option<String>,
+    last_kid_refresh: Arc<Mutex<Option<Instant>>>, // 모르는 kid 로 JWKS 를 다시 읽은 시각
+    audience: String,
+    issuer: String,
+}
+
+impl JwtVerifier {
+    pub fn new(config: &SupabaseConfig) -> Self {
+        let mut verifier = Self::with_secret(
+            config.jwt_secret.as_deref(),
+            JWT_DEFAULT_AUDIENCE,
+            &config.auth_issuer(),
+        );
+        verifier.jwks_url = Some(config.jwks_url());
+        verifier
+    }
+
+    // 로컬 secret으로 검증기 생성 (테스트에서 직접 토큰을 발급할 때 사용)
+    pub fn with_secret(secret: Option<&str>, audience: &str, issuer: &str) -> Self {
+        Self {
+            secret: secret.map(|s| DecodingKey::from_secret(s.as_bytes())),
+            jwks: Arc::new(RwLock::new(JwkSet { keys: Vec::new() })),
+            jwks_url: None,
+            last_kid_refresh: Arc::new(Mutex::new(None)),
+            audience: audience.to_string(),
+            issuer: issuer.to_string(),
+        }
+    }
+
+    // JWKS 엔드포인트에서 공개키 목록을 가져와 갱신
+    pub async fn refresh_jwks(&self) -> AppResult<usize> {
+        let url = self.jwks_url.as_deref()
+            .ok_or_else(|| AppError::Configuration("JWKS URL is not configured".to_string()))?;
+
+        let response = reqwest::get(url)
+            .await
+            .map_err(|e| AppError::ExternalService(format!("Failed to fetch JWKS: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(AppError::ExternalService(format!("Failed to fetch JWKS: {}", response.status())));
+        }
+
+        let jwks: JwkSet = response
+            .json()
+            .await
+            .map_err(|e| AppError::ExternalService(format!("Invalid JWKS response: {}", e)))?;
+
+        let count = jwks.keys.len();
+        *self.jwks.write().unwrap_or_else(|e| e.into_inner()) = jwks;
+        Ok(count)
+    }
+
+    // 토큰 서명 및 exp/aud/iss 검증 후 클레임 반환
+    pub async fn verify(&self, token: &str) -> AppResult<Claims> {
+        if token.is_empty() {
+            return Err(AppError::authentication("Token is empty"));
+        }
+
+        let header = decode_header(token).map_err(map_jwt_error)?;
+
+        let key = match header.alg {
+            Algorithm::HS256 => self.secret.clone()
+                .ok_or_else(|| AppError::authentication("HS256 tokens are not accepted (JWT secret not configured)"))?,
+            Algorithm::RS256 | Algorithm::ES256 => {
+                let kid = header.kid.as_deref()
+                    .ok_or_else(|| AppError::authentication("Token header is missing 'kid'"))?;
+                match self.find_jwk(kid)? {
+                    Some(key) => key,
+                    // 키 교체 직후일 수 있으므로 JWKS 를 다시 읽고 한 번 더 찾음
+                    None => {
+                        self.refresh_jwks_for_unknown_kid(kid).await;
+                        self.find_jwk(kid)?
+                            .ok_or_else(|| AppError::authentication(format!("Unknown signing key: {}", kid)))?
+                    }
+                }
+            }
+            other => {
+                return Err(AppError::authentication(format!("Unsupported token algorithm: {:?}", other)));
+            }
+        };
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_audience(&[&self.audience]);
+        validation.set_issuer(&[&self.issuer]);
+        validation.set_required_spec_claims(&["exp", "sub", "aud", "iss"]);
+
+        let data = decode::<Claims>(token, &key, &validation).map_err(map_jwt_error)?;
+        Ok(data.claims)
+    }
+
+    fn find_jwk(&self, kid: &str) -> AppResult<Option<DecodingKey>> {
+        let jwks = self.jwks.read().unwrap_or_else(|e| e.into_inner());
+        jwks.find(kid)
+            .map(|jwk| DecodingKey::from_jwk(jwk).map_err(map_jwt_error))
+            .transpose()
+    }
+
+    // 모르는 kid 를 받았을 때 JWKS 갱신 - 위조 토큰으로 JWKS 엔드포인트를 두드리지 않도록
+    // JWKS_REFRESH_MIN_INTERVAL_SECS 에 한 번만 (동시에 들어온 요청은 진행 중인 갱신을 기다림)
+    async fn refresh_jwks_for_unknown_kid(&self, kid: &str) {
+        if self.jwks_url.is_none() {
+            return;
+        }
+
+        let mut last_refresh = self.last_kid_refresh.lock().await;
+        if last_refresh.is_some_and(|at| at.elapsed() < Duration::from_secs(JWKS_REFRESH_MIN_INTERVAL_SECS)) {
+            return;
+        }
+        *last_refresh = Some(Instant::now());
+
+        match self.refresh_jwks().await {
+            Ok(count) => log::info!("🔑 Reloaded {} JWKS signing keys for unknown kid {}", count, kid),
+            Err(e) => log::warn!("🔑 Failed to reload JWKS for unknown kid {}: {}", kid, e),
+        }
+    }
+}
+
+fn map_jwt_error(err: jsonwebtoken::errors::Error) -> AppError {
+    let message = match err.kind() {
+        ErrorKind::ExpiredSignature => "Token has expired".to_string(),
+        ErrorKind::InvalidAudience => "Invalid token audience".to_string(),
+        ErrorKind::InvalidIssuer => "Invalid token issuer".to_string(),
+        ErrorKind::InvalidSignature => "Invalid token signature".to_string(),
+        ErrorKind::MissingRequiredClaim(claim) => format!("Token is missing required claim: {}", claim),
+        _ => format!("Invalid token: {}", err),
+    };
+    AppError::authentication(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde_json::json;
+
+    const SECRET: &str = "test-secret";
+    const ISSUER: &str = "http://127.0.0.1:54321/auth/v1";
+
+    fn mint(secret: &str, claims: serde_json::Value) -> String {
+        encode(&Header::new(Algorithm::HS256), &claims, &EncodingKey::from_secret(secret.as_bytes())).unwrap()
+    }
+
+    fn claims(overrides: serde_json::Value) -> serde_json::Value {
+        let mut claims = json!({
+            "sub": "00000000-0000-0000-0000-000000000001",
+            "email": "user@example.com",
+            "role": "authenticated",
+            "aud": JWT_DEFAULT_AUDIENCE,
+            "iss": ISSUER,
+            "exp": chrono::Utc::now().timestamp() + 3600,
+        });
+        for (key, value) in overrides.as_object().unwrap() {
+            claims[key] = value.clone();
+        }
+        claims
+    }
+
+    fn verifier() -> JwtVerifier {
+        JwtVerifier::with_secret(Some(SECRET), JWT_DEFAULT_AUDIENCE, ISSUER)
+    }
+
+    fn error_message(result: AppResult<Claims>) -> String {
+        match result {
+            Err(AppError::Authentication(message)) => message,
+            other => panic!("expected authentication error, got {:?}", other.map(|c| c.sub)),
+        }
+    }
+
+    #[tokio::test]
+    async fn accepts_valid_token() {
+        let token = mint(SECRET, claims(json!({})));
+        let claims = verifier().verify(&token).await.unwrap();
+        assert_eq!(claims.sub, "00000000-0000-0000-0000-000000000001");
+        assert_eq!(claims.email.as_deref(), Some("user@example.com"));
+    }
+
+    #[tokio::test]
+    async fn rejects_expired_token() {
+        let token = mint(SECRET, claims(json!({ "exp": chrono::Utc::now().timestamp() - 3600 })));
+        assert_eq!(error_message(verifier().verify(&token).await), "Token has expired");
+    }
+
+    #[tokio::test]
+    async fn rejects_wrong_issuer() {
+        let token = mint(SECRET, claims(json!({ "iss": "https://other.supabase.co/auth/v1" })));
+        assert_eq!(error_message(verifier().verify(&token).await), "Invalid token issuer");
+    }
+
+    #[tokio::test]
+    async fn rejects_wrong_audience() {
+        let token = mint(SECRET, claims(json!({ "aud": "anon" })));
+        assert_eq!(error_message(verifier().verify(&token).await), "Invalid token audience");
+    }
+
+    #[tokio::test]
+    async fn rejects_wrong_secret() {
+        let token = mint("other-secret", claims(json!({})));
+        assert_eq!(error_message(verifier().verify(&token).await), "Invalid token signature");
+    }
+
+    #[tokio::test]
+    async fn rejects_hs256_without_secret() {
+        let token = mint(SECRET, claims(json!({})));
+        let verifier = JwtVerifier::with_secret(None, JWT_DEFAULT_AUDIENCE, ISSUER);
+        assert!(error_message(verifier.verify(&token).await).starts_with("HS256 tokens are not accepted"));
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_kid_without_jwks_url() {
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some("rotated-key".to_string());
+        // 서명 검증 전에 kid 조회에서 실패하므로 서명 부분은 임의 값
+        let token = format!(
+            "{}.{}.c2lnbmF0dXJl",
+            base64::Engine::encode(&base64::engine::general_purpose::URL_SAFE_NO_PAD, serde_json::to_vec(&header).unwrap()),
+            base64::Engine::encode(&base64::engine::general_purpose::URL_SAFE_NO_PAD, claims(json!({})).to_string()),
+        );
+        assert_eq!(error_message(verifier().verify(&token).await), "Unknown signing key: rotated-key");
+    }
+
+    // 키 교체: 캐시에 없는 kid 면 JWKS 를 다시 읽어 검증하고, 재조회는 최소 간격에 한 번만
+    #[tokio::test]
+    async fn reloads_jwks_once_for_unknown_kid() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+        use base64::Engine;
+        use ring::rand::SystemRandom;
+        use ring::signature::{EcdsaKeyPair, KeyPair, ECDSA_P256_SHA256_FIXED_SIGNING};
+
+        let rng = SystemRandom::new();
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &rng).unwrap();
+        let key_pair = EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, pkcs8.as_ref(), &rng).unwrap();
+        let point = key_pair.public_key().as_ref(); // 0x04 || x || y
+        let jwks = json!({ "keys": [{
+            "kty": "EC", "crv": "P-256", "alg": "ES256", "use": "sig", "kid": "rotated-key",
+            "x": URL_SAFE_NO_PAD.encode(&point[1..33]),
+            "y": URL_SAFE_NO_PAD.encode(&point[33..65]),
+        }]});
+
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let counter = fetches.clone();
+        let app = axum::Router::new().route("/jwks", axum::routing::get(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            let jwks = jwks.clone();
+            async move { axum::Json(jwks) }
+        }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let mut verifier = verifier();
+        verifier.jwks_url = Some(format!("http://{}/jwks", addr));
+
+        let mut header = Header::new(Algorithm::ES256);
+        header.kid = Some("rotated-key".to_string());
+        let token = encode(&header, &claims(json!({})), &EncodingKey::from_ec_der(pkcs8.as_ref())).unwrap();
+        assert!(verifier.verify(&token).await.is_ok());
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+        header.kid = Some("unknown-key".to_string());
+        let token = encode(&header, &claims(json!({})), &EncodingKey::from_ec_der(pkcs8.as_ref())).unwrap();
+        assert_eq!(error_message(verifier.verify(&token).await), "Unknown signing key: unknown-key");
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+    }
+}
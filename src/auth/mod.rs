@@ -1,46 +1,88 @@
+pub mod jwt;
+pub mod roles;
+
+pub use jwt::*;
+pub use roles::*;
+
 use serde::{Deserialize, Serialize};
 
+use crate::error::AppResult;
+use crate::utils::constants::ADMIN_ROLE;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthUser {
     pub id: String,
     pub email: String,
     pub role: Option<String>,
+    pub app_role: Option<String>,
+    // RLS 적용 클라이언트에 그대로 전달하기 위한 원본 access token
+    #[serde(skip)]
+    pub token: String,
 }
 
-/// JWT 토큰에서 사용자 정보 추출
-pub fn extract_user_from_token(token: &str) -> Result<AuthUser, Box<dyn std::error::Error>> {
-    // 실제로는 JWT 라이브러리로 토큰 검증 및 파싱
-    // 여기서는 간단한 예시
-    if token.is_empty() {
-        return Err("Token is empty".into());
+impl AuthUser {
+    pub fn from_claims(claims: Claims, token: &str) -> Self {
+        Self {
+            id: claims.sub,
+            email: claims.email.unwrap_or_default(),
+            role: claims.role,
+            app_role: claims.app_metadata.and_then(|m| m.role),
+            token: token.to_string(),
+        }
     }
 
-    // 토큰 검증 로직 (실제로는 Supabase public key로 검증)
-    // 예시 구현
-    Ok(AuthUser {
-        id: "user-uuid-from-jwt".to_string(),
-        email: "user@example.com".to_string(),
-        role: None,
-    })
+    // JWT 클레임(role 또는 app_metadata.role)에 관리자 역할이 있는지
+    pub fn has_admin_claim(&self) -> bool {
+        self.role.as_deref() == Some(ADMIN_ROLE) || self.app_role.as_deref() == Some(ADMIN_ROLE)
+    }
 }
 
-/// 사용자 로그인 상태 확인
-pub fn is_authenticated(token: Option<&str>) -> bool {
-    match token {
-        Some(t) if !t.is_empty() => {
-            // 실제로는 JWT 토큰 유효성 검증
-            extract_user_from_token(t).is_ok()
-        }
-        _ => false,
-    }
+/// JWT 토큰에서 사용자 정보 추출 (서명, exp, aud, iss 검증)
+pub async fn extract_user_from_token(verifier: &JwtVerifier, token: &str) -> AppResult<AuthUser> {
+    let claims = verifier.verify(token).await?;
+    Ok(AuthUser::from_claims(claims, token))
 }
 
-/// 관리자 권한 확인
-pub fn is_admin(token: Option<&str>) -> bool {
-    if let Some(token) = token {
-        if let Ok(user) = extract_user_from_token(token) {
-            return user.role.as_deref() == Some("admin");
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde_json::json;
+
+    use crate::utils::constants::JWT_DEFAULT_AUDIENCE;
+
+    const SECRET: &str = "test-secret";
+    const ISSUER: &str = "http://127.0.0.1:54321/auth/v1";
+
+    async fn user_with(extra: serde_json::Value) -> AuthUser {
+        let mut claims = json!({
+            "sub": "00000000-0000-0000-0000-000000000001",
+            "role": "authenticated",
+            "aud": JWT_DEFAULT_AUDIENCE,
+            "iss": ISSUER,
+            "exp": chrono::Utc::now().timestamp() + 3600,
+        });
+        for (key, value) in extra.as_object().unwrap() {
+            claims[key] = value.clone();
         }
+        let token = encode(&Header::default(), &claims, &EncodingKey::from_secret(SECRET.as_bytes())).unwrap();
+        let verifier = JwtVerifier::with_secret(Some(SECRET), JWT_DEFAULT_AUDIENCE, ISSUER);
+        extract_user_from_token(&verifier, &token).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn has_admin_claim_from_role_or_app_metadata() {
+        assert!(!user_with(json!({})).await.has_admin_claim());
+        assert!(!user_with(json!({ "app_metadata": { "role": "editor" } })).await.has_admin_claim());
+        assert!(user_with(json!({ "role": ADMIN_ROLE })).await.has_admin_claim());
+        assert!(user_with(json!({ "app_metadata": { "role": ADMIN_ROLE } })).await.has_admin_claim());
     }
-    false
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn keeps_original_token_and_email_default() {
+        let user = user_with(json!({})).await;
+        assert_eq!(user.id, "00000000-0000-0000-0000-000000000001");
+        assert_eq!(user.email, "");
+        assert!(!user.token.is_empty());
+    }
+}
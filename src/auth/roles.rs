@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+
+use crate::config::SupabaseConfig;
+use crate::error::{AppError, AppResult};
+use crate::repository::RepositoryFactory;
+
+// 사용자 역할 조회 - 관리자 가드에서 사용 (테스트에서는 고정 구현으로 대체 가능)
+#[async_trait]
+pub trait RoleLookup: Send + Sync {
+    async fn roles_for(&self, user_id: &str) -> AppResult<Vec<String>>;
+}
+
+// user_roles 테이블 기반 역할 조회
+#[derive(Clone)]
+pub struct SupabaseRoleLookup {
+    factory: RepositoryFactory,
+}
+
+impl SupabaseRoleLookup {
+    pub fn new(config: SupabaseConfig) -> Self {
+        Self {
+            factory: RepositoryFactory::new(config),
+        }
+    }
+}
+
+#[async_trait]
+impl RoleLookup for SupabaseRoleLookup {
+    async fn roles_for(&self, user_id: &str) -> AppResult<Vec<String>> {
+        log::info!("🛡️ Looking up roles for user: {}", user_id);
+        let repo = self.factory.admin_user_repo();
+        repo.find_roles_by_user_id(user_id)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to get user roles: {}", e)))
+    }
+}
\ No newline at end of file
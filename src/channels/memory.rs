@@ -0,0 +1,52 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+
+use super::{ChannelError, NotificationChannel};
+use crate::domain::entities::notification::QueuedNotification;
+
+// 메모리 기록 채널 - 외부 서비스 없이 발송된 알림을 모아 두는 테스트/로컬용 채널
+// 복제본끼리 같은 기록을 공유하므로 워커에 넘긴 뒤에도 sent()로 확인할 수 있다.
+#[derive(Clone, Default)]
+pub struct MemoryChannel {
+    sent: Arc<Mutex<Vec<QueuedNotification>>>,
+    failure: Arc<Mutex<Option<ChannelError>>>,
+}
+
+impl MemoryChannel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[allow(dead_code)] // 테스트에서 발송 내역 확인용
+    pub fn sent(&self) -> Vec<QueuedNotification> {
+        self.sent.lock().map(|sent| sent.clone()).unwrap_or_default()
+    }
+
+    // 이후 발송을 지정한 오류로 실패시킴 (None이면 정상 발송으로 복구)
+    #[allow(dead_code)] // 테스트에서 재시도 경로 확인용
+    pub fn fail_with(&self, error: Option<ChannelError>) {
+        if let Ok(mut failure) = self.failure.lock() {
+            *failure = error;
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for MemoryChannel {
+    fn kind(&self) -> &'static str {
+        "memory"
+    }
+
+    async fn send(&self, message: &QueuedNotification) -> Result<(), ChannelError> {
+        if let Some(error) = self.failure.lock().ok().and_then(|failure| failure.clone()) {
+            return Err(error);
+        }
+
+        self.sent
+            .lock()
+            .map_err(|_| ChannelError::retryable("Memory channel lock poisoned"))?
+            .push(message.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+pub mod smtp;
+pub mod web_push;
+pub mod webhook;
+pub mod memory;
+
+pub use smtp::*;
+pub use web_push::*;
+pub use webhook::*;
+pub use memory::*;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::config::NotificationChannelConfig;
+use crate::domain::entities::notification::QueuedNotification;
+
+// 알림 발송 채널 - 큐 워커가 notification_queue 항목을 실제 외부 서비스로 보낼 때 사용
+#[async_trait]
+pub trait NotificationChannel: Send + Sync {
+    // 발송 방식 이름 (smtp, web_push, webhook, memory)
+    fn kind(&self) -> &'static str;
+
+    async fn send(&self, message: &QueuedNotification) -> Result<(), ChannelError>;
+}
+
+// 발송 실패 - retryable 이 false 면 큐 워커가 재시도 없이 failed 처리
+#[derive(Debug, Clone, Error)]
+#[error("{message}")]
+pub struct ChannelError {
+    pub message: String,
+    pub retryable: bool,
+}
+
+impl ChannelError {
+    pub fn retryable<T: Into<String>>(message: T) -> Self {
+        Self { message: message.into(), retryable: true }
+    }
+
+    pub fn permanent<T: Into<String>>(message: T) -> Self {
+        Self { message: message.into(), retryable: false }
+    }
+}
+
+// 큐 채널(push/email/sms) → 발송 채널 매핑
+#[derive(Clone, Default)]
+pub struct NotificationChannels {
+    routes: HashMap<String, Arc<dyn NotificationChannel>>,
+}
+
+impl NotificationChannels {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_channel(mut self, queue_channel: &str, channel: Arc<dyn NotificationChannel>) -> Self {
+        self.routes.insert(queue_channel.to_string(), channel);
+        self
+    }
+
+    // 설정으로 채널 구성 - 필요한 설정이 없는 발송 방식은 건너뛰고 경고만 남김
+    pub fn from_config(config: &NotificationChannelConfig) -> Self {
+        let mut channels = Self::new();
+
+        for (queue_channel, kind) in &config.routes {
+            let channel: Result<Arc<dyn NotificationChannel>, String> = match kind.as_str() {
+                "smtp" => config.smtp.clone()
+                    .map(|smtp| Arc::new(SmtpChannel::new(smtp)) as Arc<dyn NotificationChannel>)
+                    .ok_or_else(|| "SMTP_HOST is not set".to_string()),
+                "web_push" => match &config.web_push {
+                    Some(web_push) => WebPushChannel::new(web_push).map(|c| Arc::new(c) as Arc<dyn NotificationChannel>),
+                    None => Err("VAPID_PUBLIC_KEY/VAPID_PRIVATE_KEY are not set".to_string()),
+                },
+                "webhook" => config.webhook.clone()
+                    .map(|webhook| Arc::new(WebhookChannel::new(webhook)) as Arc<dyn NotificationChannel>)
+                    .ok_or_else(|| "NOTIFICATION_WEBHOOK_URL is not set".to_string()),
+                "memory" => Ok(Arc::new(MemoryChannel::new())),
+                other => Err(format!("Unknown notification sender: {}", other)),
+            };
+
+            match channel {
+                Ok(channel) => {
+                    log::info!("📨 Notification channel '{}' → {}", queue_channel, channel.kind());
+                    channels = channels.with_channel(queue_channel, channel);
+                }
+                Err(e) => log::warn!("⚠️ Notification channel '{}' ({}) disabled: {}", queue_channel, kind, e),
+            }
+        }
+
+        channels
+    }
+
+    pub fn get(&self, queue_channel: &str) -> Option<&Arc<dyn NotificationChannel>> {
+        self.routes.get(queue_channel)
+    }
+}
@@ -0,0 +1,283 @@
+use std::net::IpAddr;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio_native_tls::{native_tls, TlsConnector};
+
+use super::{ChannelError, NotificationChannel};
+use crate::config::SmtpConfig;
+use crate::domain::entities::notification::QueuedNotification;
+use crate::utils::constants::{NOTIFICATION_SEND_TIMEOUT_SECS, SMTP_LOCAL_CATCHER_HOSTS};
+
+// SMTP 이메일 채널 - 로컬 SMTP 캐처(MailHog, Mailpit 등)는 평문으로, 실서버는 STARTTLS + AUTH PLAIN으로 연결
+pub struct SmtpChannel {
+    config: SmtpConfig,
+}
+
+impl SmtpChannel {
+    pub fn new(config: SmtpConfig) -> Self {
+        Self { config }
+    }
+
+    async fn deliver(&self, to: &str, message: &str) -> Result<(), ChannelError> {
+        let stream = TcpStream::connect((self.config.host.as_str(), self.config.port))
+            .await
+            .map_err(|e| ChannelError::retryable(format!("SMTP connect failed: {}", e)))?;
+
+        let mut conn = SmtpConnection::new(stream);
+        conn.expect(220).await?;
+        conn.command(&format!("EHLO {}", self.config.helo_name), 250).await?;
+
+        if !self.config.starttls {
+            return self.transaction(conn, to, message, false).await;
+        }
+
+        conn.command("STARTTLS", 220).await?;
+        let connector = native_tls::TlsConnector::new()
+            .map_err(|e| ChannelError::retryable(format!("TLS setup failed: {}", e)))?;
+        let tls = TlsConnector::from(connector)
+            .connect(&self.config.host, conn.into_inner())
+            .await
+            .map_err(|e| ChannelError::retryable(format!("STARTTLS handshake failed: {}", e)))?;
+
+        let mut conn = SmtpConnection::new(tls);
+        conn.command(&format!("EHLO {}", self.config.helo_name), 250).await?;
+        self.transaction(conn, to, message, true).await
+    }
+
+    async fn transaction<S: AsyncRead + AsyncWrite + Unpin>(&self, mut conn: SmtpConnection<S>, to: &str, message: &str, tls: bool) -> Result<(), ChannelError> {
+        if let (Some(username), Some(password)) = (&self.config.username, &self.config.password) {
+            // 평문 연결에서 AUTH PLAIN 은 비밀번호를 그대로 보내므로 로컬 캐처가 아니면 거부
+            if !tls && !is_local_host(&self.config.host) {
+                return Err(ChannelError::permanent(format!(
+                    "Refusing SMTP AUTH without TLS to {} (set SMTP_STARTTLS=true)", self.config.host
+                )));
+            }
+            let credentials = STANDARD.encode(format!("\0{}\0{}", username, password));
+            conn.command(&format!("AUTH PLAIN {}", credentials), 235).await?;
+        }
+
+        conn.command(&format!("MAIL FROM:<{}>", self.config.from), 250).await?;
+        conn.command(&format!("RCPT TO:<{}>", to), 250).await?;
+        conn.command("DATA", 354).await?;
+        conn.command(&format!("{}\r\n.", message), 250).await?;
+        // 메일은 이미 접수됐으므로 QUIT 실패는 무시
+        let _ = conn.command("QUIT", 221).await;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for SmtpChannel {
+    fn kind(&self) -> &'static str {
+        "smtp"
+    }
+
+    async fn send(&self, message: &QueuedNotification) -> Result<(), ChannelError> {
+        let to = message.email.as_deref()
+            .filter(|email| !email.is_empty())
+            .ok_or_else(|| ChannelError::permanent("User has no email address"))?;
+        if to.contains(['\r', '\n', '<', '>']) {
+            return Err(ChannelError::permanent("Invalid email address"));
+        }
+
+        let mime = build_message(&self.config.from, to, message, chrono::Utc::now());
+        tokio::time::timeout(Duration::from_secs(NOTIFICATION_SEND_TIMEOUT_SECS), self.deliver(to, &mime))
+            .await
+            .map_err(|_| ChannelError::retryable("SMTP session timed out"))?
+    }
+}
+
+// 응답 코드 단위 SMTP 대화
+struct SmtpConnection<S> {
+    stream: BufReader<S>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> SmtpConnection<S> {
+    fn new(stream: S) -> Self {
+        Self { stream: BufReader::new(stream) }
+    }
+
+    fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+
+    async fn command(&mut self, line: &str, expected: u16) -> Result<(), ChannelError> {
+        self.stream
+            .write_all(format!("{}\r\n", line).as_bytes())
+            .await
+            .map_err(|e| ChannelError::retryable(format!("SMTP write failed: {}", e)))?;
+        self.stream.flush().await.map_err(|e| ChannelError::retryable(format!("SMTP write failed: {}", e)))?;
+        self.expect(expected).await
+    }
+
+    // 여러 줄 응답("250-...")은 마지막 줄("250 ...")까지 읽음
+    async fn expect(&mut self, expected: u16) -> Result<(), ChannelError> {
+        loop {
+            let mut line = String::new();
+            let read = self.stream
+                .read_line(&mut line)
+                .await
+                .map_err(|e| ChannelError::retryable(format!("SMTP read failed: {}", e)))?;
+            if read == 0 {
+                return Err(ChannelError::retryable("SMTP connection closed"));
+            }
+
+            let code: u16 = line.get(..3).and_then(|code| code.parse().ok())
+                .ok_or_else(|| ChannelError::retryable(format!("Invalid SMTP reply: {}", line.trim_end())))?;
+            if line.as_bytes().get(3) == Some(&b'-') {
+                continue;
+            }
+
+            return match code {
+                c if c == expected || (expected == 250 && c == 251) => Ok(()),
+                // 4xx는 일시적 오류, 5xx는 영구 오류 (RFC 5321)
+                400..=499 => Err(ChannelError::retryable(format!("SMTP {}", line.trim_end()))),
+                _ => Err(ChannelError::permanent(format!("SMTP {}", line.trim_end()))),
+            };
+        }
+    }
+}
+
+// loopback 주소 또는 로컬 SMTP 캐처 호스트
+pub fn is_local_host(host: &str) -> bool {
+    let host = host.trim_start_matches('[').trim_end_matches(']').to_ascii_lowercase();
+    host == "localhost"
+        || host.ends_with(".localhost")
+        || SMTP_LOCAL_CATCHER_HOSTS.contains(&host.as_str())
+        || host.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
+// 텍스트 메일 본문 (UTF-8 제목은 RFC 2047 인코딩, 본문은 base64 - 줄 첫 '.' 이스케이프가 필요 없음)
+pub fn build_message(from: &str, to: &str, message: &QueuedNotification, now: chrono::DateTime<chrono::Utc>) -> String {
+    let body = STANDARD.encode(message.content.as_bytes());
+    let body_lines: Vec<&str> = body
+        .as_bytes()
+        .chunks(76)
+        .map(|chunk| std::str::from_utf8(chunk).unwrap_or_default())
+        .collect();
+
+    format!(
+        "From: <{from}>\r\n\
+         To: <{to}>\r\n\
+         Subject: =?UTF-8?B?{subject}?=\r\n\
+         Date: {date}\r\n\
+         Message-ID: <notification-{id}-{queue_id}@{domain}>\r\n\
+         MIME-Version: 1.0\r\n\
+         Content-Type: text/plain; charset=UTF-8\r\n\
+         Content-Transfer-Encoding: base64\r\n\
+         \r\n\
+         {body}",
+        from = from,
+        to = to,
+        subject = STANDARD.encode(message.title.as_bytes()),
+        date = now.to_rfc2822(),
+        id = message.notification_id,
+        queue_id = message.id,
+        domain = from.rsplit_once('@').map(|(_, domain)| domain).unwrap_or("localhost"),
+        body = body_lines.join("\r\n"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_hosts_allow_cleartext_auth() {
+        for host in ["localhost", "LOCALHOST", "mail.localhost", "127.0.0.1", "127.0.0.2", "::1", "[::1]", "mailhog", "mailpit"] {
+            assert!(is_local_host(host), "{}", host);
+        }
+        for host in ["smtp.example.com", "10.0.0.5", "192.168.0.10", "localhost.example.com", "mailhog.example.com", "::2"] {
+            assert!(!is_local_host(host), "{}", host);
+        }
+    }
+
+    fn message() -> QueuedNotification {
+        QueuedNotification {
+            id: 7,
+            notification_id: 42,
+            user_id: "00000000-0000-0000-0000-000000000001".to_string(),
+            channel: "email".to_string(),
+            attempts: 1,
+            max_attempts: 5,
+            notification_type: "price_drop".to_string(),
+            title: "가격 인하".to_string(),
+            content: "10000 KRW → 9000 KRW".to_string(),
+            data: None,
+            email: Some("user@example.com".to_string()),
+            push_subscriptions: Vec::new(),
+        }
+    }
+
+    // 서버 응답을 미리 써 둔 연결로 expect 실행
+    async fn expect_reply(reply: &str, expected: u16) -> Result<(), ChannelError> {
+        let (client, mut server) = tokio::io::duplex(4096);
+        server.write_all(reply.as_bytes()).await.unwrap();
+        drop(server);
+        SmtpConnection::new(client).expect(expected).await
+    }
+
+    #[tokio::test]
+    async fn reads_multiline_reply_until_last_line() {
+        let ehlo = "250-mail.example.com greets you\r\n250-PIPELINING\r\n250-STARTTLS\r\n250 AUTH PLAIN LOGIN\r\n";
+        assert!(expect_reply(ehlo, 250).await.is_ok());
+
+        // 마지막 줄의 코드로 판단
+        let error = expect_reply("250-first\r\n554 rejected\r\n", 250).await.unwrap_err();
+        assert!(!error.retryable);
+        assert_eq!(error.message, "SMTP 554 rejected");
+    }
+
+    #[tokio::test]
+    async fn classifies_smtp_reply_codes() {
+        assert!(expect_reply("220 ready\r\n", 220).await.is_ok());
+        assert!(expect_reply("251 will forward\r\n", 250).await.is_ok());
+
+        let temporary = expect_reply("421 service not available\r\n", 250).await.unwrap_err();
+        assert!(temporary.retryable);
+        assert_eq!(temporary.message, "SMTP 421 service not available");
+
+        let permanent = expect_reply("535 authentication failed\r\n", 235).await.unwrap_err();
+        assert!(!permanent.retryable);
+
+        let invalid = expect_reply("hello\r\n", 220).await.unwrap_err();
+        assert!(invalid.retryable);
+        assert_eq!(invalid.message, "Invalid SMTP reply: hello");
+
+        // 마지막 줄 전에 연결이 끊긴 경우
+        let closed = expect_reply("250-partial\r\n", 250).await.unwrap_err();
+        assert!(closed.retryable);
+        assert_eq!(closed.message, "SMTP connection closed");
+    }
+
+    #[test]
+    fn builds_base64_message_with_encoded_subject() {
+        let now = chrono::DateTime::parse_from_rfc3339("2026-10-17T12:00:00Z").unwrap().with_timezone(&chrono::Utc);
+        let mime = build_message("noreply@example.com", "user@example.com", &message(), now);
+        let (headers, body) = mime.split_once("\r\n\r\n").unwrap();
+
+        assert!(headers.contains("From: <noreply@example.com>\r\n"));
+        assert!(headers.contains("To: <user@example.com>\r\n"));
+        assert!(headers.contains(&format!("Subject: =?UTF-8?B?{}?=\r\n", STANDARD.encode("가격 인하"))));
+        assert!(headers.contains("Date: Sat, 17 Oct 2026 12:00:00 +0000\r\n"));
+        assert!(headers.contains("Message-ID: <notification-42-7@example.com>\r\n"));
+        assert!(headers.ends_with("Content-Transfer-Encoding: base64"));
+        assert_eq!(STANDARD.decode(body).unwrap(), "10000 KRW → 9000 KRW".as_bytes());
+    }
+
+    #[test]
+    fn wraps_long_body_at_76_columns() {
+        let mut message = message();
+        message.content = "가".repeat(100);
+        let mime = build_message("noreply@example.com", "user@example.com", &message, chrono::Utc::now());
+        let body = mime.split_once("\r\n\r\n").unwrap().1;
+
+        assert!(body.split("\r\n").all(|line| line.len() <= 76));
+        assert_eq!(STANDARD.decode(body.replace("\r\n", "")).unwrap(), message.content.as_bytes());
+    }
+}
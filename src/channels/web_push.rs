@@ -0,0 +1,275 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use reqwest::{StatusCode, Url};
+use ring::rand::{SecureRandom, SystemRandom};
+use ring::signature::{EcdsaKeyPair, ECDSA_P256_SHA256_FIXED_SIGNING};
+use ring::{aead, agreement, hkdf};
+use serde_json::json;
+
+use super::{ChannelError, NotificationChannel};
+use crate::config::WebPushConfig;
+use crate::domain::entities::notification::{PushSubscription, QueuedNotification};
+use crate::utils::constants::{NOTIFICATION_SEND_TIMEOUT_SECS, VAPID_TOKEN_EXPIRY_SECS, WEB_PUSH_TTL_SECS};
+
+// aes128gcm 레코드 크기 / 한 레코드에 담을 수 있는 최대 평문 (4096 - 태그 16 - 구분자 1 - 헤더 86)
+const RECORD_SIZE: u32 = 4096;
+const MAX_PAYLOAD_LEN: usize = 3993;
+
+// Web Push 채널 (RFC 8030 전송, RFC 8291 aes128gcm 암호화, RFC 8292 VAPID 인증)
+// 사용자가 등록한 모든 브라우저 구독으로 보내고, 하나라도 성공하면 발송 성공으로 본다.
+pub struct WebPushChannel {
+    key_pair: EcdsaKeyPair,
+    public_key: String,
+    subject: String,
+    client: reqwest::Client,
+    rng: SystemRandom,
+}
+
+impl WebPushChannel {
+    pub fn new(config: &WebPushConfig) -> Result<Self, String> {
+        let rng = SystemRandom::new();
+        let public_key = decode_base64url(&config.vapid_public_key).ok_or("Invalid VAPID_PUBLIC_KEY encoding")?;
+        let private_key = decode_base64url(&config.vapid_private_key).ok_or("Invalid VAPID_PRIVATE_KEY encoding")?;
+        let key_pair = EcdsaKeyPair::from_private_key_and_public_key(&ECDSA_P256_SHA256_FIXED_SIGNING, &private_key, &public_key, &rng)
+            .map_err(|e| format!("Invalid VAPID key pair: {}", e))?;
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(NOTIFICATION_SEND_TIMEOUT_SECS))
+            .build()
+            .unwrap_or_default();
+
+        Ok(Self {
+            key_pair,
+            public_key: URL_SAFE_NO_PAD.encode(public_key),
+            subject: config.subject.clone(),
+            client,
+            rng,
+        })
+    }
+
+    // VAPID Authorization 헤더 - aud는 푸시 서비스 origin
+    fn vapid_authorization(&self, endpoint: &Url) -> Result<String, ChannelError> {
+        let header = URL_SAFE_NO_PAD.encode(json!({ "typ": "JWT", "alg": "ES256" }).to_string());
+        let claims = URL_SAFE_NO_PAD.encode(json!({
+            "aud": endpoint.origin().ascii_serialization(),
+            "exp": chrono::Utc::now().timestamp() + VAPID_TOKEN_EXPIRY_SECS,
+            "sub": self.subject,
+        }).to_string());
+
+        let signing_input = format!("{}.{}", header, claims);
+        let signature = self.key_pair
+            .sign(&self.rng, signing_input.as_bytes())
+            .map_err(|_| ChannelError::retryable("Failed to sign VAPID token"))?;
+
+        Ok(format!("vapid t={}.{}, k={}", signing_input, URL_SAFE_NO_PAD.encode(signature.as_ref()), self.public_key))
+    }
+
+    async fn push(&self, subscription: &PushSubscription, payload: &[u8]) -> Result<(), ChannelError> {
+        let endpoint = Url::parse(&subscription.endpoint)
+            .map_err(|_| ChannelError::permanent(format!("Invalid push endpoint: {}", subscription.endpoint)))?;
+        let (p256dh, auth) = match (decode_base64url(&subscription.p256dh), decode_base64url(&subscription.auth)) {
+            (Some(p256dh), Some(auth)) => (p256dh, auth),
+            _ => return Err(ChannelError::permanent("Invalid push subscription keys")),
+        };
+
+        let body = encrypt_payload(payload, &p256dh, &auth, &self.rng)?;
+        let response = self.client
+            .post(endpoint.clone())
+            .header("Authorization", self.vapid_authorization(&endpoint)?)
+            .header("Content-Encoding", "aes128gcm")
+            .header("Content-Type", "application/octet-stream")
+            .header("TTL", WEB_PUSH_TTL_SECS.to_string())
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| ChannelError::retryable(format!("Push request failed: {}", e)))?;
+
+        push_result(response.status())
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for WebPushChannel {
+    fn kind(&self) -> &'static str {
+        "web_push"
+    }
+
+    async fn send(&self, message: &QueuedNotification) -> Result<(), ChannelError> {
+        if message.push_subscriptions.is_empty() {
+            return Err(ChannelError::permanent("User has no push subscriptions"));
+        }
+
+        let payload = json!({
+            "notification_id": message.notification_id,
+            "type": message.notification_type,
+            "title": message.title,
+            "body": message.content,
+            "data": message.data,
+        })
+        .to_string();
+
+        let mut last_error = None;
+        let mut delivered = false;
+        for subscription in &message.push_subscriptions {
+            match self.push(subscription, payload.as_bytes()).await {
+                Ok(()) => delivered = true,
+                Err(e) => {
+                    log::warn!("⚠️ Web push to {} failed: {}", subscription.endpoint, e);
+                    // 하나라도 재시도 가능한 실패가 있으면 전체를 재시도 대상으로
+                    if last_error.as_ref().is_none_or(|prev: &ChannelError| !prev.retryable) {
+                        last_error = Some(e);
+                    }
+                }
+            }
+        }
+
+        match (delivered, last_error) {
+            (true, _) | (false, None) => Ok(()),
+            (false, Some(error)) => Err(error),
+        }
+    }
+}
+
+// 응답 상태 → 발송 결과 (404/410은 만료된 구독)
+pub fn push_result(status: StatusCode) -> Result<(), ChannelError> {
+    match status {
+        s if s.is_success() => Ok(()),
+        StatusCode::NOT_FOUND | StatusCode::GONE => Err(ChannelError::permanent(format!("Push subscription expired ({})", status))),
+        s if s.is_server_error() || s == StatusCode::TOO_MANY_REQUESTS => Err(ChannelError::retryable(format!("Push service responded {}", status))),
+        _ => Err(ChannelError::permanent(format!("Push service responded {}", status))),
+    }
+}
+
+// RFC 8291 메시지 암호화 - 임시 ECDH 키와 구독의 p256dh/auth로 CEK/nonce를 유도해 단일 aes128gcm 레코드 생성
+// 결과: salt(16) | rs(4) | idlen(1) | keyid(임시 공개키 65) | 암호문+태그
+pub fn encrypt_payload(payload: &[u8], p256dh: &[u8], auth: &[u8], rng: &dyn SecureRandom) -> Result<Vec<u8>, ChannelError> {
+    if payload.len() > MAX_PAYLOAD_LEN {
+        return Err(ChannelError::permanent(format!("Push payload too large ({} bytes)", payload.len())));
+    }
+
+    let crypto_error = |step: &str| ChannelError::retryable(format!("Web push encryption failed: {}", step));
+
+    let ephemeral = agreement::EphemeralPrivateKey::generate(&agreement::ECDH_P256, rng).map_err(|_| crypto_error("key generation"))?;
+    let server_public = ephemeral.compute_public_key().map_err(|_| crypto_error("public key"))?;
+    let client_public = agreement::UnparsedPublicKey::new(&agreement::ECDH_P256, p256dh);
+    let shared_secret = agreement::agree_ephemeral(ephemeral, &client_public, |secret| secret.to_vec())
+        .map_err(|_| ChannelError::permanent("Invalid push subscription p256dh key"))?;
+
+    let mut salt = [0u8; 16];
+    rng.fill(&mut salt).map_err(|_| crypto_error("salt"))?;
+
+    // IKM = HKDF(auth, ecdh_secret, "WebPush: info" || 0x00 || ua_public || as_public)
+    let mut key_info = b"WebPush: info\0".to_vec();
+    key_info.extend_from_slice(p256dh);
+    key_info.extend_from_slice(server_public.as_ref());
+    let ikm = hkdf_expand(auth, &shared_secret, &key_info, 32).ok_or_else(|| crypto_error("ikm"))?;
+
+    let cek = hkdf_expand(&salt, &ikm, b"Content-Encoding: aes128gcm\0", 16).ok_or_else(|| crypto_error("cek"))?;
+    let nonce = hkdf_expand(&salt, &ikm, b"Content-Encoding: nonce\0", 12).ok_or_else(|| crypto_error("nonce"))?;
+
+    // 마지막(유일한) 레코드 구분자 0x02, 패딩 없음
+    let mut record = payload.to_vec();
+    record.push(0x02);
+
+    let key = aead::LessSafeKey::new(aead::UnboundKey::new(&aead::AES_128_GCM, &cek).map_err(|_| crypto_error("cek key"))?);
+    let nonce = aead::Nonce::try_assume_unique_for_key(&nonce).map_err(|_| crypto_error("nonce"))?;
+    key.seal_in_place_append_tag(nonce, aead::Aad::empty(), &mut record).map_err(|_| crypto_error("seal"))?;
+
+    let server_public = server_public.as_ref();
+    let mut body = Vec::with_capacity(21 + server_public.len() + record.len());
+    body.extend_from_slice(&salt);
+    body.extend_from_slice(&RECORD_SIZE.to_be_bytes());
+    body.push(server_public.len() as u8);
+    body.extend_from_slice(server_public);
+    body.extend_from_slice(&record);
+    Ok(body)
+}
+
+struct OutputLen(usize);
+
+impl hkdf::KeyType for OutputLen {
+    fn len(&self) -> usize {
+        self.0
+    }
+}
+
+fn hkdf_expand(salt: &[u8], secret: &[u8], info: &[u8], len: usize) -> Option<Vec<u8>> {
+    let prk = hkdf::Salt::new(hkdf::HKDF_SHA256, salt).extract(secret);
+    let info = [info];
+    let okm = prk.expand(&info, OutputLen(len)).ok()?;
+    let mut out = vec![0u8; len];
+    okm.fill(&mut out).ok()?;
+    Some(out)
+}
+
+// 브라우저가 주는 키는 패딩 유무가 섞여 있으므로 패딩을 떼고 디코딩
+fn decode_base64url(value: &str) -> Option<Vec<u8>> {
+    URL_SAFE_NO_PAD.decode(value.trim().trim_end_matches('=')).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn b64(value: &str) -> Vec<u8> {
+        decode_base64url(value).unwrap()
+    }
+
+    // RFC 8291 Appendix A - 고정된 임시 키(as_private)와 salt 로 암호화하면 예시 메시지와 같아야 함
+    #[test]
+    #[allow(deprecated)]
+    fn encrypts_rfc8291_appendix_a_example() {
+        let as_private = b64("yfWPiYE-n46HLnH0KqZOF1fJJU3MYrct3AELtAQ-oRw");
+        let salt = b64("DGv6ra1nlYgDCS1FRnbzlw");
+        let rng = ring::test::rand::FixedSliceSequenceRandom {
+            bytes: &[&as_private, &salt],
+            current: core::cell::UnsafeCell::new(0),
+        };
+
+        let body = encrypt_payload(
+            b"When I grow up, I want to be a watermelon",
+            &b64("BCVxsr7N_eNgVRqvHtD0zTZsEc6-VV-JvLexhqUzORcxaOzi6-AYWXvTBHm4bjyPjs7Vd8pZGH6SRpkNtoIAiw4"),
+            &b64("BTBZMqHH6r4Tts7J_aSIgg"),
+            &rng,
+        )
+        .unwrap();
+
+        assert_eq!(
+            URL_SAFE_NO_PAD.encode(&body),
+            "DGv6ra1nlYgDCS1FRnbzlwAAEABBBP4z9KsN6nGRTbVYI_c7VJSPQTBtkgcy27mlmlMoZIIgDll6e3vCYLocInmYWAmS6TlzAC8wEqKK6PBru3jl7A_yl95bQpu6cVPTpK4Mqgkf1CXztLVBSt2Ks3oZwbuwXPXLWyouBWLVWGNWQexSgSxsj_Qulcy4a-fN"
+        );
+    }
+
+    #[test]
+    fn rejects_payload_larger_than_one_record() {
+        let rng = SystemRandom::new();
+        let error = encrypt_payload(&vec![b'x'; MAX_PAYLOAD_LEN + 1], &[4; 65], &[0; 16], &rng).unwrap_err();
+        assert!(!error.retryable);
+        assert_eq!(error.message, format!("Push payload too large ({} bytes)", MAX_PAYLOAD_LEN + 1));
+    }
+
+    #[test]
+    fn maps_push_service_status() {
+        for status in [StatusCode::OK, StatusCode::CREATED, StatusCode::ACCEPTED] {
+            assert!(push_result(status).is_ok(), "{}", status);
+        }
+
+        // 재시도: 5xx, 429
+        for status in [StatusCode::INTERNAL_SERVER_ERROR, StatusCode::BAD_GATEWAY, StatusCode::SERVICE_UNAVAILABLE, StatusCode::TOO_MANY_REQUESTS] {
+            assert!(push_result(status).unwrap_err().retryable, "{}", status);
+        }
+
+        // 영구 실패: 만료된 구독(404/410)과 그 밖의 4xx
+        for status in [StatusCode::NOT_FOUND, StatusCode::GONE] {
+            let error = push_result(status).unwrap_err();
+            assert!(!error.retryable, "{}", status);
+            assert!(error.message.starts_with("Push subscription expired"), "{}", error.message);
+        }
+        for status in [StatusCode::BAD_REQUEST, StatusCode::UNAUTHORIZED, StatusCode::FORBIDDEN, StatusCode::PAYLOAD_TOO_LARGE] {
+            assert!(!push_result(status).unwrap_err().retryable, "{}", status);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use ring::hmac;
+use serde_json::json;
+
+use super::{ChannelError, NotificationChannel};
+use crate::config::WebhookConfig;
+use crate::domain::entities::notification::QueuedNotification;
+use crate::utils::constants::NOTIFICATION_SEND_TIMEOUT_SECS;
+
+// 범용 HTTP 웹훅 채널 - 알림을 JSON으로 POST (SMS 게이트웨이, 사내 메신저 연동 등)
+// 같은 큐 항목이 다시 전달될 수 있으므로 수신 측은 X-Duk-Delivery-Id 로 중복을 걸러야 한다.
+pub struct WebhookChannel {
+    config: WebhookConfig,
+    client: reqwest::Client,
+}
+
+impl WebhookChannel {
+    pub fn new(config: WebhookConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(NOTIFICATION_SEND_TIMEOUT_SECS))
+            .build()
+            .unwrap_or_default();
+
+        Self { config, client }
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for WebhookChannel {
+    fn kind(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn send(&self, message: &QueuedNotification) -> Result<(), ChannelError> {
+        let body = json!({
+            "delivery_id": message.id,
+            "notification_id": message.notification_id,
+            "user_id": message.user_id,
+            "channel": message.channel,
+            "type": message.notification_type,
+            "title": message.title,
+            "content": message.content,
+            "data": message.data,
+            "email": message.email,
+        })
+        .to_string();
+
+        let mut request = self.client
+            .post(&self.config.url)
+            .header("Content-Type", "application/json")
+            .header("X-Duk-Delivery-Id", message.id.to_string());
+
+        if let Some(secret) = &self.config.secret {
+            request = request.header("X-Duk-Signature", format!("sha256={}", sign_body(secret, &body)));
+        }
+
+        let response = request
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| ChannelError::retryable(format!("Webhook request failed: {}", e)))?;
+
+        webhook_result(response.status())
+    }
+}
+
+// 본문 HMAC-SHA256 서명 (hex)
+pub fn sign_body(secret: &str, body: &str) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    hmac::sign(&key, body.as_bytes())
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+// 응답 상태 → 발송 결과 (5xx, 408, 429는 재시도, 그 외 4xx는 재시도해도 같은 결과)
+pub fn webhook_result(status: StatusCode) -> Result<(), ChannelError> {
+    if status.is_success() {
+        Ok(())
+    } else if status.is_server_error() || status == StatusCode::REQUEST_TIMEOUT || status == StatusCode::TOO_MANY_REQUESTS {
+        Err(ChannelError::retryable(format!("Webhook responded {}", status)))
+    } else {
+        Err(ChannelError::permanent(format!("Webhook responded {}", status)))
+    }
+}
@@ -0,0 +1,61 @@
+use std::env;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::utils::constants::{
+    CRAWLER_DEFAULT_USER_AGENT, CRAWLER_DOMAIN_CONCURRENCY, CRAWLER_DOMAIN_DELAY_MS, CRAWLER_MAX_CRAWL_DELAY_SECS,
+    CRAWLER_REQUEST_TIMEOUT_SECS,
+};
+
+// 상점 크롤러 설정
+//   CRAWLER_USER_AGENT          요청 User-Agent (기본 CRAWLER_DEFAULT_USER_AGENT, robots.txt 그룹도 이 이름으로 고름)
+//   CRAWLER_PROFILES_PATH       플랫폼/도메인별 CSS 선택자 프로필 JSON (내장 프로필에 추가/덮어쓰기)
+//   CRAWLER_FIXTURE_DIR         지정하면 네트워크 대신 이 디렉터리의 HTML 파일로 페이지를 읽음 (fixtures/crawler)
+//   CRAWLER_DOMAIN_CONCURRENCY  상점 도메인별 동시 요청 수 (기본 CRAWLER_DOMAIN_CONCURRENCY)
+//   CRAWLER_DOMAIN_DELAY_MS     상점 도메인별 요청 시작 간격 (기본 CRAWLER_DOMAIN_DELAY_MS, robots.txt Crawl-delay 가 더 길면 그것)
+//   CRAWLER_SCHEDULE_MINUTES    지정하면 이 간격으로 도메인이 있는 모든 상점을 크롤링
+#[derive(Debug, Clone)]
+pub struct CrawlerConfig {
+    pub user_agent: String,
+    pub request_timeout_secs: u64,
+    pub profiles_path: Option<PathBuf>,
+    pub fixture_dir: Option<PathBuf>,
+    pub domain_concurrency: usize,
+    pub domain_delay: Duration,
+    pub max_crawl_delay: Duration,
+    pub schedule_interval: Option<Duration>,
+}
+
+impl CrawlerConfig {
+    pub fn from_env() -> Result<Self, String> {
+        let domain_concurrency = match env_var("CRAWLER_DOMAIN_CONCURRENCY") {
+            Some(value) => value.parse().ok().filter(|n| *n > 0)
+                .ok_or_else(|| format!("Invalid CRAWLER_DOMAIN_CONCURRENCY: {}", value))?,
+            None => CRAWLER_DOMAIN_CONCURRENCY,
+        };
+        let domain_delay_ms = match env_var("CRAWLER_DOMAIN_DELAY_MS") {
+            Some(value) => value.parse().map_err(|_| format!("Invalid CRAWLER_DOMAIN_DELAY_MS: {}", value))?,
+            None => CRAWLER_DOMAIN_DELAY_MS,
+        };
+        let schedule_minutes: Option<u64> = match env_var("CRAWLER_SCHEDULE_MINUTES") {
+            Some(value) => Some(value.parse().ok().filter(|n| *n > 0)
+                .ok_or_else(|| format!("Invalid CRAWLER_SCHEDULE_MINUTES: {}", value))?),
+            None => None,
+        };
+
+        Ok(Self {
+            user_agent: env_var("CRAWLER_USER_AGENT").unwrap_or_else(|| CRAWLER_DEFAULT_USER_AGENT.to_string()),
+            request_timeout_secs: CRAWLER_REQUEST_TIMEOUT_SECS,
+            profiles_path: env_var("CRAWLER_PROFILES_PATH").map(PathBuf::from),
+            fixture_dir: env_var("CRAWLER_FIXTURE_DIR").map(PathBuf::from),
+            domain_concurrency,
+            domain_delay: Duration::from_millis(domain_delay_ms),
+            max_crawl_delay: Duration::from_secs(CRAWLER_MAX_CRAWL_DELAY_SECS),
+            schedule_interval: schedule_minutes.map(|minutes| Duration::from_secs(minutes * 60)),
+        })
+    }
+}
+
+fn env_var(key: &str) -> Option<String> {
+    env::var(key).ok().filter(|value| !value.is_empty())
+}
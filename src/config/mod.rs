@@ -1,3 +1,9 @@
 pub mod supabase;
+pub mod notification;
+pub mod realtime;
+pub mod crawler;
 
-pub use supabase::*;
\ No newline at end of file
+pub use supabase::*;
+pub use notification::*;
+pub use realtime::*;
+pub use crawler::*;
@@ -0,0 +1,117 @@
+use std::env;
+
+use crate::utils::constants::NOTIFICATION_SENDER_KINDS;
+
+// 알림 발송 채널 설정
+// NOTIFICATION_CHANNELS 로 큐 채널(push/email/sms)별 발송 방식을 지정한다.
+//   예) NOTIFICATION_CHANNELS=email=smtp,push=web_push,sms=webhook
+// 지정하지 않은 큐 채널의 항목은 재시도 없이 failed 로 기록된다.
+#[derive(Debug, Clone, Default)]
+pub struct NotificationChannelConfig {
+    pub routes: Vec<(String, String)>, // (큐 채널, 발송 방식)
+    pub smtp: Option<SmtpConfig>,
+    pub web_push: Option<WebPushConfig>,
+    pub webhook: Option<WebhookConfig>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub from: String,
+    pub starttls: bool,
+    pub helo_name: String,
+}
+
+// VAPID 키는 base64url (공개키: 65바이트 비압축 P-256 점, 개인키: 32바이트 스칼라)
+#[derive(Debug, Clone)]
+pub struct WebPushConfig {
+    pub vapid_public_key: String,
+    pub vapid_private_key: String,
+    pub subject: String, // mailto: 또는 https: 연락처
+}
+
+#[derive(Debug, Clone)]
+pub struct WebhookConfig {
+    pub url: String,
+    pub secret: Option<String>, // 있으면 본문 HMAC-SHA256 서명 헤더 추가
+}
+
+impl NotificationChannelConfig {
+    pub fn from_env() -> Result<Self, String> {
+        let routes = match env_var("NOTIFICATION_CHANNELS") {
+            Some(value) => parse_channel_routes(&value)?,
+            None => Vec::new(),
+        };
+
+        let smtp = match env_var("SMTP_HOST") {
+            Some(host) => {
+                let starttls = env_var("SMTP_STARTTLS").is_some_and(|v| v == "true" || v == "1");
+                let port = match env_var("SMTP_PORT") {
+                    Some(port) => port.parse().map_err(|_| format!("Invalid SMTP_PORT: {}", port))?,
+                    None if starttls => 587,
+                    None => 25,
+                };
+
+                Some(SmtpConfig {
+                    host,
+                    port,
+                    username: env_var("SMTP_USERNAME"),
+                    password: env_var("SMTP_PASSWORD"),
+                    from: env_var("SMTP_FROM").ok_or("SMTP_FROM is required when SMTP_HOST is set")?,
+                    starttls,
+                    helo_name: env_var("SMTP_HELO_NAME").unwrap_or_else(|| "localhost".to_string()),
+                })
+            }
+            None => None,
+        };
+
+        let web_push = match (env_var("VAPID_PUBLIC_KEY"), env_var("VAPID_PRIVATE_KEY")) {
+            (Some(vapid_public_key), Some(vapid_private_key)) => Some(WebPushConfig {
+                vapid_public_key,
+                vapid_private_key,
+                subject: env_var("VAPID_SUBJECT").ok_or("VAPID_SUBJECT is required when VAPID keys are set")?,
+            }),
+            _ => None,
+        };
+
+        let webhook = env_var("NOTIFICATION_WEBHOOK_URL").map(|url| WebhookConfig {
+            url,
+            secret: env_var("NOTIFICATION_WEBHOOK_SECRET"),
+        });
+
+        Ok(Self { routes, smtp, web_push, webhook })
+    }
+}
+
+// "email=smtp,push=web_push" 형식 파싱 (발송 방식은 NOTIFICATION_SENDER_KINDS 중 하나)
+pub fn parse_channel_routes(value: &str) -> Result<Vec<(String, String)>, String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (channel, kind) = entry
+                .split_once('=')
+                .map(|(channel, kind)| (channel.trim(), kind.trim()))
+                .filter(|(channel, kind)| !channel.is_empty() && !kind.is_empty())
+                .ok_or_else(|| format!("Invalid notification channel route: {}", entry))?;
+
+            if !NOTIFICATION_SENDER_KINDS.contains(&kind) {
+                return Err(format!(
+                    "Unknown notification sender '{}'. Allowed: {}",
+                    kind,
+                    NOTIFICATION_SENDER_KINDS.join(", ")
+                ));
+            }
+
+            Ok((channel.to_string(), kind.to_string()))
+        })
+        .collect()
+}
+
+fn env_var(key: &str) -> Option<String> {
+    env::var(key).ok().filter(|value| !value.is_empty())
+}
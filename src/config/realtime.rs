@@ -0,0 +1,44 @@
+use std::env;
+
+use crate::config::SupabaseConfig;
+
+// Supabase Realtime 설정
+// SUPABASE_REALTIME=true 면 SUPABASE_URL 의 /realtime/v1/websocket 에 연결하고,
+// SUPABASE_REALTIME_URL 을 지정하면 그 주소(로컬 WebSocket 대역 서버 등)에 연결한다.
+// 둘 다 없으면 Realtime 연결 없이 서버 안에서 만든 알림만 내부 이벤트 버스로 전달한다.
+#[derive(Debug, Clone)]
+pub struct RealtimeConfig {
+    pub url: String, // apikey, vsn 쿼리 파라미터 포함
+    pub access_token: String,
+}
+
+impl RealtimeConfig {
+    pub fn from_env(config: &SupabaseConfig) -> Option<Self> {
+        let base_url = match env_var("SUPABASE_REALTIME_URL") {
+            Some(url) => url,
+            None if env_var("SUPABASE_REALTIME").is_some_and(|v| v == "true" || v == "1") => realtime_base_url(&config.url),
+            None => return None,
+        };
+
+        let separator = if base_url.contains('?') { '&' } else { '?' };
+        Some(Self {
+            url: format!("{}{}apikey={}&vsn=1.0.0", base_url, separator, config.service_key),
+            access_token: config.service_key.clone(),
+        })
+    }
+}
+
+// https://<ref>.supabase.co → wss://<ref>.supabase.co/realtime/v1/websocket
+pub fn realtime_base_url(supabase_url: &str) -> String {
+    let base = supabase_url.trim_end_matches('/').trim_end_matches("/rest/v1");
+    let base = match base.split_once("://") {
+        Some(("https", rest)) => format!("wss://{}", rest),
+        Some(("http", rest)) => format!("ws://{}", rest),
+        _ => base.to_string(),
+    };
+    format!("{}/realtime/v1/websocket", base)
+}
+
+fn env_var(key: &str) -> Option<String> {
+    env::var(key).ok().filter(|value| !value.is_empty())
+}
@@ -1,13 +1,18 @@
 use postgrest::Postgrest;
+use sqlx::postgres::{PgPool, PgPoolOptions};
 use std::env;
 use dotenv::dotenv;
 
+use crate::utils::constants::DATABASE_MAX_CONNECTIONS;
+
 #[derive(Clone)]
 pub struct SupabaseConfig {
     pub client: Postgrest,
     pub url: String,
     pub anon_key: String,
     pub service_key: String,
+    pub jwt_secret: Option<String>,
+    pub database_url: Option<String>,
 }
 
 impl SupabaseConfig {
@@ -17,6 +22,10 @@ impl SupabaseConfig {
         let url = env::var("SUPABASE_URL")?;
         let anon_key = env::var("SUPABASE_ANON_KEY")?;
         let service_key = env::var("SUPABASE_SERVICE_KEY")?;
+        // HS256 토큰 검증용 (비대칭 키만 사용하는 프로젝트는 JWKS로 검증)
+        let jwt_secret = env::var("SUPABASE_JWT_SECRET").ok();
+        // 행 잠금이 필요한 작업(알림 발송 큐)용 직접 연결 - 없으면 해당 워커 비활성화
+        let database_url = env::var("DATABASE_URL").ok().filter(|url| !url.is_empty());
 
         // Service Key로 기본 클라이언트 생성 (관리자 권한)
         let client = Postgrest::new(&url)
@@ -28,6 +37,8 @@ impl SupabaseConfig {
             url,
             anon_key,
             service_key,
+            jwt_secret,
+            database_url,
         })
     }
 
@@ -50,6 +61,25 @@ impl SupabaseConfig {
         &self.client
     }
 
+    // Supabase Auth 토큰 발급자 (JWT iss 클레임)
+    pub fn auth_issuer(&self) -> String {
+        let base = self.url.trim_end_matches('/').trim_end_matches("/rest/v1");
+        format!("{}/auth/v1", base)
+    }
+
+    // 비대칭 서명 키 공개 엔드포인트
+    pub fn jwks_url(&self) -> String {
+        format!("{}/.well-known/jwks.json", self.auth_issuer())
+    }
+
+    // Postgres 직접 연결 풀 (DATABASE_URL이 없으면 None, 첫 쿼리 시점에 연결)
+    pub fn database_pool(&self) -> Result<Option<PgPool>, sqlx::Error> {
+        self.database_url
+            .as_deref()
+            .map(|url| PgPoolOptions::new().max_connections(DATABASE_MAX_CONNECTIONS).connect_lazy(url))
+            .transpose()
+    }
+
     // 연결 테스트 메소드
     pub async fn test_connection(&self) -> Result<(), Box<dyn std::error::Error>> {
         let response = self.client
@@ -0,0 +1,186 @@
+use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
+
+use crate::crawler::{extract_json_ld_item_urls, extract_json_ld_product, split_selector, CrawlError, ScrapedProduct, SelectorProfile};
+use crate::utils::constants::CRAWLER_DEFAULT_CURRENCY;
+
+// 카테고리 목록 페이지 한 장에서 찾은 상품 주소와 다음 페이지 주소
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ListingPage {
+    pub product_urls: Vec<String>,
+    pub next_page: Option<String>,
+}
+
+// 상품 상세 페이지 HTML 에서 이름/가격/할인가/이미지를 추출
+// JSON-LD Product/Offer 를 먼저 쓰고, 없는 값은 선택자 프로필로 채운다.
+// (JSON-LD Offer 가격은 현재 판매가, 프로필의 price 는 정가로 본다)
+// scraper::Html 은 Send 가 아니므로 await 사이에 들고 있지 않도록 동기 함수로 둔다.
+pub fn extract_product(html: &str, url: &str, profile: &SelectorProfile) -> Result<ScrapedProduct, CrawlError> {
+    let document = Html::parse_document(html);
+    let json_ld = extract_json_ld_product(&document).unwrap_or_default();
+
+    let name = json_ld.name
+        .or_else(|| select_value(&document, &profile.name))
+        .ok_or_else(|| CrawlError::extract(url, "product name not found"))?;
+
+    let price_text = select_value(&document, &profile.price);
+    let sale_text = select_value(&document, &profile.sale_price);
+    let price = json_ld.list_price
+        .or_else(|| price_text.as_deref().and_then(parse_price))
+        .or(json_ld.offer_price)
+        .ok_or_else(|| match &price_text {
+            Some(text) => CrawlError::extract(url, format!("invalid price '{}'", text)),
+            None => CrawlError::extract(url, "price not found"),
+        })?;
+    let sale_price = json_ld.offer_price
+        .or_else(|| sale_text.as_deref().and_then(parse_price))
+        .filter(|sale_price| *sale_price > 0.0 && *sale_price < price);
+
+    let currency = json_ld.currency
+        .or_else(|| select_value(&document, &profile.currency))
+        .map(|currency| currency.to_uppercase())
+        .filter(|currency| currency.len() == 3 && currency.chars().all(|c| c.is_ascii_alphabetic()))
+        .or_else(|| price_text.as_deref().and_then(detect_currency))
+        .or_else(|| sale_text.as_deref().and_then(detect_currency))
+        .or_else(|| profile.default_currency.clone())
+        .unwrap_or_else(|| CRAWLER_DEFAULT_CURRENCY.to_string());
+
+    Ok(ScrapedProduct {
+        url: url.to_string(),
+        name,
+        sku: json_ld.sku.or_else(|| select_value(&document, &profile.sku)),
+        price,
+        sale_price,
+        currency,
+        image_url: json_ld.image
+            .or_else(|| select_value(&document, &profile.image))
+            .map(|src| resolve_url(url, &src)),
+    })
+}
+
+// 카테고리 목록 페이지에서 상품 주소(JSON-LD ItemList + product_links)와 다음 페이지(next_page) 추출
+pub fn parse_listing(html: &str, page_url: &str, profile: &SelectorProfile) -> ListingPage {
+    let document = Html::parse_document(html);
+
+    let mut product_urls: Vec<String> = Vec::new();
+    let links = extract_json_ld_item_urls(&document)
+        .into_iter()
+        .chain(select_all_values(&document, &profile.product_links));
+    for link in links {
+        let url = strip_fragment(&resolve_url(page_url, &link));
+        if !product_urls.contains(&url) {
+            product_urls.push(url);
+        }
+    }
+
+    // 마지막 페이지의 "다음" 링크가 자기 자신(#none 등)을 가리키는 경우 제외
+    let next_page = select_value(&document, &profile.next_page)
+        .map(|href| strip_fragment(&resolve_url(page_url, &href)))
+        .filter(|next_page| *next_page != strip_fragment(page_url));
+
+    ListingPage { product_urls, next_page }
+}
+
+// 선택자 목록을 순서대로 시도해 처음 나온 비어 있지 않은 값 (공백 정리)
+pub fn select_value(document: &Html, selectors: &[String]) -> Option<String> {
+    selectors.iter().find_map(|spec| {
+        let (css, attr) = split_selector(spec);
+        let selector = Selector::parse(css).ok()?;
+
+        document.select(&selector).find_map(|element| {
+            let value = match attr {
+                Some(attr) => element.value().attr(attr)?.to_string(),
+                None => element.text().collect::<String>(),
+            };
+            let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+            (!value.is_empty()).then_some(value)
+        })
+    })
+}
+
+// 선택자 목록 중 값이 하나라도 나온 첫 선택자의 모든 값 (목록 페이지의 상품 링크 등)
+pub fn select_all_values(document: &Html, selectors: &[String]) -> Vec<String> {
+    selectors
+        .iter()
+        .map(|spec| {
+            let (css, attr) = split_selector(spec);
+            let Ok(selector) = Selector::parse(css) else {
+                return Vec::new();
+            };
+
+            document
+                .select(&selector)
+                .filter_map(|element| match attr {
+                    Some(attr) => element.value().attr(attr).map(str::trim).map(str::to_string),
+                    None => Some(element.text().collect::<String>().trim().to_string()),
+                })
+                .filter(|value| !value.is_empty())
+                .collect::<Vec<_>>()
+        })
+        .find(|values| !values.is_empty())
+        .unwrap_or_default()
+}
+
+// "₩12,900원" → 12900, "$1,299.99" → 1299.99, "19,90 €" → 19.9
+// 범위("12,900 ~ 15,000")면 첫 가격. 구분자가 둘 다 있으면 마지막 것이 소수점,
+// 하나만 있으면 뒤에 1~2자리가 올 때만 소수점으로 본다.
+pub fn parse_price(text: &str) -> Option<f64> {
+    let number: String = text
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit() || *c == ',' || *c == '.')
+        .collect();
+    let number = number.trim_end_matches([',', '.']);
+    if number.is_empty() {
+        return None;
+    }
+
+    let decimal_separator = match (number.rfind(','), number.rfind('.')) {
+        (Some(comma), Some(dot)) => Some(comma.max(dot)),
+        (Some(index), None) | (None, Some(index)) => {
+            let separator = number.as_bytes()[index] as char;
+            let fraction_digits = number.len() - index - 1;
+            (number.matches(separator).count() == 1 && (1..=2).contains(&fraction_digits)).then_some(index)
+        }
+        (None, None) => None,
+    };
+
+    let normalized: String = number
+        .char_indices()
+        .filter_map(|(index, c)| match c {
+            '0'..='9' => Some(c),
+            _ if Some(index) == decimal_separator => Some('.'),
+            _ => None,
+        })
+        .collect();
+
+    normalized.parse::<f64>().ok()
+}
+
+// 가격 문자열의 통화 기호로 통화 코드 추정
+pub fn detect_currency(text: &str) -> Option<String> {
+    let currency = if text.contains('₩') || text.contains('원') || text.contains("KRW") {
+        "KRW"
+    } else if text.contains('¥') || text.contains('円') || text.contains("JPY") {
+        "JPY"
+    } else if text.contains('€') || text.contains("EUR") {
+        "EUR"
+    } else if text.contains('$') || text.contains("USD") {
+        "USD"
+    } else {
+        return None;
+    };
+    Some(currency.to_string())
+}
+
+// 상대 경로/프로토콜 생략 이미지 주소를 페이지 URL 기준 절대 주소로
+pub fn resolve_url(base: &str, href: &str) -> String {
+    reqwest::Url::parse(base)
+        .and_then(|base| base.join(href))
+        .map(|url| url.to_string())
+        .unwrap_or_else(|_| href.to_string())
+}
+
+fn strip_fragment(url: &str) -> String {
+    url.split('#').next().unwrap_or(url).to_string()
+}
@@ -0,0 +1,249 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::config::crawler::CrawlerConfig;
+use crate::crawler::CrawlError;
+
+// 이전 응답의 검증자 - 있으면 조건부 요청(If-None-Match / If-Modified-Since)으로 바뀌지 않은 페이지는 304
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PageValidators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+// 크롤러가 읽은 페이지 (304 면 body 는 비어 있음)
+#[derive(Debug, Clone)]
+pub struct FetchedPage {
+    pub url: String,
+    pub status: u16,
+    pub body: String,
+    pub validators: PageValidators,
+}
+
+impl FetchedPage {
+    pub fn is_not_modified(&self) -> bool {
+        self.status == StatusCode::NOT_MODIFIED.as_u16()
+    }
+}
+
+// 페이지 읽기 - 실제 HTTP 요청(HttpFetcher) 또는 저장된 HTML 파일(FixtureFetcher)
+// 2xx 와 304 만 Ok, 그 밖의 상태는 CrawlError::Status
+#[async_trait]
+pub trait PageFetcher: Send + Sync {
+    async fn fetch(&self, url: &str, validators: &PageValidators) -> Result<FetchedPage, CrawlError>;
+}
+
+pub struct HttpFetcher {
+    client: reqwest::Client,
+}
+
+impl HttpFetcher {
+    pub fn new(config: &CrawlerConfig) -> Result<Self, CrawlError> {
+        let client = reqwest::Client::builder()
+            .user_agent(&config.user_agent)
+            .cookie_store(true)
+            .gzip(true)
+            .timeout(Duration::from_secs(config.request_timeout_secs))
+            .build()
+            .map_err(|e| CrawlError::fetch("", e.to_string()))?;
+
+        Ok(Self { client })
+    }
+}
+
+#[async_trait]
+impl PageFetcher for HttpFetcher {
+    async fn fetch(&self, url: &str, validators: &PageValidators) -> Result<FetchedPage, CrawlError> {
+        let mut request = self.client.get(url);
+        if let Some(etag) = &validators.etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &validators.last_modified {
+            request = request.header(IF_MODIFIED_SINCE, last_modified);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| CrawlError::fetch(url, e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() && status != StatusCode::NOT_MODIFIED {
+            return Err(CrawlError::Status { url: url.to_string(), status: status.as_u16() });
+        }
+
+        let header = |name| response.headers().get(name).and_then(|value| value.to_str().ok()).map(str::to_string);
+        let validators = PageValidators { etag: header(ETAG), last_modified: header(LAST_MODIFIED) };
+        let final_url = response.url().to_string();
+        let body = response.text().await.map_err(|e| CrawlError::fetch(url, e.to_string()))?;
+
+        Ok(FetchedPage { url: final_url, status: status.as_u16(), body, validators })
+    }
+}
+
+// 네트워크 없이 저장된 HTML 로 크롤링 (개발/검증용)
+//   https://shop.example.com/products/a?variant=1 → <dir>/shop.example.com/products/a_variant_1.html
+//   https://shop.example.com/                     → <dir>/shop.example.com/index.html
+//   https://shop.example.com/robots.txt           → <dir>/shop.example.com/robots.txt
+// ETag 는 파일 내용 해시, Last-Modified 는 파일 수정 시각이라 조건부 요청도 HTTP 와 같게 동작한다.
+pub struct FixtureFetcher {
+    dir: PathBuf,
+}
+
+impl FixtureFetcher {
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        Self { dir: dir.as_ref().to_path_buf() }
+    }
+
+    pub fn fixture_path(&self, url: &str) -> Result<PathBuf, CrawlError> {
+        let parsed = reqwest::Url::parse(url).map_err(|e| CrawlError::fetch(url, e.to_string()))?;
+        let host = parsed.host_str().ok_or_else(|| CrawlError::fetch(url, "URL has no host"))?;
+
+        let mut path = parsed.path().trim_start_matches('/').to_string();
+        if path.is_empty() || path.ends_with('/') {
+            path.push_str("index");
+        }
+        if let Some(query) = parsed.query() {
+            path.push('?');
+            path.push_str(query);
+        }
+
+        let file_name: String = path
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-' | '/') { c } else { '_' })
+            .collect();
+        // ".." 경로로 픽스처 디렉터리 밖을 읽지 않도록
+        let file_name = file_name.replace("..", "_");
+        let file_name = if file_name == "robots.txt" { file_name } else { format!("{}.html", file_name) };
+
+        Ok(self.dir.join(host).join(file_name))
+    }
+}
+
+#[async_trait]
+impl PageFetcher for FixtureFetcher {
+    async fn fetch(&self, url: &str, validators: &PageValidators) -> Result<FetchedPage, CrawlError> {
+        let path = self.fixture_path(url)?;
+
+        let body = match tokio::fs::read_to_string(&path).await {
+            Ok(body) => body,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(CrawlError::Status { url: url.to_string(), status: 404 });
+            }
+            Err(e) => return Err(CrawlError::fetch(url, format!("{}: {}", path.display(), e))),
+        };
+
+        let mut hasher = DefaultHasher::new();
+        body.hash(&mut hasher);
+        let last_modified = tokio::fs::metadata(&path)
+            .await
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .map(|modified| DateTime::<Utc>::from(modified).format("%a, %d %b %Y %H:%M:%S GMT").to_string());
+        let current = PageValidators { etag: Some(format!("\"{:016x}\"", hasher.finish())), last_modified };
+
+        // If-None-Match 가 있으면 그것만, 없으면 If-Modified-Since 로 비교
+        let not_modified = match (&validators.etag, &validators.last_modified) {
+            (Some(etag), _) => current.etag.as_ref() == Some(etag),
+            (None, Some(last_modified)) => current.last_modified.as_ref() == Some(last_modified),
+            (None, None) => false,
+        };
+
+        if not_modified {
+            Ok(FetchedPage { url: url.to_string(), status: 304, body: String::new(), validators: current })
+        } else {
+            Ok(FetchedPage { url: url.to_string(), status: 200, body, validators: current })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 테스트마다 따로 쓰는 픽스처 디렉터리 (끝나면 삭제)
+    struct TempFixtures(PathBuf);
+
+    impl TempFixtures {
+        fn new() -> Self {
+            Self(std::env::temp_dir().join(format!("duk-fixtures-{}", uuid::Uuid::new_v4())))
+        }
+
+        fn write(&self, relative: &str, body: &str) {
+            let path = self.0.join(relative);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, body).unwrap();
+        }
+    }
+
+    impl Drop for TempFixtures {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn maps_urls_to_fixture_paths() {
+        let fetcher = FixtureFetcher::new("/fixtures");
+        let path = |url: &str| fetcher.fixture_path(url).unwrap();
+
+        assert_eq!(path("https://shop.example.com/products/a?variant=1"), Path::new("/fixtures/shop.example.com/products/a_variant_1.html"));
+        assert_eq!(path("https://shop.example.com/"), Path::new("/fixtures/shop.example.com/index.html"));
+        assert_eq!(path("https://shop.example.com/robots.txt"), Path::new("/fixtures/shop.example.com/robots.txt"));
+        assert_eq!(path("https://shop.example.com/a/..%2F..%2Fsecret"), Path::new("/fixtures/shop.example.com/a/__2F__2Fsecret.html"));
+        assert!(fetcher.fixture_path("not a url").is_err());
+    }
+
+    #[tokio::test]
+    async fn conditional_requests_round_trip() {
+        let fixtures = TempFixtures::new();
+        fixtures.write("shop.example.com/products/a.html", "<h1>A</h1>");
+        let fetcher = FixtureFetcher::new(&fixtures.0);
+        let url = "https://shop.example.com/products/a";
+
+        let first = fetcher.fetch(url, &PageValidators::default()).await.unwrap();
+        assert_eq!((first.status, first.body.as_str()), (200, "<h1>A</h1>"));
+        assert!(!first.is_not_modified());
+        let etag = first.validators.etag.clone().unwrap();
+        let last_modified = first.validators.last_modified.clone().unwrap();
+
+        // 지난 응답의 ETag/Last-Modified 를 그대로 보내면 304
+        let not_modified = fetcher.fetch(url, &first.validators).await.unwrap();
+        assert!(not_modified.is_not_modified());
+        assert!(not_modified.body.is_empty());
+        assert_eq!(not_modified.validators, first.validators);
+
+        let by_date = PageValidators { etag: None, last_modified: Some(last_modified.clone()) };
+        assert!(fetcher.fetch(url, &by_date).await.unwrap().is_not_modified());
+
+        let stale_date = PageValidators { etag: None, last_modified: Some("Mon, 01 Jan 2024 00:00:00 GMT".to_string()) };
+        assert_eq!(fetcher.fetch(url, &stale_date).await.unwrap().status, 200);
+
+        // If-None-Match 가 있으면 If-Modified-Since 는 보지 않음
+        let stale_etag = PageValidators { etag: Some("\"stale\"".to_string()), last_modified: Some(last_modified) };
+        assert_eq!(fetcher.fetch(url, &stale_etag).await.unwrap().status, 200);
+
+        // 내용이 바뀌면 ETag 도 바뀌어 200
+        fixtures.write("shop.example.com/products/a.html", "<h1>A (sale)</h1>");
+        let changed = fetcher.fetch(url, &first.validators).await.unwrap();
+        assert_eq!((changed.status, changed.body.as_str()), (200, "<h1>A (sale)</h1>"));
+        assert_ne!(changed.validators.etag, Some(etag));
+    }
+
+    #[tokio::test]
+    async fn missing_fixture_is_404() {
+        let fixtures = TempFixtures::new();
+        let fetcher = FixtureFetcher::new(&fixtures.0);
+
+        let error = fetcher.fetch("https://shop.example.com/robots.txt", &PageValidators::default()).await.unwrap_err();
+        assert!(matches!(error, CrawlError::Status { status: 404, .. }));
+    }
+}
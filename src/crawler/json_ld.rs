@@ -0,0 +1,239 @@
+use scraper::{Html, Selector};
+use serde_json::Value;
+
+use crate::crawler::parse_price;
+
+// schema.org Product (JSON-LD) 에서 읽은 값
+// offer_price 는 Offer 의 현재 판매가, list_price 는 priceSpecification 의 정가/취소선 가격 (있을 때만)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JsonLdProduct {
+    pub name: Option<String>,
+    pub sku: Option<String>,
+    pub image: Option<String>,
+    pub offer_price: Option<f64>,
+    pub list_price: Option<f64>,
+    pub currency: Option<String>,
+}
+
+// 페이지의 첫 번째 Product 노드
+pub fn extract_json_ld_product(document: &Html) -> Option<JsonLdProduct> {
+    let nodes = json_ld_nodes(document);
+    let product = nodes.iter().find(|node| has_type(node, "Product"))?;
+
+    let offer = match product.get("offers") {
+        Some(Value::Array(offers)) => offers.first(),
+        offer => offer,
+    };
+    let specifications = offer.map(|offer| price_specifications(offer.get("priceSpecification"))).unwrap_or_default();
+
+    let offer_price = offer
+        .and_then(|offer| number(offer.get("price")).or_else(|| number(offer.get("lowPrice"))))
+        .or_else(|| specifications.iter().find(|spec| !is_list_price(spec)).and_then(|spec| number(spec.get("price"))));
+    let list_price = specifications.iter().find(|spec| is_list_price(spec)).and_then(|spec| number(spec.get("price")));
+    let currency = offer
+        .and_then(|offer| text(offer.get("priceCurrency")))
+        .or_else(|| specifications.iter().find_map(|spec| text(spec.get("priceCurrency"))));
+
+    Some(JsonLdProduct {
+        name: text(product.get("name")),
+        sku: text(product.get("sku")).or_else(|| text(product.get("mpn"))),
+        image: image_url(product.get("image")),
+        offer_price,
+        list_price,
+        currency,
+    })
+}
+
+// 목록 페이지의 ItemList 상품 주소 (itemListElement[].url 또는 item)
+pub fn extract_json_ld_item_urls(document: &Html) -> Vec<String> {
+    json_ld_nodes(document)
+        .iter()
+        .filter(|node| has_type(node, "ItemList"))
+        .filter_map(|node| node.get("itemListElement").and_then(Value::as_array))
+        .flatten()
+        .filter_map(|element| {
+            text(element.get("url")).or_else(|| match element.get("item") {
+                Some(item @ Value::Object(_)) => text(item.get("url")).or_else(|| text(item.get("@id"))),
+                item => text(item),
+            })
+        })
+        .collect()
+}
+
+// <script type="application/ld+json"> 를 모두 읽어 최상위 배열과 @graph 를 풀어낸 노드 목록
+fn json_ld_nodes(document: &Html) -> Vec<Value> {
+    let Ok(selector) = Selector::parse(r#"script[type="application/ld+json"]"#) else {
+        return Vec::new();
+    };
+
+    document
+        .select(&selector)
+        .filter_map(|script| serde_json::from_str::<Value>(script.text().collect::<String>().trim()).ok())
+        .flat_map(flatten_nodes)
+        .collect()
+}
+
+fn flatten_nodes(value: Value) -> Vec<Value> {
+    match value {
+        Value::Array(items) => items.into_iter().flat_map(flatten_nodes).collect(),
+        Value::Object(mut node) => match node.remove("@graph") {
+            Some(graph) => {
+                let mut nodes = flatten_nodes(graph);
+                if node.contains_key("@type") {
+                    nodes.push(Value::Object(node));
+                }
+                nodes
+            }
+            None => vec![Value::Object(node)],
+        },
+        _ => Vec::new(),
+    }
+}
+
+// "@type": "Product" | ["Product", ...] | "https://schema.org/Product"
+fn has_type(node: &Value, type_name: &str) -> bool {
+    let matches = |value: &Value| value.as_str().and_then(|t| t.rsplit('/').next()) == Some(type_name);
+    match node.get("@type") {
+        Some(Value::Array(types)) => types.iter().any(matches),
+        Some(value) => matches(value),
+        None => false,
+    }
+}
+
+fn price_specifications(value: Option<&Value>) -> Vec<&Value> {
+    match value {
+        Some(Value::Array(specs)) => specs.iter().collect(),
+        Some(spec @ Value::Object(_)) => vec![spec],
+        _ => Vec::new(),
+    }
+}
+
+// priceType 이 StrikethroughPrice / ListPrice 면 할인 전 가격
+fn is_list_price(spec: &Value) -> bool {
+    spec.get("priceType")
+        .and_then(Value::as_str)
+        .is_some_and(|price_type| price_type.ends_with("StrikethroughPrice") || price_type.ends_with("ListPrice"))
+}
+
+// 숫자 또는 "12,900" 같은 문자열
+fn number(value: Option<&Value>) -> Option<f64> {
+    match value? {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => parse_price(text),
+        _ => None,
+    }
+}
+
+fn text(value: Option<&Value>) -> Option<String> {
+    let text = match value? {
+        Value::String(text) => text.trim().to_string(),
+        Value::Number(number) => number.to_string(),
+        _ => return None,
+    };
+    (!text.is_empty()).then_some(text)
+}
+
+// "image": "url" | ["url", ...] | {"url": "..."} | [{"url": "..."}]
+fn image_url(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::Array(images) => images.first().and_then(|image| image_url(Some(image))),
+        image @ Value::Object(_) => text(image.get("url")).or_else(|| text(image.get("contentUrl"))),
+        image => text(Some(image)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn product(json_ld: &str) -> Option<JsonLdProduct> {
+        let html = format!(r#"<html><head><script type="application/ld+json">{}</script></head><body></body></html>"#, json_ld);
+        extract_json_ld_product(&Html::parse_document(&html))
+    }
+
+    #[test]
+    fn reads_product_inside_graph() {
+        let product = product(r#"{
+            "@context": "https://schema.org",
+            "@graph": [
+                {"@type": "Organization", "name": "Example Shop"},
+                {"@type": ["Product", "Thing"], "name": "Canvas Tote Bag", "mpn": "CT-300",
+                 "image": {"url": "https://shop.example.com/tote.jpg"},
+                 "offers": {"@type": "Offer", "price": 25000, "priceCurrency": "KRW"}}
+            ]
+        }"#)
+        .unwrap();
+
+        assert_eq!(product.name.as_deref(), Some("Canvas Tote Bag"));
+        assert_eq!(product.sku.as_deref(), Some("CT-300"));
+        assert_eq!(product.image.as_deref(), Some("https://shop.example.com/tote.jpg"));
+        assert_eq!((product.offer_price, product.list_price), (Some(25000.0), None));
+        assert_eq!(product.currency.as_deref(), Some("KRW"));
+    }
+
+    #[test]
+    fn reads_first_offer_of_array_with_strikethrough_price() {
+        let product = product(r#"[
+            {"@type": "BreadcrumbList", "itemListElement": []},
+            {"@type": "https://schema.org/Product", "name": "Linen Shirt", "sku": 1001,
+             "offers": [
+                {"@type": "Offer", "price": "41,300", "priceCurrency": "KRW",
+                 "priceSpecification": [
+                    {"@type": "UnitPriceSpecification", "price": 59000, "priceType": "https://schema.org/StrikethroughPrice"}
+                 ]},
+                {"@type": "Offer", "price": "45,000", "priceCurrency": "KRW"}
+             ]}
+        ]"#)
+        .unwrap();
+
+        assert_eq!(product.sku.as_deref(), Some("1001"));
+        assert_eq!((product.offer_price, product.list_price), (Some(41300.0), Some(59000.0)));
+    }
+
+    #[test]
+    fn reads_low_price_of_aggregate_offer() {
+        let product = product(r#"{
+            "@type": "Product", "name": "Wool Coat",
+            "offers": {"@type": "AggregateOffer", "lowPrice": "189000", "highPrice": "219000", "offerCount": 3, "priceCurrency": "KRW"}
+        }"#)
+        .unwrap();
+
+        assert_eq!((product.offer_price, product.list_price), (Some(189000.0), None));
+        assert_eq!(product.currency.as_deref(), Some("KRW"));
+    }
+
+    #[test]
+    fn reads_price_from_specification_without_offer_price() {
+        let product = product(r#"{
+            "@type": "Product", "name": "Kettle",
+            "offers": {"@type": "Offer", "priceSpecification": [
+                {"@type": "UnitPriceSpecification", "price": 49.99, "priceCurrency": "USD", "priceType": "https://schema.org/ListPrice"},
+                {"@type": "UnitPriceSpecification", "price": 39.99, "priceCurrency": "USD"}
+            ]}
+        }"#)
+        .unwrap();
+
+        assert_eq!((product.offer_price, product.list_price), (Some(39.99), Some(49.99)));
+        assert_eq!(product.currency.as_deref(), Some("USD"));
+    }
+
+    #[test]
+    fn ignores_invalid_json_and_pages_without_product() {
+        assert_eq!(product(r#"{"@type": "Product", "name": "#), None);
+        assert_eq!(product(r#"{"@type": "Organization", "name": "Example Shop"}"#), None);
+    }
+
+    #[test]
+    fn reads_item_list_urls() {
+        let html = r#"<script type="application/ld+json">{"@graph": [{"@type": "ItemList", "itemListElement": [
+            {"@type": "ListItem", "position": 1, "url": "https://shop.example.com/products/a"},
+            {"@type": "ListItem", "position": 2, "item": {"@id": "https://shop.example.com/products/b"}},
+            {"@type": "ListItem", "position": 3, "item": "https://shop.example.com/products/c"}
+        ]}]}</script>"#;
+
+        assert_eq!(
+            extract_json_ld_item_urls(&Html::parse_document(html)),
+            vec!["https://shop.example.com/products/a", "https://shop.example.com/products/b", "https://shop.example.com/products/c"]
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::{Mutex as AsyncMutex, OwnedSemaphorePermit, Semaphore};
+use tokio::time::Instant;
+
+use crate::config::crawler::CrawlerConfig;
+use crate::crawler::normalize_domain;
+
+// 상점 도메인별 요청 제한 - 모든 크롤링 실행(수동/예약, 여러 상점)이 함께 사용
+//   - 도메인당 동시 요청 수는 domain_concurrency 이하
+//   - 같은 도메인의 요청 시작 간격은 domain_delay 와 robots.txt Crawl-delay(max_crawl_delay 이하) 중 긴 쪽
+pub struct DomainLimiter {
+    concurrency: usize,
+    delay: Duration,
+    max_crawl_delay: Duration,
+    domains: Mutex<HashMap<String, Arc<DomainSlot>>>,
+}
+
+struct DomainSlot {
+    permits: Arc<Semaphore>,
+    next_request: AsyncMutex<Instant>,
+}
+
+// 요청이 끝날 때까지 들고 있는 동시 요청 슬롯
+pub struct DomainPermit {
+    _permit: OwnedSemaphorePermit,
+}
+
+impl DomainLimiter {
+    pub fn new(config: &CrawlerConfig) -> Self {
+        Self {
+            concurrency: config.domain_concurrency.max(1),
+            delay: config.domain_delay,
+            max_crawl_delay: config.max_crawl_delay,
+            domains: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // 도메인의 동시 요청 슬롯을 얻고 요청 간격만큼 기다림
+    pub async fn acquire(&self, domain: &str, crawl_delay: Option<Duration>) -> DomainPermit {
+        let slot = {
+            let mut domains = self.domains.lock().unwrap_or_else(|e| e.into_inner());
+            domains
+                .entry(normalize_domain(domain))
+                .or_insert_with(|| Arc::new(DomainSlot {
+                    permits: Arc::new(Semaphore::new(self.concurrency)),
+                    next_request: AsyncMutex::new(Instant::now()),
+                }))
+                .clone()
+        };
+
+        // 세마포어는 닫지 않으므로 실패하지 않음
+        let permit = slot.permits.clone().acquire_owned().await.expect("domain semaphore closed");
+
+        let delay = crawl_delay.map_or(self.delay, |crawl_delay| self.delay.max(crawl_delay.min(self.max_crawl_delay)));
+        let mut next_request = slot.next_request.lock().await;
+        tokio::time::sleep_until(*next_request).await;
+        *next_request = Instant::now() + delay;
+
+        DomainPermit { _permit: permit }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(concurrency: usize, delay_ms: u64, max_crawl_delay_ms: u64) -> DomainLimiter {
+        DomainLimiter::new(&CrawlerConfig {
+            user_agent: "DukBot/0.1".to_string(),
+            request_timeout_secs: 20,
+            profiles_path: None,
+            fixture_dir: None,
+            domain_concurrency: concurrency,
+            domain_delay: Duration::from_millis(delay_ms),
+            max_crawl_delay: Duration::from_millis(max_crawl_delay_ms),
+            schedule_interval: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn spaces_requests_to_the_same_domain() {
+        let limiter = limiter(2, 100, 1000);
+
+        let start = Instant::now();
+        drop(limiter.acquire("shop.example.com", None).await);
+        drop(limiter.acquire("https://www.shop.example.com/", None).await); // 같은 도메인
+        assert!(start.elapsed() >= Duration::from_millis(100));
+
+        // 다른 도메인은 기다리지 않음
+        let start = Instant::now();
+        drop(limiter.acquire("mall.example.co.kr", None).await);
+        assert!(start.elapsed() < Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn uses_longer_crawl_delay_up_to_the_maximum() {
+        let limiter = limiter(1, 10, 150);
+
+        // Crawl-delay 1시간도 max_crawl_delay(150ms) 까지만
+        let start = Instant::now();
+        drop(limiter.acquire("shop.example.com", Some(Duration::from_secs(3600))).await);
+        drop(limiter.acquire("shop.example.com", Some(Duration::from_secs(3600))).await);
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(150) && elapsed < Duration::from_secs(2), "{:?}", elapsed);
+    }
+
+    #[tokio::test]
+    async fn limits_concurrent_requests_per_domain() {
+        let limiter = limiter(1, 0, 0);
+
+        let permit = limiter.acquire("shop.example.com", None).await;
+        let blocked = tokio::time::timeout(Duration::from_millis(50), limiter.acquire("shop.example.com", None)).await;
+        assert!(blocked.is_err());
+
+        drop(permit);
+        let acquired = tokio::time::timeout(Duration::from_millis(50), limiter.acquire("shop.example.com", None)).await;
+        assert!(acquired.is_ok());
+    }
+}
@@ -0,0 +1,52 @@
+pub mod profile;
+pub mod json_ld;
+pub mod extract;
+pub mod platform;
+pub mod fetcher;
+pub mod robots;
+pub mod limiter;
+
+pub use profile::*;
+pub use json_ld::*;
+pub use extract::*;
+pub use platform::*;
+pub use fetcher::*;
+pub use robots::*;
+pub use limiter::*;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+// 상품 페이지에서 추출한 정보 (upsert_crawled_product RPC 입력, crawl_pages.extracted)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScrapedProduct {
+    pub url: String,
+    pub name: String,
+    pub sku: Option<String>,
+    pub price: f64,              // 정가 (할인 전 가격)
+    pub sale_price: Option<f64>, // 할인가 - 정가보다 낮을 때만 Some
+    pub currency: String,
+    pub image_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Error)]
+pub enum CrawlError {
+    #[error("Failed to fetch {url}: {message}")]
+    Fetch { url: String, message: String },
+
+    #[error("HTTP {status} for {url}")]
+    Status { url: String, status: u16 },
+
+    #[error("Failed to extract {url}: {message}")]
+    Extract { url: String, message: String },
+}
+
+impl CrawlError {
+    pub fn fetch<T: Into<String>>(url: &str, message: T) -> Self {
+        Self::Fetch { url: url.to_string(), message: message.into() }
+    }
+
+    pub fn extract<T: Into<String>>(url: &str, message: T) -> Self {
+        Self::Extract { url: url.to_string(), message: message.into() }
+    }
+}
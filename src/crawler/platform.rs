@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::crawler::{extract_product, normalize_domain, parse_listing, CrawlError, ListingPage, ScrapedProduct, SelectorProfile, SelectorProfiles};
+use crate::domain::entities::shop::Shop;
+
+// 상점 플랫폼별 페이지 해석 규칙
+// 크롤러(CrawlerService)는 페이지를 읽고 저장만 하고, 페이지 구조는 여기서 해석한다.
+// 새 플랫폼은 CRAWLER_PROFILES_PATH 에 선택자 프로필을 추가하거나, 이 트레이트를 구현해 ScraperRegistry::register 로 등록한다.
+// (scraper::Html 이 Send 가 아니므로 HTML 을 다루는 메서드는 동기 함수)
+pub trait PlatformScraper: Send + Sync {
+    // 목록 URL 을 지정하지 않았을 때 크롤링할 목록 경로 (상점 도메인 기준, 예: /collections/all)
+    fn listing_paths(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    // 카테고리 목록 페이지 한 장 - 상품 주소와 다음 페이지 주소
+    fn parse_listing(&self, html: &str, page_url: &str) -> ListingPage;
+
+    // 상품 상세 페이지
+    fn extract_product(&self, html: &str, url: &str) -> Result<ScrapedProduct, CrawlError>;
+}
+
+// 선택자 프로필 기반 스크레이퍼 - JSON-LD Product/Offer/ItemList 를 먼저 읽고 빠진 값은 CSS 선택자로 채움
+pub struct ProfileScraper {
+    profile: SelectorProfile,
+}
+
+impl ProfileScraper {
+    pub fn new(profile: SelectorProfile) -> Self {
+        Self { profile }
+    }
+}
+
+impl PlatformScraper for ProfileScraper {
+    fn listing_paths(&self) -> Vec<String> {
+        self.profile.listing_paths.clone()
+    }
+
+    fn parse_listing(&self, html: &str, page_url: &str) -> ListingPage {
+        parse_listing(html, page_url, &self.profile)
+    }
+
+    fn extract_product(&self, html: &str, url: &str) -> Result<ScrapedProduct, CrawlError> {
+        extract_product(html, url, &self.profile)
+    }
+}
+
+// Shop.platform / Shop.domain 별 스크레이퍼 - 상점 도메인 > 상점 플랫폼 > generic 순으로 선택
+#[derive(Clone)]
+pub struct ScraperRegistry {
+    platforms: HashMap<String, Arc<dyn PlatformScraper>>,
+    domains: HashMap<String, Arc<dyn PlatformScraper>>,
+    fallback: Arc<dyn PlatformScraper>,
+}
+
+impl ScraperRegistry {
+    // 프로필마다 ProfileScraper 를 등록 (generic 프로필이 기본 스크레이퍼)
+    pub fn from_profiles(profiles: SelectorProfiles) -> Self {
+        let platforms: HashMap<String, Arc<dyn PlatformScraper>> = profiles.platforms
+            .into_iter()
+            .map(|(platform, profile)| (platform, Arc::new(ProfileScraper::new(profile)) as Arc<dyn PlatformScraper>))
+            .collect();
+        let domains = profiles.domains
+            .into_iter()
+            .map(|(domain, profile)| (domain, Arc::new(ProfileScraper::new(profile)) as Arc<dyn PlatformScraper>))
+            .collect();
+        // SelectorProfiles 는 항상 내장 generic 프로필을 포함
+        let fallback = platforms["generic"].clone();
+
+        Self { platforms, domains, fallback }
+    }
+
+    pub fn register<S: PlatformScraper + 'static>(&mut self, platform: &str, scraper: S) -> &mut Self {
+        self.platforms.insert(platform.to_lowercase(), Arc::new(scraper));
+        self
+    }
+
+    pub fn register_domain<S: PlatformScraper + 'static>(&mut self, domain: &str, scraper: S) -> &mut Self {
+        self.domains.insert(normalize_domain(domain), Arc::new(scraper));
+        self
+    }
+
+    pub fn for_shop(&self, shop: &Shop) -> Arc<dyn PlatformScraper> {
+        self.domains
+            .get(&normalize_domain(&shop.domain))
+            .or_else(|| self.platforms.get(&shop.platform.to_lowercase()))
+            .unwrap_or(&self.fallback)
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crawler::{FixtureFetcher, PageFetcher, PageValidators};
+
+    fn shop(domain: &str, platform: &str) -> Shop {
+        Shop {
+            id: 1,
+            name: domain.to_string(),
+            domain: domain.to_string(),
+            platform: platform.to_string(),
+            logo_url: None,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    // fixtures/crawler 의 페이지를 상점 플랫폼의 스크레이퍼로 추출 (네트워크 없음)
+    async fn scrape(platform: &str, url: &str) -> ScrapedProduct {
+        let fetcher = FixtureFetcher::new(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/crawler"));
+        let page = fetcher.fetch(url, &PageValidators::default()).await.unwrap();
+        let domain = reqwest::Url::parse(url).unwrap().host_str().unwrap().to_string();
+
+        ScraperRegistry::from_profiles(SelectorProfiles::builtin())
+            .for_shop(&shop(&domain, platform))
+            .extract_product(&page.body, &page.url)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn extracts_fixture_products_per_profile() {
+        // (플랫폼, URL, 이름, SKU, 정가, 할인가, 통화)
+        let cases = [
+            ("shopify", "https://shop.example.com/products/linen-shirt", "Linen Shirt", "LS-001", 59000.0, Some(41300.0), "KRW"),
+            ("shopify", "https://shop.example.com/products/wool-coat", "Wool Coat", "WC-210", 189000.0, None, "KRW"),
+            ("cafe24", "https://mall.example.co.kr/product/detail.html?product_no=12", "데일리 니트 가디건", "P00000MK", 39000.0, Some(29900.0), "KRW"),
+            ("generic", "https://store.example.org/items/kettle", "Electric Kettle 1.7L", "EK-17", 49.99, Some(39.99), "USD"),
+        ];
+
+        for (platform, url, name, sku, price, sale_price, currency) in cases {
+            let product = scrape(platform, url).await;
+            assert_eq!(product.url, url);
+            assert_eq!(product.name, name, "{}", url);
+            assert_eq!(product.sku.as_deref(), Some(sku), "{}", url);
+            assert_eq!(product.price, price, "{}", url);
+            assert_eq!(product.sale_price, sale_price, "{}", url);
+            assert_eq!(product.currency, currency, "{}", url);
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fixture_image_urls() {
+        let product = scrape("shopify", "https://shop.example.com/products/linen-shirt").await;
+        assert_eq!(product.image_url.as_deref(), Some("https://shop.example.com/cdn/shop/files/linen-shirt.jpg"));
+
+        let product = scrape("cafe24", "https://mall.example.co.kr/product/detail.html?product_no=12").await;
+        assert_eq!(product.image_url.as_deref(), Some("https://mall.example.co.kr/web/product/big/cardigan.jpg"));
+    }
+
+    #[tokio::test]
+    async fn extracts_json_ld_graph_with_offer_array() {
+        // @graph 안의 Product, offers 배열의 StrikethroughPrice 가 정가
+        let product = scrape("shopify", "https://shop.example.com/products/canvas-tote").await;
+        assert_eq!(product.name, "Canvas Tote Bag");
+        assert_eq!(product.sku.as_deref(), Some("CT-300"));
+        assert_eq!((product.price, product.sale_price), (32000.0, Some(25000.0)));
+        assert_eq!(product.image_url.as_deref(), Some("https://shop.example.com/cdn/shop/files/canvas-tote.jpg"));
+    }
+
+    #[test]
+    fn fills_missing_json_ld_values_from_css_selectors() {
+        let html = r#"<html><head><script type="application/ld+json">
+            {"@type": "Product", "name": "Linen Shirt", "offers": {"@type": "AggregateOffer", "lowPrice": 41300, "priceCurrency": "KRW"}}
+        </script></head><body>
+            <p class="product__sku">SKU: <span data-sku>LS-001</span></p>
+            <div class="price price--on-sale"><s class="price-item price-item--regular">₩59,000</s></div>
+        </body></html>"#;
+        let scraper = ScraperRegistry::from_profiles(SelectorProfiles::builtin()).for_shop(&shop("shop.example.com", "shopify"));
+
+        let product = scraper.extract_product(html, "https://shop.example.com/products/linen-shirt").unwrap();
+        assert_eq!(product.sku.as_deref(), Some("LS-001"));
+        assert_eq!((product.price, product.sale_price), (59000.0, Some(41300.0)));
+        assert_eq!(product.currency, "KRW");
+    }
+
+    #[tokio::test]
+    async fn follows_fixture_listing_pages() {
+        let fetcher = FixtureFetcher::new(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/crawler"));
+        let scraper = ScraperRegistry::from_profiles(SelectorProfiles::builtin()).for_shop(&shop("shop.example.com", "shopify"));
+        assert_eq!(scraper.listing_paths(), vec!["/collections/all"]);
+
+        let page = fetcher.fetch("https://shop.example.com/collections/all", &PageValidators::default()).await.unwrap();
+        let listing = scraper.parse_listing(&page.body, &page.url);
+        assert_eq!(listing.product_urls, vec![
+            "https://shop.example.com/products/linen-shirt",
+            "https://shop.example.com/products/wool-coat",
+        ]);
+        assert_eq!(listing.next_page.as_deref(), Some("https://shop.example.com/collections/all?page=2"));
+
+        // 마지막 페이지 - JSON-LD ItemList 와 상품 카드 링크가 같은 상품이면 한 번만
+        let page = fetcher.fetch(listing.next_page.as_deref().unwrap(), &PageValidators::default()).await.unwrap();
+        let listing = scraper.parse_listing(&page.body, &page.url);
+        assert_eq!(listing.product_urls, vec![
+            "https://shop.example.com/products/canvas-tote",
+            "https://partner.example.net/products/other",
+        ]);
+        assert_eq!(listing.next_page, None);
+    }
+
+    #[test]
+    fn finds_next_page_from_css_pagination_without_link_rel() {
+        let scraper = ScraperRegistry::from_profiles(SelectorProfiles::builtin()).for_shop(&shop("mall.example.co.kr", "cafe24"));
+        let page_url = "https://mall.example.co.kr/product/list.html?cate_no=24&page=2";
+        let listing_html = |next_href: &str| format!(r#"<html><body>
+            <ul class="prdList"><li><p class="name"><a href="/product/detail.html?product_no=12#detail">가디건</a></p></li></ul>
+            <div class="xans-product-normalpaging"><a href="?cate_no=24&page=1" class="prev">이전</a><a href="{}" class="next">다음</a></div>
+        </body></html>"#, next_href);
+
+        let listing = scraper.parse_listing(&listing_html("?cate_no=24&amp;page=3"), page_url);
+        assert_eq!(listing.product_urls, vec!["https://mall.example.co.kr/product/detail.html?product_no=12"]);
+        assert_eq!(listing.next_page.as_deref(), Some("https://mall.example.co.kr/product/list.html?cate_no=24&page=3"));
+
+        // 마지막 페이지의 "다음" 링크는 자기 자신(#none)
+        let listing = scraper.parse_listing(&listing_html("#none"), page_url);
+        assert_eq!(listing.next_page, None);
+    }
+
+    #[test]
+    fn registry_prefers_domain_then_platform_then_generic() {
+        struct Fixed(&'static str);
+        impl PlatformScraper for Fixed {
+            fn parse_listing(&self, _html: &str, _page_url: &str) -> ListingPage {
+                ListingPage::default()
+            }
+            fn extract_product(&self, _html: &str, url: &str) -> Result<ScrapedProduct, CrawlError> {
+                Err(CrawlError::extract(url, self.0))
+            }
+        }
+
+        let mut registry = ScraperRegistry::from_profiles(SelectorProfiles::builtin());
+        registry.register("custom", Fixed("platform")).register_domain("https://www.Special.example.com/", Fixed("domain"));
+        let scraped = |shop: Shop| registry.for_shop(&shop).extract_product("", "https://x").unwrap_err().to_string();
+
+        assert!(scraped(shop("special.example.com", "custom")).ends_with("domain"));
+        assert!(scraped(shop("other.example.com", "Custom")).ends_with("platform"));
+        // 알 수 없는 플랫폼은 generic 프로필 (빈 페이지라 이름 없음)
+        assert!(scraped(shop("other.example.com", "unknown")).ends_with("product name not found"));
+    }
+}
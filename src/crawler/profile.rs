@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use scraper::Selector;
+use serde::Deserialize;
+
+// 한 필드의 CSS 선택자 목록 - 앞에서부터 시도해 처음으로 값이 나온 것을 사용
+// "선택자@속성" 이면 요소 텍스트 대신 속성 값을 읽는다 (예: meta[property="og:image"]@content)
+pub type SelectorList = Vec<String>;
+
+// 상품 상세/카테고리 목록 페이지 추출 규칙 (JSON-LD 로 채우지 못한 값에 사용)
+#[derive(Debug, Clone, Deserialize)]
+pub struct SelectorProfile {
+    #[serde(default)]
+    pub name: SelectorList,
+    #[serde(default)]
+    pub sku: SelectorList,
+    #[serde(default)]
+    pub price: SelectorList, // 정가 (할인 중이면 보통 취소선 가격)
+    #[serde(default)]
+    pub sale_price: SelectorList, // 판매가 - 정가보다 낮을 때만 할인으로 봄
+    #[serde(default)]
+    pub currency: SelectorList, // 통화 코드 (없으면 가격의 통화 기호, 그다음 default_currency)
+    #[serde(default)]
+    pub image: SelectorList,
+    #[serde(default)]
+    pub default_currency: Option<String>,
+    #[serde(default)]
+    pub product_links: SelectorList, // 목록 페이지의 상품 링크 (보통 "a...@href")
+    #[serde(default = "default_next_page")]
+    pub next_page: SelectorList, // 목록 페이지의 다음 페이지 링크
+    #[serde(default)]
+    pub listing_paths: Vec<String>, // 목록 URL 을 지정하지 않았을 때 크롤링할 상점 도메인 기준 목록 경로
+}
+
+impl SelectorProfile {
+    // 설정 파일의 잘못된 선택자를 로드 시점에 확인
+    pub fn validate(&self) -> Result<(), String> {
+        [&self.name, &self.sku, &self.price, &self.sale_price, &self.currency, &self.image, &self.product_links, &self.next_page]
+            .into_iter()
+            .flatten()
+            .try_for_each(|spec| {
+                let (css, _) = split_selector(spec);
+                Selector::parse(css)
+                    .map(|_| ())
+                    .map_err(|e| format!("Invalid CSS selector '{}': {:?}", spec, e))
+            })
+    }
+}
+
+// "선택자@속성" 분리 (속성 이름이 아닌 '@' 는 선택자의 일부로 봄)
+pub fn split_selector(spec: &str) -> (&str, Option<&str>) {
+    match spec.rsplit_once('@') {
+        Some((css, attr)) if !attr.is_empty() && attr.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == ':' || c == '_') => {
+            (css.trim(), Some(attr))
+        }
+        _ => (spec.trim(), None),
+    }
+}
+
+// CRAWLER_PROFILES_PATH 파일 형식
+//   {"platforms": {"shopify": {...}}, "domains": {"shop.example.com": {...}}}
+#[derive(Debug, Default, Deserialize)]
+struct ProfileFile {
+    #[serde(default)]
+    platforms: HashMap<String, SelectorProfile>,
+    #[serde(default)]
+    domains: HashMap<String, SelectorProfile>,
+}
+
+// 플랫폼/도메인별 추출 규칙 (내장 + CRAWLER_PROFILES_PATH) - ScraperRegistry 가 스크레이퍼로 등록
+#[derive(Debug, Clone)]
+pub struct SelectorProfiles {
+    pub platforms: HashMap<String, SelectorProfile>,
+    pub domains: HashMap<String, SelectorProfile>,
+}
+
+impl SelectorProfiles {
+    pub fn builtin() -> Self {
+        let platforms = [
+            ("generic", generic_profile()),
+            ("shopify", shopify_profile()),
+            ("cafe24", cafe24_profile()),
+            ("smartstore", smartstore_profile()),
+        ]
+        .into_iter()
+        .map(|(platform, profile)| (platform.to_string(), profile))
+        .collect();
+
+        Self { platforms, domains: HashMap::new() }
+    }
+
+    // 내장 프로필에 설정 파일 프로필을 추가 (같은 플랫폼이면 덮어씀)
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let file: ProfileFile = serde_json::from_str(&text)
+            .map_err(|e| format!("Invalid crawler profile file {}: {}", path.display(), e))?;
+
+        let mut profiles = Self::builtin();
+        for (platform, profile) in file.platforms {
+            profile.validate()?;
+            profiles.platforms.insert(platform.to_lowercase(), profile);
+        }
+        for (domain, profile) in file.domains {
+            profile.validate()?;
+            profiles.domains.insert(normalize_domain(&domain), profile);
+        }
+
+        Ok(profiles)
+    }
+}
+
+// "https://www.Shop.com/" → "shop.com"
+pub fn normalize_domain(domain: &str) -> String {
+    let domain = domain.trim().to_lowercase();
+    let domain = domain.split_once("://").map_or(domain.as_str(), |(_, rest)| rest);
+    let domain = domain.split('/').next().unwrap_or_default();
+    domain.strip_prefix("www.").unwrap_or(domain).to_string()
+}
+
+fn list(selectors: &[&str]) -> SelectorList {
+    selectors.iter().map(|s| s.to_string()).collect()
+}
+
+fn default_next_page() -> SelectorList {
+    list(&[r#"link[rel="next"]@href"#, r#"a[rel="next"]@href"#])
+}
+
+// Open Graph / 상품 메타 태그 / schema.org 마이크로데이터
+fn generic_profile() -> SelectorProfile {
+    SelectorProfile {
+        name: list(&[r#"meta[property="og:title"]@content"#, r#"[itemprop="name"]"#, "h1"]),
+        sku: list(&[r#"[itemprop="sku"]@content"#, r#"[itemprop="sku"]"#]),
+        price: list(&[
+            r#"meta[property="product:original_price:amount"]@content"#,
+            r#"meta[property="product:price:amount"]@content"#,
+            r#"[itemprop="price"]@content"#,
+            r#"[itemprop="price"]"#,
+        ]),
+        sale_price: list(&[
+            r#"meta[property="product:sale_price:amount"]@content"#,
+            r#"meta[property="product:price:amount"]@content"#,
+        ]),
+        currency: list(&[
+            r#"meta[property="product:price:currency"]@content"#,
+            r#"[itemprop="priceCurrency"]@content"#,
+        ]),
+        image: list(&[r#"meta[property="og:image"]@content"#, r#"[itemprop="image"]@src"#]),
+        default_currency: None,
+        product_links: list(&[r#"[itemtype$="/Product"] a[itemprop="url"]@href"#]),
+        next_page: default_next_page(),
+        listing_paths: Vec::new(),
+    }
+}
+
+// Shopify Online Store 2.0 (Dawn 계열) 테마 - 할인 중이면 .price--on-sale 에 정가(s)와 할인가가 함께 표시됨
+fn shopify_profile() -> SelectorProfile {
+    SelectorProfile {
+        name: list(&[".product__title h1", "h1.product-single__title", r#"meta[property="og:title"]@content"#]),
+        sku: list(&[".product__sku [data-sku]", ".product__sku", "[data-product-sku]"]),
+        price: list(&[
+            ".price--on-sale s.price-item--regular",
+            ".price__regular .price-item--regular",
+            r#"meta[property="og:price:amount"]@content"#,
+        ]),
+        sale_price: list(&[".price--on-sale .price-item--sale"]),
+        currency: list(&[r#"meta[property="og:price:currency"]@content"#]),
+        image: list(&[r#"meta[property="og:image"]@content"#, ".product__media img@src"]),
+        default_currency: None,
+        product_links: list(&[".card__heading a@href", ".product-card a@href", r#"a[href*="/products/"]@href"#]),
+        next_page: list(&[r#"link[rel="next"]@href"#, r#".pagination a[aria-label="Next page"]@href"#]),
+        listing_paths: list(&["/collections/all"]),
+    }
+}
+
+// Cafe24 기본 스킨 - 소비자가(#span_product_price_custom)가 있으면 정가, 판매가/할인판매가가 실제 가격
+fn cafe24_profile() -> SelectorProfile {
+    SelectorProfile {
+        name: list(&[".infoArea .headingArea h2", ".headingArea h2", r#"meta[property="og:title"]@content"#]),
+        sku: list(&["#product_code", r#"meta[property="product:retailer_item_id"]@content"#]),
+        price: list(&["#span_product_price_custom", "#span_product_price_text"]),
+        sale_price: list(&["#span_product_price_sale", "#span_product_price_text"]),
+        currency: list(&[r#"meta[property="product:price:currency"]@content"#]),
+        image: list(&[r#"meta[property="og:image"]@content"#, ".keyImg img@src"]),
+        default_currency: Some("KRW".to_string()),
+        product_links: list(&[".prdList .name a@href", r#".xans-product-listnormal a[href*="/product/"]@href"#]),
+        next_page: list(&[r#"link[rel="next"]@href"#, ".xans-product-normalpaging a.next@href", ".xans-product-normalpaging p.next a@href"]),
+        listing_paths: Vec::new(),
+    }
+}
+
+// 네이버 스마트스토어 - 상품 정보는 주로 JSON-LD 로 읽고 메타 태그로 보완
+fn smartstore_profile() -> SelectorProfile {
+    SelectorProfile {
+        name: list(&[r#"meta[property="og:title"]@content"#]),
+        sku: Vec::new(),
+        price: list(&[r#"meta[property="product:price:amount"]@content"#]),
+        sale_price: list(&[r#"meta[property="product:sale_price:amount"]@content"#]),
+        currency: list(&[r#"meta[property="product:price:currency"]@content"#]),
+        image: list(&[r#"meta[property="og:image"]@content"#]),
+        default_currency: Some("KRW".to_string()),
+        product_links: list(&[r#"a[href*="/products/"]@href"#]),
+        next_page: default_next_page(),
+        listing_paths: Vec::new(),
+    }
+}
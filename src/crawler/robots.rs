@@ -0,0 +1,284 @@
+use std::time::Duration;
+
+use crate::utils::constants::CRAWLER_MAX_CRAWL_DELAY_SECS;
+
+// robots.txt 규칙 (RFC 9309) - 우리 User-Agent 에 해당하는 그룹의 규칙만 보관
+//   - 제품 토큰(User-Agent 의 '/' 앞부분)과 가장 길게 일치하는 user-agent 그룹, 없으면 '*' 그룹
+//   - 같은 user-agent 의 그룹이 여러 개면 합침
+//   - 경로와 가장 길게 일치하는 Allow/Disallow 가 적용되고, 길이가 같으면 Allow 우선
+//   - 패턴의 '*' 는 임의 문자열, 끝의 '$' 는 경로 끝
+//   - Crawl-delay 는 표준은 아니지만 널리 쓰여 도메인 요청 간격에 반영 (CRAWLER_MAX_CRAWL_DELAY_SECS 이하)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RobotsRules {
+    rules: Vec<RobotsRule>,
+    crawl_delay: Option<Duration>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct RobotsRule {
+    allow: bool,
+    pattern: String,
+}
+
+#[derive(Default)]
+struct RobotsGroup {
+    agents: Vec<String>,
+    rules: Vec<RobotsRule>,
+    crawl_delay: Option<Duration>,
+}
+
+impl RobotsRules {
+    // robots.txt 가 없을 때 (4xx)
+    pub fn allow_all() -> Self {
+        Self::default()
+    }
+
+    // robots.txt 를 읽을 수 없을 때 (5xx, 네트워크 오류) - 서버 상태를 알 수 없으므로 크롤링하지 않음
+    pub fn disallow_all() -> Self {
+        Self {
+            rules: vec![RobotsRule { allow: false, pattern: "/".to_string() }],
+            crawl_delay: None,
+        }
+    }
+
+    pub fn parse(text: &str, user_agent: &str) -> Self {
+        let token = user_agent.split('/').next().unwrap_or_default().trim().to_lowercase();
+
+        let mut groups: Vec<RobotsGroup> = Vec::new();
+        let mut reading_agents = false;
+        for line in text.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+
+            match key.trim().to_lowercase().as_str() {
+                "user-agent" => {
+                    // 연속된 user-agent 줄은 같은 그룹
+                    if !reading_agents {
+                        groups.push(RobotsGroup::default());
+                    }
+                    if let Some(group) = groups.last_mut() {
+                        group.agents.push(value.to_lowercase());
+                    }
+                    reading_agents = true;
+                }
+                key @ ("allow" | "disallow") => {
+                    reading_agents = false;
+                    // 빈 Disallow 는 "모두 허용"
+                    if let (Some(group), false) = (groups.last_mut(), value.is_empty()) {
+                        group.rules.push(RobotsRule { allow: key == "allow", pattern: value.to_string() });
+                    }
+                }
+                "crawl-delay" => {
+                    reading_agents = false;
+                    if let Some(group) = groups.last_mut() {
+                        // 음수/NaN 은 무시, 너무 큰 값(inf 포함)은 상한으로
+                        group.crawl_delay = value
+                            .parse::<f64>()
+                            .ok()
+                            .filter(|secs| *secs >= 0.0)
+                            .and_then(|secs| Duration::try_from_secs_f64(secs.min(CRAWLER_MAX_CRAWL_DELAY_SECS as f64)).ok());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // 가장 구체적인 user-agent (제품 토큰이 그 이름으로 시작하는 것 중 가장 긴 것), 없으면 '*'
+        let matched_agent = groups
+            .iter()
+            .flat_map(|group| &group.agents)
+            .filter(|agent| *agent != "*" && !agent.is_empty() && token.starts_with(agent.as_str()))
+            .max_by_key(|agent| agent.len())
+            .cloned()
+            .unwrap_or_else(|| "*".to_string());
+
+        groups
+            .into_iter()
+            .filter(|group| group.agents.contains(&matched_agent))
+            .fold(Self::default(), |mut rules, group| {
+                rules.rules.extend(group.rules);
+                rules.crawl_delay = rules.crawl_delay.max(group.crawl_delay);
+                rules
+            })
+    }
+
+    // path 는 경로 + 쿼리 ("/products/a?variant=1")
+    pub fn is_allowed(&self, path: &str) -> bool {
+        if path == "/robots.txt" {
+            return true;
+        }
+
+        self.rules
+            .iter()
+            .filter(|rule| pattern_matches(&rule.pattern, path))
+            .max_by_key(|rule| (rule.pattern.len(), rule.allow))
+            .is_none_or(|rule| rule.allow)
+    }
+
+    pub fn crawl_delay(&self) -> Option<Duration> {
+        self.crawl_delay
+    }
+}
+
+// 경로 앞부분부터 비교 - '*' 는 임의 문자열, 끝의 '$' 는 경로 끝
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(pattern) => (pattern, true),
+        None => (pattern, false),
+    };
+
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let Some(prefix) = parts.first() else {
+        return true;
+    };
+    if !path.starts_with(prefix) {
+        return false;
+    }
+
+    let mut position = prefix.len();
+    for (index, part) in parts.iter().enumerate().skip(1) {
+        // '$' 로 끝나면 마지막 조각은 경로 끝에 있어야 함
+        if anchored && index == parts.len() - 1 {
+            return path.len() - position >= part.len() && path.ends_with(part);
+        }
+        match path[position..].find(part) {
+            Some(offset) => position += offset + part.len(),
+            None => return false,
+        }
+    }
+
+    !anchored || position == path.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // RFC 9309 5.1 예시
+    const RFC_EXAMPLE: &str = "User-Agent: *
+Disallow: *.gif$
+Disallow: /example/
+Allow: /publications/
+
+User-Agent: foobot
+Disallow:/
+Allow:/example/page.html
+Allow:/example/allowed.gif
+
+User-Agent: barbot
+User-Agent: bazbot
+Disallow: /example/page.html
+
+User-Agent: quxbot
+";
+
+    #[test]
+    fn rfc_9309_example_groups() {
+        let foobot = RobotsRules::parse(RFC_EXAMPLE, "FooBot/1.0");
+        assert!(foobot.is_allowed("/example/page.html"));
+        assert!(foobot.is_allowed("/example/allowed.gif"));
+        assert!(!foobot.is_allowed("/example/other.html"));
+        assert!(!foobot.is_allowed("/publications/"));
+
+        // 연속된 user-agent 줄은 한 그룹
+        for agent in ["barbot", "bazbot"] {
+            let rules = RobotsRules::parse(RFC_EXAMPLE, agent);
+            assert!(!rules.is_allowed("/example/page.html"), "{}", agent);
+            assert!(rules.is_allowed("/example/other.html"), "{}", agent);
+            assert!(rules.is_allowed("/image.gif"), "{}", agent);
+        }
+
+        // 규칙 없는 그룹은 모두 허용
+        let quxbot = RobotsRules::parse(RFC_EXAMPLE, "quxbot");
+        assert!(quxbot.is_allowed("/example/page.html"));
+
+        // 일치하는 그룹이 없으면 '*'
+        let other = RobotsRules::parse(RFC_EXAMPLE, "DukBot/0.1");
+        assert!(!other.is_allowed("/image.gif"));
+        assert!(other.is_allowed("/image.gif?size=large"));
+        assert!(!other.is_allowed("/example/page.html"));
+        assert!(other.is_allowed("/publications/paper.html"));
+        assert!(other.is_allowed("/"));
+    }
+
+    #[test]
+    fn longest_match_wins_and_allow_wins_ties() {
+        let rules = RobotsRules::parse("User-agent: *\nAllow: /p\nDisallow: /\nAllow: /folder\nDisallow: /folder\nDisallow: /page$\n", "DukBot");
+        assert!(rules.is_allowed("/page.html"));
+        assert!(!rules.is_allowed("/page"));
+        assert!(rules.is_allowed("/folder/page"));
+        assert!(!rules.is_allowed("/other"));
+        assert!(rules.is_allowed("/robots.txt"));
+    }
+
+    #[test]
+    fn pattern_matching_examples() {
+        // (패턴, 일치하는 경로, 일치하지 않는 경로)
+        let cases: [(&str, &[&str], &[&str]); 6] = [
+            ("/fish", &["/fish", "/fish.html", "/fish/salmon.html", "/fishheads", "/fish.php?id=anything"], &["/Fish.asp", "/catfish", "/?id=fish"]),
+            ("/fish*", &["/fish", "/fish.html", "/fishheads/yummy.html"], &["/Fish.asp", "/catfish"]),
+            ("/fish/", &["/fish/", "/fish/?id=anything", "/fish/salmon.htm"], &["/fish", "/fish.html"]),
+            ("/*.php", &["/index.php", "/folder/filename.php?parameters", "/folder/any.php.file.html", "/filename.php/"], &["/", "/windows.PHP"]),
+            ("/*.php$", &["/filename.php", "/folder/filename.php"], &["/filename.php?parameters", "/filename.php/", "/filename.php5", "/windows.PHP"]),
+            ("/fish*.php", &["/fish.php", "/fishheads/catfish.php?parameters"], &["/Fish.PHP"]),
+        ];
+
+        for (pattern, matching, not_matching) in cases {
+            for path in matching {
+                assert!(pattern_matches(pattern, path), "{} should match {}", pattern, path);
+            }
+            for path in not_matching {
+                assert!(!pattern_matches(pattern, path), "{} should not match {}", pattern, path);
+            }
+        }
+    }
+
+    #[test]
+    fn merges_groups_and_ignores_comments_and_empty_disallow() {
+        let text = "# comment\nUser-agent: DukBot # our bot\nDisallow: /cart\n\nUser-agent: *\nDisallow: /\n\nUser-agent: dukbot\nDisallow: /search\nDisallow:\n";
+        let rules = RobotsRules::parse(text, "DukBot/0.1 (+https://example.com/bot)");
+        assert!(!rules.is_allowed("/cart"));
+        assert!(!rules.is_allowed("/search?q=coat"));
+        assert!(rules.is_allowed("/products/coat"));
+    }
+
+    #[test]
+    fn fixture_robots_txt() {
+        let text = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/crawler/shop.example.com/robots.txt")).unwrap();
+
+        let rules = RobotsRules::parse(&text, "DukBot/0.1");
+        assert!(rules.is_allowed("/products/linen-shirt"));
+        assert!(!rules.is_allowed("/products/linen-shirt?variant=1"));
+        assert!(!rules.is_allowed("/cart"));
+        assert_eq!(rules.crawl_delay(), Some(Duration::from_secs(1)));
+
+        let other = RobotsRules::parse(&text, "OtherBot");
+        assert!(!other.is_allowed("/products/linen-shirt"));
+        assert_eq!(other.crawl_delay(), None);
+    }
+
+    #[test]
+    fn crawl_delay_is_clamped_and_invalid_values_ignored() {
+        let delay = |value: &str| RobotsRules::parse(&format!("User-agent: *\nCrawl-delay: {}\n", value), "DukBot").crawl_delay();
+        let max = Duration::from_secs(CRAWLER_MAX_CRAWL_DELAY_SECS);
+
+        assert_eq!(delay("1.5"), Some(Duration::from_millis(1500)));
+        assert_eq!(delay("0"), Some(Duration::ZERO));
+        assert_eq!(delay("86400"), Some(max));
+        assert_eq!(delay("1e400"), Some(max)); // inf
+        assert_eq!(delay("1e300"), Some(max)); // Duration 범위 밖
+        assert_eq!(delay("-1"), None);
+        assert_eq!(delay("NaN"), None);
+        assert_eq!(delay("soon"), None);
+    }
+
+    #[test]
+    fn allow_all_and_disallow_all() {
+        assert!(RobotsRules::allow_all().is_allowed("/anything"));
+        assert!(!RobotsRules::disallow_all().is_allowed("/anything"));
+        assert!(RobotsRules::disallow_all().is_allowed("/robots.txt"));
+    }
+}
@@ -1,4 +1,6 @@
 
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,4 +18,225 @@ pub struct PagenationResult<T> {
     pub total_pages: u32,
     pub has_next: bool,
     pub has_prev: bool,
-}
\ No newline at end of file
+}
+
+impl<T> PagenationResult<T> {
+    pub fn new(data: Vec<T>, total: u64, pagination: &Pagenation) -> Self {
+        let total_pages = (total as f64 / pagination.limit as f64).ceil() as u32;
+
+        Self {
+            data,
+            total,
+            page: pagination.page,
+            limit: pagination.limit,
+            total_pages,
+            has_next: pagination.page < total_pages,
+            has_prev: pagination.page > 1,
+        }
+    }
+}
+
+// 정렬 키 (sort=field:direction)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SortKey {
+    pub field: String,
+    pub direction: String,
+}
+
+// 다중 정렬 (앞선 키가 우선) - RPC 파라미터로는 [{"field", "direction"}] 배열로 직렬화
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Sort(pub Vec<SortKey>);
+
+impl Sort {
+    // PostgREST order 파라미터 ("name.asc,created_at.desc") - 페이지 경계가 흔들리지 않도록 id를 마지막 키로 추가
+    pub fn to_postgrest_order(&self) -> String {
+        let mut keys: Vec<String> = self.0
+            .iter()
+            .map(|key| format!("{}.{}", key.field, key.direction))
+            .collect();
+
+        if !self.0.iter().any(|key| key.field == "id") {
+            keys.push("id.desc".to_string());
+        }
+
+        keys.join(",")
+    }
+}
+
+// 커서 이동 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CursorDirection {
+    Next,
+    Prev,
+}
+
+// 키셋 커서 - (created_at, id) 위치와 이동 방향을 불투명 문자열로 인코딩
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cursor {
+    pub created_at: DateTime<Utc>,
+    pub id: i64,
+    pub direction: CursorDirection,
+}
+
+impl Cursor {
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    pub fn decode(token: &str) -> Option<Self> {
+        let bytes = URL_SAFE_NO_PAD.decode(token).ok()?;
+        serde_json::from_slice(&bytes).ok()
+    }
+}
+
+// 키셋 페이지네이션 요청 (cursor가 없으면 첫 페이지) - count 쿼리 없이 created_at DESC, id DESC 순으로 조회
+#[derive(Debug, Clone)]
+pub struct CursorPagenation {
+    pub cursor: Option<Cursor>,
+    pub limit: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CursorPagenationResult<T> {
+    pub data: Vec<T>,
+    pub limit: u32,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+    pub has_next: bool,
+    pub has_prev: bool,
+}
+
+// 키셋 페이지네이션 대상 엔티티의 정렬 키
+pub trait KeysetItem {
+    fn keyset_key(&self) -> (DateTime<Utc>, i64);
+}
+
+impl<T: KeysetItem> CursorPagenationResult<T> {
+    // limit + 1건 조회 결과(조회 방향 순서)로 페이지 구성 - 초과분 1건으로 다음 페이지 존재 여부 판단
+    pub fn from_rows(mut rows: Vec<T>, pagination: &CursorPagenation) -> Self {
+        let has_more = rows.len() > pagination.limit as usize;
+        rows.truncate(pagination.limit as usize);
+
+        let direction = pagination.cursor.as_ref().map(|cursor| cursor.direction);
+        if direction == Some(CursorDirection::Prev) {
+            rows.reverse();
+        }
+
+        let (has_next, has_prev) = match direction {
+            None => (has_more, false),
+            Some(CursorDirection::Next) => (has_more, true),
+            Some(CursorDirection::Prev) => (true, has_more),
+        };
+
+        let cursor_at = |item: &T, direction: CursorDirection| {
+            let (created_at, id) = item.keyset_key();
+            Cursor { created_at, id, direction }.encode()
+        };
+        let next_cursor = rows.last().filter(|_| has_next).map(|item| cursor_at(item, CursorDirection::Next));
+        let prev_cursor = rows.first().filter(|_| has_prev).map(|item| cursor_at(item, CursorDirection::Prev));
+
+        Self {
+            data: rows,
+            limit: pagination.limit,
+            next_cursor,
+            prev_cursor,
+            has_next,
+            has_prev,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[derive(Debug)]
+    struct Item(i64);
+
+    // id 가 클수록 최근 (created_at DESC, id DESC 순서)
+    impl KeysetItem for Item {
+        fn keyset_key(&self) -> (DateTime<Utc>, i64) {
+            (Utc.timestamp_opt(1_700_000_000 + self.0, 0).unwrap(), self.0)
+        }
+    }
+
+    fn ids(result: &CursorPagenationResult<Item>) -> Vec<i64> {
+        result.data.iter().map(|item| item.0).collect()
+    }
+
+    fn page(cursor: Option<&str>, limit: u32) -> CursorPagenation {
+        CursorPagenation { cursor: cursor.map(|token| Cursor::decode(token).unwrap()), limit }
+    }
+
+    #[test]
+    fn cursor_round_trips() {
+        let cursor = Cursor {
+            created_at: Utc.with_ymd_and_hms(2026, 10, 17, 12, 0, 0).unwrap() + chrono::Duration::microseconds(123456),
+            id: 42,
+            direction: CursorDirection::Prev,
+        };
+
+        let token = cursor.encode();
+        assert!(token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+
+        let decoded = Cursor::decode(&token).unwrap();
+        assert_eq!((decoded.created_at, decoded.id, decoded.direction), (cursor.created_at, 42, CursorDirection::Prev));
+    }
+
+    #[test]
+    fn corrupted_cursor_is_rejected() {
+        let token = Cursor { created_at: Utc::now(), id: 1, direction: CursorDirection::Next }.encode();
+
+        assert!(Cursor::decode("").is_none());
+        assert!(Cursor::decode("not a cursor!").is_none());
+        assert!(Cursor::decode(&token[..token.len() - 4]).is_none());
+        assert!(Cursor::decode(&URL_SAFE_NO_PAD.encode(br#"{"id": 1}"#)).is_none());
+    }
+
+    #[test]
+    fn first_page_uses_extra_row_for_has_next() {
+        let result = CursorPagenationResult::from_rows(vec![Item(5), Item(4), Item(3)], &page(None, 2));
+        assert_eq!(ids(&result), vec![5, 4]);
+        assert!(result.has_next && !result.has_prev);
+        assert!(result.prev_cursor.is_none());
+
+        let next = Cursor::decode(result.next_cursor.as_deref().unwrap()).unwrap();
+        assert_eq!((next.id, next.direction), (4, CursorDirection::Next));
+
+        // 정확히 limit 건이면 마지막 페이지
+        let result = CursorPagenationResult::from_rows(vec![Item(5), Item(4)], &page(None, 2));
+        assert!(!result.has_next && result.next_cursor.is_none());
+    }
+
+    #[test]
+    fn next_page_has_prev_cursor_at_first_row() {
+        let after = Cursor { created_at: Item(4).keyset_key().0, id: 4, direction: CursorDirection::Next }.encode();
+
+        let result = CursorPagenationResult::from_rows(vec![Item(3), Item(2)], &page(Some(&after), 2));
+        assert_eq!(ids(&result), vec![3, 2]);
+        assert!(!result.has_next && result.has_prev);
+
+        let prev = Cursor::decode(result.prev_cursor.as_deref().unwrap()).unwrap();
+        assert_eq!((prev.id, prev.direction), (3, CursorDirection::Prev));
+    }
+
+    #[test]
+    fn prev_page_rows_are_returned_in_original_order() {
+        let before = Cursor { created_at: Item(3).keyset_key().0, id: 3, direction: CursorDirection::Prev }.encode();
+
+        // 이전 페이지는 ASC 로 조회됨 (3 바로 앞부터)
+        let result = CursorPagenationResult::from_rows(vec![Item(4), Item(5), Item(6)], &page(Some(&before), 2));
+        assert_eq!(ids(&result), vec![5, 4]);
+        assert!(result.has_next && result.has_prev);
+        assert_eq!(Cursor::decode(result.next_cursor.as_deref().unwrap()).unwrap().id, 4);
+        assert_eq!(Cursor::decode(result.prev_cursor.as_deref().unwrap()).unwrap().id, 5);
+
+        // 더 앞이 없으면 has_prev = false
+        let result = CursorPagenationResult::from_rows(vec![Item(4), Item(5)], &page(Some(&before), 2));
+        assert_eq!(ids(&result), vec![5, 4]);
+        assert!(result.has_next && !result.has_prev);
+    }
+}
@@ -1,6 +1,10 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 
+use crate::domain::entities::notification::{NotificationSettings, QuietHours};
+
 // Shop 관련 요청 DTO
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CreateShopRequest {
@@ -37,6 +41,19 @@ pub struct UpdateProductRequest {
     pub is_deleted: Option<bool>,
 }
 
+// 상품 목록 패싯 필터 (filter_products RPC 파라미터로 그대로 직렬화)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProductFilter {
+    pub country: Option<String>,
+    pub shop_id: Option<i64>,
+    pub brand_id: Option<i64>,
+    pub category_id: Option<i64>,
+    pub include_subcategories: bool,
+    pub min_discount_rate: Option<f64>,
+    pub max_discount_rate: Option<f64>,
+    pub has_discount: Option<bool>,
+}
+
 // Discount 관련 요청 DTO
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CreateDiscountRequest {
@@ -66,6 +83,29 @@ pub struct UpdateDiscountRequest {
     pub source_url: Option<String>,
 }
 
+// Coupon 관련 요청 DTO
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UseCouponRequest {
+    pub order_amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ValidateCouponRequest {
+    pub code: String,
+    pub order_amount: f64,
+}
+
+// 크롤링 요청 DTO (POST /api/v1/admin/crawl/shops/:shop_id) - 상점 도메인 주소만 허용
+// urls: 기존 상품 외에 추가로 크롤링할 상품 페이지
+// listing_urls: 상품을 찾을 카테고리 목록 페이지 (없으면 플랫폼 기본 목록 경로)
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CrawlShopRequest {
+    #[serde(default)]
+    pub urls: Vec<String>,
+    #[serde(default)]
+    pub listing_urls: Vec<String>,
+}
+
 // User 관련 요청 DTO
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CreateProfileRequest {
@@ -97,22 +137,137 @@ pub struct CreateSubscriptionRequest {
 pub struct ListQuery {
     pub page: Option<u32>,
     pub limit: Option<u32>,
-    pub sort_by: Option<String>,
-    pub order: Option<String>, // asc, desc
+    pub sort: Option<String>, // "field:direction[,field:direction...]"
     pub search: Option<String>,
+    pub cursor: Option<String>, // 키셋 페이지네이션 (빈 값이면 첫 페이지)
+}
+
+// 알림 목록 조회 쿼리 파라미터 (GET /api/v1/notifications)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NotificationQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    pub sort: Option<String>,
+    pub cursor: Option<String>,
+    pub is_read: Option<bool>, // 없으면 전체
+}
+
+// 알림 설정 변경 (PUT: 빠진 필드는 기본값, PATCH: 빠진 필드는 기존 값 유지)
+// quiet_hours 는 채널 단위로 병합하며 null 이면 해당 채널의 방해 금지 시간 해제
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct NotificationSettingsPatch {
+    pub push_enabled: Option<bool>,
+    pub email_enabled: Option<bool>,
+    pub sms_enabled: Option<bool>,
+    pub discount_updates: Option<bool>,
+    pub subscription_changes: Option<bool>,
+    pub quiet_hours: Option<HashMap<String, Option<QuietHours>>>,
+}
+
+impl NotificationSettingsPatch {
+    pub fn apply_to(self, settings: &mut NotificationSettings) {
+        if let Some(value) = self.push_enabled { settings.push_enabled = value; }
+        if let Some(value) = self.email_enabled { settings.email_enabled = value; }
+        if let Some(value) = self.sms_enabled { settings.sms_enabled = value; }
+        if let Some(value) = self.discount_updates { settings.discount_updates = value; }
+        if let Some(value) = self.subscription_changes { settings.subscription_changes = value; }
+
+        for (channel, window) in self.quiet_hours.unwrap_or_default() {
+            match window {
+                Some(window) => { settings.quiet_hours.insert(channel, window); }
+                None => { settings.quiet_hours.remove(&channel); }
+            }
+        }
+    }
+}
+
+// Web Push 구독 등록 (브라우저 PushSubscription.toJSON() 형식)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PushSubscriptionRequest {
+    pub endpoint: String,
+    pub keys: PushSubscriptionKeys,
+    pub user_agent: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PushSubscriptionKeys {
+    pub p256dh: String,
+    pub auth: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeletePushSubscriptionRequest {
+    pub endpoint: String,
+}
+
+// 할인 목록 조회 쿼리 파라미터 (GET /api/v1/discounts)
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DiscountQuery {
-    pub shop_id: Option<String>,
-    pub brand_id: Option<String>,
-    pub category_id: Option<String>,
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    pub sort: Option<String>,
+    pub cursor: Option<String>,
+    pub shop_id: Option<i64>,
+    pub brand_id: Option<i64>,
+    pub category_id: Option<i64>,
+    pub min_discount_rate: Option<f64>,
+    pub max_discount_rate: Option<f64>,
+    pub currency: Option<String>,
+    pub is_active: Option<bool>,
+    pub start_date: Option<DateTime<Utc>>, // 이 시각 이후까지 진행되는 할인
+    pub end_date: Option<DateTime<Utc>>,   // 이 시각 이전에 시작하는 할인
+    pub preset: Option<String>,            // "ending_soon" | "newly_started"
+    pub embed: Option<String>,             // "product,shop,brand"
+}
+
+// 할인 목록 프리셋
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscountPreset {
+    EndingSoon,    // 진행 중이며 곧 종료되는 할인
+    NewlyStarted,  // 최근 시작된 진행 중 할인
+}
+
+impl DiscountPreset {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "ending_soon" => Some(Self::EndingSoon),
+            "newly_started" => Some(Self::NewlyStarted),
+            _ => None,
+        }
+    }
+
+    // sort 파라미터가 없을 때 사용하는 정렬
+    pub fn default_sort(self) -> &'static str {
+        match self {
+            Self::EndingSoon => "end_at:asc",
+            Self::NewlyStarted => "start_at:desc",
+        }
+    }
+}
+
+// 할인 목록에 함께 임베딩할 관계 (PostgREST resource embedding)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiscountEmbed {
+    pub product: bool,
+    pub shop: bool,
+    pub brand: bool,
+}
+
+// 검증된 할인 목록 필터 (DiscountQuery에서 변환)
+#[derive(Debug, Clone, Default)]
+pub struct DiscountFilter {
+    pub shop_id: Option<i64>,
+    pub brand_id: Option<i64>,
+    pub category_id: Option<i64>,
     pub min_discount_rate: Option<f64>,
     pub max_discount_rate: Option<f64>,
     pub currency: Option<String>,
     pub is_active: Option<bool>,
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
+    pub preset: Option<DiscountPreset>,
+    pub embed: DiscountEmbed,
 }
 
 // 다국어 지원 DTO
@@ -126,4 +281,54 @@ pub struct TranslationRequest {
     pub locale: String,
     pub target_id: String,
     pub content: serde_json::Value, // 번역할 내용
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings_with_quiet_hours() -> NotificationSettings {
+        let mut settings = NotificationSettings::defaults("user-1");
+        settings.quiet_hours.insert("push".to_string(), QuietHours { start: "22:00".to_string(), end: "08:00".to_string() });
+        settings.quiet_hours.insert("email".to_string(), QuietHours { start: "00:00".to_string(), end: "07:00".to_string() });
+        settings
+    }
+
+    #[test]
+    fn partial_patch_keeps_other_fields() {
+        let mut settings = settings_with_quiet_hours();
+        let patch: NotificationSettingsPatch = serde_json::from_str(r#"{"email_enabled": false}"#).unwrap();
+        patch.apply_to(&mut settings);
+
+        assert!(!settings.email_enabled);
+        assert!(settings.push_enabled);
+        assert!(!settings.sms_enabled);
+        assert!(settings.discount_updates);
+        assert!(settings.subscription_changes);
+        assert_eq!(settings.quiet_hours.len(), 2);
+        assert_eq!(settings.quiet_hours["push"].start, "22:00");
+    }
+
+    #[test]
+    fn null_quiet_hours_removes_only_that_channel() {
+        let mut settings = settings_with_quiet_hours();
+        let patch: NotificationSettingsPatch = serde_json::from_str(
+            r#"{"quiet_hours": {"email": null, "sms": {"start": "23:00", "end": "06:30"}}}"#,
+        )
+        .unwrap();
+        patch.apply_to(&mut settings);
+
+        assert!(!settings.quiet_hours.contains_key("email"));
+        assert_eq!(settings.quiet_hours["push"].end, "08:00");
+        assert_eq!(settings.quiet_hours["sms"].start, "23:00");
+        assert_eq!(settings.quiet_hours["sms"].end, "06:30");
+    }
+
+    #[test]
+    fn missing_quiet_hours_leaves_windows_untouched() {
+        let mut settings = settings_with_quiet_hours();
+        NotificationSettingsPatch::default().apply_to(&mut settings);
+
+        assert_eq!(settings.quiet_hours.len(), 2);
+        assert!(settings.push_enabled);
+    }
+}
@@ -1,6 +1,11 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 
+use crate::domain::entities::discount::{pick_current_discount, DiscountDetail, DiscountInfo, DiscountInfoTranslation};
+use crate::domain::entities::product::{ProductDetail, ProductTranslation};
+use crate::domain::entities::shop::{Brand, Category, Shop};
+use crate::utils::constants::DEFAULT_CURRENCY;
+
 // 공통 응답 구조
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ApiResponse<T> {
@@ -31,7 +36,7 @@ pub struct PaginationInfo {
 // Shop 관련 응답 DTO
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ShopResponse {
-    pub id: String,
+    pub id: i64,
     pub name: String,
     pub domain: String,
     pub platform: String,
@@ -51,10 +56,10 @@ pub struct ShopTranslationResponse {
 // Product 관련 응답 DTO  
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ProductResponse {
-    pub id: String,
-    pub shop_id: String,
-    pub brand_id: Option<String>,
-    pub category_id: Option<String>, 
+    pub id: i64,
+    pub shop_id: i64,
+    pub brand_id: Option<i64>,
+    pub category_id: Option<i64>,
     pub name: String,
     pub sku: Option<String>,
     pub is_deleted: bool,
@@ -65,7 +70,7 @@ pub struct ProductResponse {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ProductDetailResponse {
-    pub id: String,
+    pub id: i64,
     pub shop: Option<ShopResponse>,
     pub brand: Option<BrandResponse>,
     pub category: Option<CategoryResponse>,
@@ -76,6 +81,18 @@ pub struct ProductDetailResponse {
     pub updated_at: DateTime<Utc>,
     pub translations: Option<Vec<ProductTranslationResponse>>,
     pub current_discount: Option<DiscountInfoResponse>,
+    pub price_summary: Option<PriceSummary>,
+}
+
+// 가격 요약 (product_price_summary RPC) - 현재가가 없으면 lowest_in_days 는 None
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PriceSummary {
+    pub current_price: Option<f64>,
+    pub all_time_low: Option<f64>,
+    pub low_30d: Option<f64>,
+    pub low_90d: Option<f64>,
+    pub is_all_time_low: bool,
+    pub lowest_in_days: Option<i32>, // "N일 내 최저가"
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -85,10 +102,40 @@ pub struct ProductTranslationResponse {
     pub description: Option<String>,
 }
 
+// 상품 목록 패싯 카운트 - 각 차원은 자기 자신을 제외한 나머지 필터만 적용한 개수
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ProductFacets {
+    pub shops: Vec<FacetCount>,
+    pub brands: Vec<FacetCount>,
+    pub categories: Vec<FacetCount>,
+    pub discount: DiscountFacet,
+    pub discount_rates: Vec<DiscountRateFacet>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FacetCount {
+    pub id: i64,
+    pub name: String,
+    pub count: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DiscountFacet {
+    pub with_discount: u64,
+    pub without_discount: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DiscountRateFacet {
+    pub min: f64,
+    pub max: Option<f64>,
+    pub count: u64,
+}
+
 // Brand 관련 응답 DTO
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BrandResponse {
-    pub id: String,
+    pub id: i64,
     pub name: String,
     pub image_url: Option<String>,
     pub created_at: DateTime<Utc>,
@@ -106,11 +153,10 @@ pub struct BrandTranslationResponse {
 // Category 관련 응답 DTO
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CategoryResponse {
-    pub id: String,
+    pub id: i64,
     pub name: String,
-    pub parent_id: Option<String>,
-    pub level: i32,
-    pub path: String,
+    pub parent_id: Option<i64>,
+    pub icon: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub translations: Option<Vec<CategoryTranslationResponse>>,
@@ -127,10 +173,10 @@ pub struct CategoryTranslationResponse {
 // Discount 관련 응답 DTO
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DiscountInfoResponse {
-    pub id: String,
-    pub product_id: String,
-    pub shop_id: String,
-    pub brand_id: Option<String>,
+    pub id: i64,
+    pub product_id: i64,
+    pub shop_id: Option<i64>,
+    pub brand_id: Option<i64>,
     pub original_price: f64,
     pub discount_price: f64,
     pub discount_rate: f64,
@@ -148,7 +194,7 @@ pub struct DiscountInfoResponse {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DiscountInfoDetailResponse {
-    pub id: String,
+    pub id: i64,
     pub product: Option<ProductResponse>,
     pub shop: Option<ShopResponse>,
     pub brand: Option<BrandResponse>,
@@ -270,4 +316,168 @@ pub struct HealthResponse {
     pub timestamp: String,
     pub version: String,
     pub database: String,
-}
\ No newline at end of file
+}
+
+// 엔티티 -> 상세 응답 변환
+// 번역은 요청 언어로만 조회되므로, 번역이 있으면 이름을 번역된 값으로 대체한다
+impl From<Shop> for ShopResponse {
+    fn from(shop: Shop) -> Self {
+        Self {
+            id: shop.id,
+            name: shop.name,
+            domain: shop.domain,
+            platform: shop.platform,
+            logo_url: shop.logo_url,
+            created_at: shop.created_at,
+            updated_at: shop.updated_at,
+            translations: None, // 매장 번역 테이블 없음
+        }
+    }
+}
+
+impl From<Brand> for BrandResponse {
+    fn from(brand: Brand) -> Self {
+        Self {
+            id: brand.id,
+            name: brand.name,
+            image_url: brand.image_url,
+            created_at: brand.created_at,
+            updated_at: brand.updated_at,
+            translations: None, // 브랜드 번역 테이블 없음
+        }
+    }
+}
+
+impl From<Category> for CategoryResponse {
+    fn from(category: Category) -> Self {
+        Self {
+            id: category.id,
+            name: category.name,
+            parent_id: category.parent_id,
+            icon: category.icon,
+            created_at: category.created_at,
+            updated_at: category.updated_at,
+            translations: None, // 카테고리 번역 테이블 없음
+            children: None,
+        }
+    }
+}
+
+impl From<ProductTranslation> for ProductTranslationResponse {
+    fn from(translation: ProductTranslation) -> Self {
+        Self {
+            locale: translation.locale,
+            name: translation.name,
+            description: translation.description,
+        }
+    }
+}
+
+impl From<DiscountInfoTranslation> for DiscountInfoTranslationResponse {
+    fn from(translation: DiscountInfoTranslation) -> Self {
+        Self {
+            locale: translation.locale,
+            description: translation.description,
+            terms_conditions: translation.terms_conditions,
+        }
+    }
+}
+
+impl From<DiscountInfo> for DiscountInfoResponse {
+    fn from(discount: DiscountInfo) -> Self {
+        Self {
+            id: discount.id,
+            product_id: discount.product_id,
+            shop_id: discount.shop_id,
+            brand_id: discount.brand_id,
+            original_price: discount.original_price,
+            discount_price: discount.discount_price,
+            discount_rate: discount.discount_rate,
+            currency: discount.currency.unwrap_or_else(|| DEFAULT_CURRENCY.to_string()),
+            start_at: discount.start_at,
+            end_at: discount.end_at,
+            is_active: discount.is_active.unwrap_or(true),
+            source_url: discount.source_url.or(discount.info_url),
+            is_auto_discovered: discount.is_auto_discovered.unwrap_or(false),
+            is_event_based: discount.is_event_based.unwrap_or(false),
+            created_at: discount.created_at,
+            updated_at: discount.updated_at,
+            translations: None,
+        }
+    }
+}
+
+impl From<ProductDetail> for ProductResponse {
+    fn from(detail: ProductDetail) -> Self {
+        let product = detail.product;
+        Self {
+            id: product.id,
+            shop_id: product.shop_id,
+            brand_id: product.brand_id,
+            category_id: product.category_id,
+            name: localized_name(product.name, &detail.translations),
+            sku: product.sku,
+            is_deleted: product.is_deleted,
+            created_at: product.created_at,
+            updated_at: product.updated_at,
+            translations: Some(detail.translations.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl ProductDetailResponse {
+    // 진행 중인 할인은 now 기준으로 선택
+    pub fn from_detail(detail: ProductDetail, now: DateTime<Utc>) -> Self {
+        let current_discount = pick_current_discount(&detail.active_discounts, now)
+            .cloned()
+            .map(Into::into);
+        let product = detail.product;
+
+        Self {
+            id: product.id,
+            shop: detail.shop.map(Into::into),
+            brand: detail.brand.map(Into::into),
+            category: detail.category.map(Into::into),
+            name: localized_name(product.name, &detail.translations),
+            sku: product.sku,
+            is_deleted: product.is_deleted,
+            created_at: product.created_at,
+            updated_at: product.updated_at,
+            translations: Some(detail.translations.into_iter().map(Into::into).collect()),
+            current_discount,
+            price_summary: None,
+        }
+    }
+}
+
+impl From<DiscountDetail> for DiscountInfoDetailResponse {
+    fn from(detail: DiscountDetail) -> Self {
+        let discount = DiscountInfoResponse::from(detail.discount);
+        Self {
+            id: discount.id,
+            product: detail.product.map(Into::into),
+            shop: detail.shop.map(Into::into),
+            brand: detail.brand.map(Into::into),
+            original_price: discount.original_price,
+            discount_price: discount.discount_price,
+            discount_rate: discount.discount_rate,
+            currency: discount.currency,
+            start_at: discount.start_at,
+            end_at: discount.end_at,
+            is_active: discount.is_active,
+            source_url: discount.source_url,
+            is_auto_discovered: discount.is_auto_discovered,
+            is_event_based: discount.is_event_based,
+            created_at: discount.created_at,
+            updated_at: discount.updated_at,
+            translations: Some(detail.translations.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+fn localized_name(name: String, translations: &[ProductTranslation]) -> String {
+    translations
+        .first()
+        .map(|translation| translation.name.clone())
+        .unwrap_or(name)
+}
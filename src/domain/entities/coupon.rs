@@ -14,6 +14,8 @@ pub struct Coupon {
     pub max_discount_amount: Option<f64>,
     pub usage_limit: Option<i32>,
     pub used_count: i32,
+    #[serde(default)]
+    pub per_user_limit: Option<i32>,  // 사용자당 사용 가능 횟수 (None이면 무제한)
     pub start_date: DateTime<Utc>,
     pub end_date: DateTime<Utc>,
     pub is_active: bool,
@@ -37,4 +39,11 @@ pub struct CouponValidationResult {
     pub is_valid: bool,
     pub discount_amount: f64,
     pub error_message: Option<String>,
+}
+
+// redeem_coupon RPC 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CouponRedemptionResult {
+    pub status: String,  // redeemed, not_found, unavailable, usage_limit_reached, user_limit_reached
+    pub usage: Option<CouponUsage>,
 }
\ No newline at end of file
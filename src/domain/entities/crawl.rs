@@ -0,0 +1,88 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+// upsert_crawled_product RPC 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrawledProductResult {
+    pub product_id: i64,
+    pub product_created: bool,
+    pub discount_id: Option<i64>,
+    pub discount_change: String, // created, updated, extended, ended, none
+}
+
+// 크롤링 중 실패한 페이지
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrawlPageError {
+    pub url: String,
+    pub error: String,
+}
+
+// 상점 한 번 크롤링한 결과
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CrawlSummary {
+    pub shop_id: i64,
+    pub pages_fetched: u32,
+    pub pages_not_modified: u32, // 304 - 저장된 추출 결과 사용
+    pub pages_skipped: u32,      // robots.txt 가 막은 페이지
+    pub products_discovered: u32, // 목록 페이지에서 찾은 상품 주소
+    pub products_upserted: u32,
+    pub products_created: u32,
+    pub discounts_changed: u32,
+    pub errors: Vec<CrawlPageError>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CrawlTrigger {
+    Manual,    // 관리자 API
+    Scheduled, // CRAWLER_SCHEDULE_MINUTES 주기 실행
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CrawlRunStatus {
+    Running,
+    Completed,
+    Failed,
+}
+
+// crawl_runs - 크롤링 한 번의 실행 기록
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrawlRun {
+    pub id: i64,
+    pub shop_id: i64,
+    pub trigger: CrawlTrigger,
+    pub status: CrawlRunStatus,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub pages_fetched: i32,
+    pub pages_not_modified: i32,
+    pub pages_skipped: i32,
+    pub products_discovered: i32,
+    pub products_upserted: i32,
+    pub products_created: i32,
+    pub discounts_changed: i32,
+    pub error_count: i32,
+    pub errors: Vec<CrawlPageError>, // 앞의 CRAWLER_RUN_ERROR_LIMIT 개
+    pub failure: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CrawlPageType {
+    Listing,
+    Product,
+}
+
+// crawl_pages - 페이지별 마지막 검증자(ETag/Last-Modified)와 추출 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrawlPage {
+    pub shop_id: i64,
+    pub url: String,
+    pub page_type: CrawlPageType,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub extracted: Option<serde_json::Value>, // product: ScrapedProduct, listing: ListingPage
+    pub last_status: Option<i32>,
+    pub last_crawled_at: DateTime<Utc>,
+}
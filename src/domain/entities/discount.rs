@@ -1,22 +1,89 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 
+use crate::domain::dto::pagenation::KeysetItem;
+use crate::domain::entities::product::{Product, ProductDetail};
+use crate::domain::entities::shop::{Brand, Shop};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiscountInfo {
     pub id: i64,  // 실제 DB 스키마에 맞춤
     pub product_id: i64,
+    pub shop_id: Option<i64>,
+    pub brand_id: Option<i64>,
     pub original_price: f64,
     pub discount_price: f64,
     pub discount_rate: f64,
+    pub currency: Option<String>,
     pub start_at: DateTime<Utc>,
     pub end_at: DateTime<Utc>,
+    pub is_active: Option<bool>,
     pub info_url: Option<String>, // 실제 DB 필드명
+    pub source_url: Option<String>, // 마이그레이션 스키마 필드명
+    pub is_auto_discovered: Option<bool>,
+    pub is_event_based: Option<bool>,
     pub thumbnail_url: Option<String>,
     pub click_count: Option<i32>, // 실제 DB에 존재
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+impl KeysetItem for DiscountInfo {
+    fn keyset_key(&self) -> (DateTime<Utc>, i64) {
+        (self.created_at, self.id)
+    }
+}
+
+// 할인 목록 항목 - embed 파라미터로 요청한 관계만 채워짐 (PostgREST resource embedding)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscountListItem {
+    #[serde(flatten)]
+    pub discount: DiscountInfo,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<Product>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shop: Option<Shop>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand: Option<Brand>,
+}
+
+impl KeysetItem for DiscountListItem {
+    fn keyset_key(&self) -> (DateTime<Utc>, i64) {
+        self.discount.keyset_key()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscountInfoTranslation {
+    pub locale: String,
+    pub description: Option<String>,
+    pub terms_conditions: Option<String>,
+}
+
+// 할인 상세 - 상품(번역 포함)/매장/브랜드/요청 언어 번역을 한 번의 임베딩 쿼리로 조회
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscountDetail {
+    #[serde(flatten)]
+    pub discount: DiscountInfo,
+    pub product: Option<ProductDetail>,
+    pub shop: Option<Shop>,
+    pub brand: Option<Brand>,
+    #[serde(default)]
+    pub translations: Vec<DiscountInfoTranslation>,
+}
+
+// 진행 중인 할인 선택 - 활성 + 기간 내 할인 중 할인율이 가장 높은 것 (동률이면 최근 시작)
+pub fn pick_current_discount(discounts: &[DiscountInfo], now: DateTime<Utc>) -> Option<&DiscountInfo> {
+    discounts
+        .iter()
+        .filter(|d| d.is_active != Some(false) && d.start_at <= now && now <= d.end_at)
+        .max_by(|a, b| {
+            a.discount_rate
+                .total_cmp(&b.discount_rate)
+                .then(a.start_at.cmp(&b.start_at))
+        })
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiscountEvent {
     pub id: i64,  // BIGSERIAL
@@ -5,6 +5,7 @@ pub mod user;
 pub mod notification;
 pub mod monitoring;
 pub mod coupon;
+pub mod crawl;
 
 pub use shop::*;
 pub use product::*;
@@ -12,4 +13,5 @@ pub use discount::*;
 pub use user::*;
 pub use notification::*;
 pub use monitoring::*;
-pub use coupon::*;
\ No newline at end of file
+pub use coupon::*;
+pub use crawl::*;
\ No newline at end of file
@@ -1,42 +1,152 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use serde_json::Value;
 
-// 알림 관련 엔티티들
+use crate::domain::dto::pagenation::KeysetItem;
+
+// 알림 관련 엔티티들 (notifications 테이블 - 컬럼명 type/message)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Notification {
     pub id: i64,
     pub user_id: String,
     pub title: String,
+    #[serde(rename = "message")]
     pub content: String,
+    #[serde(rename = "type")]
     pub notification_type: String,
     pub is_read: bool,
     pub data: Option<Value>,
     pub created_at: DateTime<Utc>,
 }
 
+impl KeysetItem for Notification {
+    fn keyset_key(&self) -> (DateTime<Utc>, i64) {
+        (self.created_at, self.id)
+    }
+}
+
+// 사용자별 알림 설정 (notification_settings 테이블 - 사용자당 한 행, 행이 없으면 기본값)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NotificationSettings {
     pub user_id: String,
     pub push_enabled: bool,
     pub email_enabled: bool,
     pub sms_enabled: bool,
-    pub discount_alerts: bool,
-    pub price_drop_alerts: bool,
-    pub new_product_alerts: bool,
-    pub created_at: DateTime<Utc>,
-    pub updated_at: DateTime<Utc>,
+    pub discount_updates: bool,     // 할인/가격 인하 알림
+    pub subscription_changes: bool, // 브랜드/매장/카테고리 구독 알림
+    #[serde(default)]
+    pub quiet_hours: HashMap<String, QuietHours>, // 채널(push/email/sms)별 방해 금지 시간
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl NotificationSettings {
+    pub fn defaults(user_id: &str) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            push_enabled: true,
+            email_enabled: true,
+            sms_enabled: false,
+            discount_updates: true,
+            subscription_changes: true,
+            quiet_hours: HashMap::new(),
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+// 방해 금지 시간 (profiles.timezone 기준 "HH:MM", start > end 면 자정을 넘기는 구간)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuietHours {
+    pub start: String,
+    pub end: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NotificationLog {
     pub id: i64,
+    pub queue_id: Option<i64>,
     pub user_id: String,
     pub notification_type: String,
+    pub channel: String,
     pub title: String,
     pub content: String,
     pub status: String, // sent, failed, pending
+    pub attempt: i32,
     pub error_message: Option<String>,
     pub sent_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
-}
\ No newline at end of file
+}
+
+// 발송 큐에서 클레임한 항목 (notification_queue + notifications 조인, attempts는 이번 시도 포함)
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct QueuedNotification {
+    pub id: i64,
+    pub notification_id: i64,
+    pub user_id: String,
+    pub channel: String, // push, email, sms
+    pub attempts: i32,
+    pub max_attempts: i32,
+    pub notification_type: String,
+    pub title: String,
+    pub content: String,
+    pub data: Option<Value>,
+    pub email: Option<String>,
+    #[sqlx(json)]
+    pub push_subscriptions: Vec<PushSubscription>,
+}
+
+// 브라우저 Web Push 구독 (push_subscriptions 테이블, PushSubscription.toJSON()의 endpoint/keys)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PushSubscription {
+    pub endpoint: String,
+    pub p256dh: String,
+    pub auth: String,
+}
+
+// 가격 인하 알림 대상 (claim_price_drop_alerts RPC 결과 - 사용자/할인당 한 건)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceDropAlert {
+    pub user_id: String,
+    pub discount_info_id: i64,
+    pub product_id: i64,
+    pub product_name: String,
+    pub matched_by: String,  // product, brand, shop, category
+    pub change_type: String, // created, price_drop, reactivated
+    pub original_price: f64,
+    pub discount_price: f64,
+    pub previous_price: Option<f64>,
+    pub discount_rate: f64,
+    pub currency: Option<String>,
+    pub end_at: DateTime<Utc>,
+    #[serde(default)]
+    pub attempts: i32, // 이번 클레임을 포함한 시도 횟수
+}
+
+// 큐 항목 발송 결과 - notification_logs.status 는 sent / pending(재시도 예정) / failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeliveryOutcome {
+    Sent,
+    Retry { delay_secs: u64, error: String },
+    Failed { error: String },
+}
+
+impl DeliveryOutcome {
+    pub fn log_status(&self) -> &'static str {
+        match self {
+            Self::Sent => "sent",
+            Self::Retry { .. } => "pending",
+            Self::Failed { .. } => "failed",
+        }
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        match self {
+            Self::Sent => None,
+            Self::Retry { error, .. } | Self::Failed { error } => Some(error),
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 
+use crate::domain::entities::discount::DiscountInfo;
+use crate::domain::entities::shop::{Brand, Category, Shop};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Product {
     pub id: i64,  // 실제 DB 스키마에 맞춤
@@ -13,4 +16,54 @@ pub struct Product {
     pub is_deleted: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
-}
\ No newline at end of file
+}
+
+// 상품 검색 결과 - search_products RPC가 계산한 관련도 점수 포함
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductSearchHit {
+    #[serde(flatten)]
+    pub product: Product,
+    pub relevance: f64,
+}
+
+// 상품 목록 항목 - 진행 중인 할인 중 최대 할인율 포함 (filter_products RPC)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductListItem {
+    #[serde(flatten)]
+    pub product: Product,
+    pub current_discount_rate: Option<f64>,
+}
+
+// 가격 이력 - 할인가/정가가 바뀔 때마다 DB 트리거가 기록 (price_history)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceHistory {
+    pub id: i64,
+    pub product_id: i64,
+    pub discount_info_id: Option<i64>,
+    pub original_price: f64,
+    pub price: f64,
+    pub currency: Option<String>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductTranslation {
+    pub locale: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+// 상품 상세 - 매장/브랜드/카테고리/요청 언어 번역/진행 중 할인을 한 번의 임베딩 쿼리로 조회
+// (할인 상세의 product 임베딩에도 사용되며, 이때는 번역만 채워짐)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductDetail {
+    #[serde(flatten)]
+    pub product: Product,
+    pub shop: Option<Shop>,
+    pub brand: Option<Brand>,
+    pub category: Option<Category>,
+    #[serde(default)]
+    pub translations: Vec<ProductTranslation>,
+    #[serde(default)]
+    pub active_discounts: Vec<DiscountInfo>,
+}
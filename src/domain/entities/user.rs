@@ -15,6 +15,13 @@ pub struct Profile {
     pub updated_at: DateTime<Utc>,
 }
 
+// 사용자 권한 (user_roles 테이블)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserRole {
+    pub user_id: String,
+    pub role: String,
+}
+
 // 구독 관련 엔티티들
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProductSubscription {
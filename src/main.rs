@@ -7,14 +7,25 @@ mod api;
 mod utils;
 mod error;
 mod auth;
+mod channels;
+mod realtime;
+mod crawler;
 
 use axum::{
-    extract::{Path, Query, State},
-    response::Json,
-    routing::{delete, get, post, put},
+    body::Bytes,
+    extract::{ws::WebSocketUpgrade, Path, Query, State},
+    http::{header::ACCEPT_LANGUAGE, HeaderMap, StatusCode},
+    middleware,
+    response::{
+        sse::{Event, Sse},
+        Json, Response,
+    },
+    routing::{delete, get, patch, post, put},
     Router,
 };
+use futures_util::Stream;
 use serde_json::json;
+use std::convert::Infallible;
 use std::sync::Arc;
 use tower::ServiceBuilder;
 use tower_http::{
@@ -22,10 +33,21 @@ use tower_http::{
     trace::TraceLayer,
 };
 
-use crate::config::SupabaseConfig;
-use crate::service::{DiscountService, ShopService, ProductService, UserService, NotificationService, MonitoringService};
-use crate::domain::dto::{HealthResponse, pagenation::Pagenation};
-use crate::utils::init_logger;
+use crate::api::middleware::{bearer_token, require_admin, require_auth};
+use crate::auth::{extract_user_from_token, AuthUser, JwtVerifier, RoleLookup, SupabaseRoleLookup};
+use crate::channels::NotificationChannels;
+use crate::domain::entities::CrawlTrigger;
+use crate::domain::entities::notification::PushSubscription;
+use crate::config::{CrawlerConfig, NotificationChannelConfig, RealtimeConfig, SupabaseConfig};
+use crate::realtime::{realtime_sse_stream, serve_realtime_socket, sse_keep_alive, RealtimeBus, RealtimeEvent, SupabaseRealtimeClient};
+use crate::service::{DiscountService, ShopService, ProductService, UserService, NotificationService, MonitoringService, CouponService, PriceAlertService, NotificationQueueService, CrawlerService};
+use crate::domain::dto::{CrawlShopRequest, DeletePushSubscriptionRequest, DiscountEmbed, DiscountFilter, DiscountQuery, HealthResponse, ListQuery, NotificationQuery, NotificationSettingsPatch, ProductFilter, PushSubscriptionRequest, UseCouponRequest, ValidateCouponRequest, pagenation::{CursorPagenation, Pagenation}};
+use crate::utils::{
+    init_logger, make_request_span, resolve_locale, validate_country, validate_discount_rate_range, validate_pagination,
+    validate_cursor, validate_currency, validate_date_range, validate_discount_embed, validate_discount_preset,
+    validate_push_subscription, validate_quiet_hours, validate_search_query, validate_sort, BRAND_SORT_FIELDS, DEFAULT_PAGE_SIZE, DISCOUNT_SORT_FIELDS, MAX_PRICE_HISTORY_DAYS,
+    NOTIFICATION_SORT_FIELDS, PRODUCT_SORT_FIELDS, SHOP_SORT_FIELDS,
+};
 use crate::error::{AppError, AppResult};
 use serde::Deserialize;
 
@@ -35,6 +57,59 @@ pub struct ProductQuery {
     pub page: Option<u32>,
     pub limit: Option<u32>,
     pub country: Option<String>,
+    pub shop_id: Option<i64>,
+    pub brand_id: Option<i64>,
+    pub category_id: Option<i64>,
+    pub include_subcategories: Option<bool>,
+    pub min_discount_rate: Option<f64>,
+    pub max_discount_rate: Option<f64>,
+    pub has_discount: Option<bool>,
+    pub sort: Option<String>,
+}
+
+// 상품 검색을 위한 쿼리 파라미터
+#[derive(Debug, Deserialize)]
+pub struct SearchQuery {
+    pub q: Option<String>,
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    pub locale: Option<String>,
+}
+
+// 가격 이력 조회를 위한 쿼리 파라미터
+#[derive(Debug, Deserialize)]
+pub struct PriceHistoryQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    pub days: Option<u32>, // 최근 N일 기록만
+}
+
+// 상세 조회 언어 지정 (없으면 Accept-Language 사용)
+#[derive(Debug, Deserialize)]
+pub struct LocaleQuery {
+    pub locale: Option<String>,
+}
+
+// 쿠폰 목록 조회를 위한 쿼리 파라미터
+#[derive(Debug, Deserialize)]
+pub struct CouponQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    pub shop_id: Option<i64>,
+}
+
+// 실시간 WebSocket/SSE 연결 파라미터 (브라우저는 헤더를 지정할 수 없어 토큰을 쿼리로도 받음)
+#[derive(Debug, Deserialize)]
+pub struct RealtimeQuery {
+    pub access_token: Option<String>,
+    pub last_event_id: Option<u64>, // SSE 첫 연결용 (재연결 시에는 Last-Event-ID 헤더)
+}
+
+// 할인 SSE 스트림 파라미터
+#[derive(Debug, Deserialize)]
+pub struct DiscountStreamQuery {
+    pub product_id: Option<i64>,
+    pub last_event_id: Option<u64>,
 }
 
 // 애플리케이션 상태 - Phase 1-4: 완전한 서비스 레이어
@@ -46,6 +121,11 @@ pub struct AppState {
     pub user_service: UserService,
     pub notification_service: NotificationService,
     pub monitoring_service: MonitoringService,
+    pub coupon_service: CouponService,
+    pub crawler_service: Option<CrawlerService>, // 크롤러 설정이 잘못되면 None (크롤링 API/예약 크롤링 없이 시작)
+    pub realtime_bus: RealtimeBus,
+    pub jwt_verifier: JwtVerifier,
+    pub role_lookup: Arc<dyn RoleLookup>,
 }
 
 #[tokio::main]
@@ -61,18 +141,102 @@ async fn main() {
     let config = SupabaseConfig::new().expect("Failed to load Supabase config");
     tracing::info!("⚙️ Configuration loaded");
     
+    // JWT 검증기 초기화 (비대칭 키 사용 프로젝트를 위해 JWKS 로드)
+    let jwt_verifier = JwtVerifier::new(&config);
+    match jwt_verifier.refresh_jwks().await {
+        Ok(count) => tracing::info!("🔑 Loaded {} JWKS signing keys", count),
+        Err(e) => tracing::warn!("🔑 JWKS not loaded, only HS256 tokens will be accepted: {}", e),
+    }
+    
+    // 실시간 이벤트 버스 - Supabase Realtime 을 쓰면 Realtime 수신기가, 아니면 알림 서비스가 직접 발행
+    let realtime_bus = RealtimeBus::new();
+    let realtime_config = RealtimeConfig::from_env(&config);
+    let notification_service = match realtime_config {
+        Some(_) => NotificationService::new(config.clone()),
+        None => NotificationService::new(config.clone()).with_event_bus(realtime_bus.clone()),
+    };
+    
+    // 크롤러 설정/초기화에 실패해도 나머지 API 는 제공
+    let crawler = CrawlerConfig::from_env().and_then(|crawler_config| {
+        CrawlerService::from_config(config.clone(), &crawler_config)
+            .map(|service| match realtime_config {
+                Some(_) => service,
+                None => service.with_event_bus(realtime_bus.clone()),
+            })
+            .map(|service| (service, crawler_config))
+            .map_err(|e| e.to_string())
+    });
+    let crawler = match crawler {
+        Ok(crawler) => Some(crawler),
+        Err(e) => {
+            tracing::error!("🕷️ Crawler disabled, crawl routes and scheduler not started: {}", e);
+            None
+        }
+    };
+
     // 서비스 초기화 - Phase 1-4: 완전한 서비스 레이어
     let app_state = AppState {
         discount_service: DiscountService::new(config.clone()),
         shop_service: ShopService::new(config.clone()),
         product_service: ProductService::new(config.clone()),
         user_service: UserService::new(config.clone()),
-        notification_service: NotificationService::new(config.clone()),
-        monitoring_service: MonitoringService::new(config),
+        notification_service,
+        monitoring_service: MonitoringService::new(config.clone()),
+        coupon_service: CouponService::new(config.clone()),
+        crawler_service: crawler.as_ref().map(|(service, _)| service.clone()),
+        realtime_bus: realtime_bus.clone(),
+        jwt_verifier,
+        role_lookup: Arc::new(SupabaseRoleLookup::new(config.clone())),
     };
     
     tracing::info!("🔧 Services initialized");
     
+    // Supabase Realtime 수신기 (notifications / discount_infos 변경 → /api/v1/ws)
+    match realtime_config {
+        Some(realtime_config) => {
+            SupabaseRealtimeClient::new(realtime_config, realtime_bus).spawn();
+            tracing::info!("📡 Supabase Realtime subscriber started");
+        }
+        None => tracing::info!("📡 Supabase Realtime disabled, only notifications and crawled discounts from this server are streamed"),
+    }
+    
+    // 가격 인하 알림 매처 (discount_infos 변경 이벤트 폴링)
+    PriceAlertService::new(config.clone(), app_state.notification_service.clone()).spawn_matcher();
+    tracing::info!("🔔 Price drop matcher started");
+
+    // 예약 크롤링 (CRAWLER_SCHEDULE_MINUTES)
+    if let Some((crawler_service, crawler_config)) = crawler {
+        match crawler_service.fail_interrupted_runs().await {
+            Ok(0) => {}
+            Ok(count) => tracing::warn!("🕷️ Marked {} crawl runs interrupted by the last shutdown as failed", count),
+            Err(e) => tracing::error!("🕷️ {}", e),
+        }
+        match crawler_config.schedule_interval {
+            Some(interval) => {
+                crawler_service.spawn_scheduler(interval);
+                tracing::info!("🕷️ Crawl scheduler started (every {} minutes)", interval.as_secs() / 60);
+            }
+            None => tracing::info!("🕷️ CRAWLER_SCHEDULE_MINUTES not set, shops are crawled only on demand"),
+        }
+    }
+
+    // 알림 발송 큐 워커 (DATABASE_URL 직접 연결 필요)
+    match config.database_pool() {
+        Ok(Some(pool)) => {
+            let channels = match NotificationChannelConfig::from_env() {
+                Ok(channel_config) => NotificationChannels::from_config(&channel_config),
+                Err(e) => {
+                    tracing::warn!("📨 Invalid notification channel config, no channels enabled: {}", e);
+                    NotificationChannels::new()
+                }
+            };
+            NotificationQueueService::new(pool, channels).spawn_worker();
+            tracing::info!("📨 Notification queue worker started");
+        }
+        Ok(None) => tracing::warn!("📨 DATABASE_URL not set, notification queue worker disabled"),
+        Err(e) => tracing::warn!("📨 Invalid DATABASE_URL, notification queue worker disabled: {}", e),
+    }
+    
     // 라우터 구성
     let app = create_router(Arc::new(app_state));
     
@@ -84,6 +248,52 @@ async fn main() {
 }
 
 fn create_router(state: Arc<AppState>) -> Router {
+    // 🔐 인증 필요 API - 사용자는 URL이 아닌 access token에서 결정
+    let user_routes = Router::new()
+        // 👥 Phase 2: 사용자 프로필 API
+        .route("/api/v1/profiles/me", get(get_my_profile))
+        .route("/api/v1/profiles/me", put(update_my_profile))
+        
+        // 📋 Phase 2: 구독 관리 API  
+        .route("/api/v1/subscriptions/my", get(get_my_subscriptions))
+        .route("/api/v1/subscriptions/products/:product_id", post(add_product_subscription))
+        .route("/api/v1/subscriptions/products/:product_id", delete(remove_product_subscription))
+        .route("/api/v1/subscriptions/brands/:brand_id", post(add_brand_subscription))
+        .route("/api/v1/subscriptions/brands/:brand_id", delete(remove_brand_subscription))
+        .route("/api/v1/subscriptions/shops/:shop_id", post(add_shop_subscription))
+        .route("/api/v1/subscriptions/shops/:shop_id", delete(remove_shop_subscription))
+        
+        // 🔔 Phase 3: 알림 시스템 API
+        .route("/api/v1/notifications", get(get_notifications))
+        .route("/api/v1/notifications/unread-count", get(get_unread_notification_count))
+        .route("/api/v1/notifications/read-all", post(mark_all_notifications_read))
+        .route("/api/v1/notifications/:id", delete(delete_notification))
+        .route("/api/v1/notifications/:id/read", post(mark_notification_read))
+        .route("/api/v1/notifications/settings", get(get_notification_settings))
+        .route("/api/v1/notifications/settings", put(update_notification_settings))
+        .route("/api/v1/notifications/settings", patch(patch_notification_settings))
+        .route("/api/v1/notifications/push-subscriptions", post(register_push_subscription))
+        .route("/api/v1/notifications/push-subscriptions", delete(remove_push_subscription))
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_auth));
+
+    // 🛡️ 관리자 전용 API
+    let admin_routes = Router::new()
+        // 📈 Phase 4: 모니터링 API (관리자)
+        .route("/api/v1/admin/metrics/api", get(get_api_metrics))
+        .route("/api/v1/admin/logs/errors", get(get_error_logs))
+        .route("/api/v1/admin/cache/stats", get(get_cache_stats))
+        .route("/api/v1/admin/system/health", get(get_system_health));
+
+    // 🕷️ 크롤링 API (관리자, 크롤러가 초기화된 경우만)
+    let admin_routes = match state.crawler_service {
+        Some(_) => admin_routes
+            .route("/api/v1/admin/crawl/shops/:shop_id", post(crawl_shop))
+            .route("/api/v1/admin/crawl/shops/:shop_id/runs", get(get_crawl_runs))
+            .route("/api/v1/admin/crawl/runs/:run_id", get(get_crawl_run)),
+        None => admin_routes,
+    }
+    .route_layer(middleware::from_fn_with_state(state.clone(), require_admin));
+
     Router::new()
         // Health check
         .route("/", get(health_check))
@@ -94,14 +304,19 @@ fn create_router(state: Arc<AppState>) -> Router {
         .route("/api/v1/products/popular", get(get_popular_products)) // 인기 상품 목록
         .route("/api/v1/products/:id", get(get_product_by_id))   // 상품 상세
         .route("/api/v1/products/:id/click", post(record_product_click)) // 클릭 기록
+        .route("/api/v1/products/:id/price-history", get(get_product_price_history)) // 가격 이력
         .route("/api/v1/products/search", get(search_products)) // 상품 검색
         
         // 💰 Phase 1: 할인 정보 API (기본)  
+        .route("/api/v1/discounts", get(get_discounts))
+        .route("/api/v1/discounts/stream", get(discount_stream))
         .route("/api/v1/discounts/:id", get(get_discount_by_id))
         
         // 💰 Phase 3: 쿠폰 시스템 API
         .route("/api/v1/coupons", get(get_coupons))
         .route("/api/v1/coupons/:id", get(get_coupon_by_id))
+        .route("/api/v1/coupons/code/:code", get(get_coupon_by_code))
+        .route("/api/v1/coupons/validate", post(validate_coupon))
         .route("/api/v1/coupons/:id/use", post(use_coupon))
         
         // 🏪 Phase 1: 매장 정보 API (기본)
@@ -114,34 +329,15 @@ fn create_router(state: Arc<AppState>) -> Router {
         .route("/api/v1/categories", get(get_categories))
         .route("/api/v1/categories/:id", get(get_category_by_id))
         
-        // 👥 Phase 2: 사용자 프로필 API
-        .route("/api/v1/profiles/:user_id", get(get_user_profile))
-        .route("/api/v1/profiles/:user_id", post(update_user_profile))
-        
-        // 📋 Phase 2: 구독 관리 API  
-        .route("/api/v1/subscriptions/my/:user_id", get(get_my_subscriptions))
-        .route("/api/v1/subscriptions/products/:user_id/:product_id", post(add_product_subscription))
-        .route("/api/v1/subscriptions/products/:user_id/:product_id", delete(remove_product_subscription))
-        .route("/api/v1/subscriptions/brands/:user_id/:brand_id", post(add_brand_subscription))
-        .route("/api/v1/subscriptions/brands/:user_id/:brand_id", delete(remove_brand_subscription))
-        .route("/api/v1/subscriptions/shops/:user_id/:shop_id", post(add_shop_subscription))
-        .route("/api/v1/subscriptions/shops/:user_id/:shop_id", delete(remove_shop_subscription))
-        
-        // 🔔 Phase 3: 알림 시스템 API
-        .route("/api/v1/notifications/:user_id", get(get_notifications))
-        .route("/api/v1/notifications/:id/read", post(mark_notification_read))
-        .route("/api/v1/notifications/settings/:user_id", get(get_notification_settings))
-        .route("/api/v1/notifications/settings/:user_id", put(update_notification_settings))
-        
-        // 📈 Phase 4: 모니터링 API (관리자)
-        .route("/api/v1/admin/metrics/api", get(get_api_metrics))
-        .route("/api/v1/admin/logs/errors", get(get_error_logs))
-        .route("/api/v1/admin/cache/stats", get(get_cache_stats))
-        .route("/api/v1/admin/system/health", get(get_system_health))
+        // 🔴 실시간 알림/할인 WebSocket, 알림 SSE (토큰은 핸들러에서 검증)
+        .route("/api/v1/ws", get(realtime_websocket))
+        .route("/api/v1/notifications/stream", get(notification_stream))
         
+        .merge(user_routes)
+        .merge(admin_routes)
         .layer(
             ServiceBuilder::new()
-                .layer(TraceLayer::new_for_http())
+                .layer(TraceLayer::new_for_http().make_span_with(make_request_span::<axum::body::Body>))
                 .layer(CorsLayer::permissive())
         )
         .with_state(state)
@@ -161,7 +357,7 @@ async fn health_check() -> Json<HealthResponse> {
 
 // 📦 Phase 1: 상품 핸들러들
 
-// 상품 목록 조회 (나라별 필터링 또는 전체)
+// 상품 목록 조회 (배송 국가 + 패싯 필터, 필터가 없으면 전체)
 async fn get_products(
     Query(query): Query<ProductQuery>,
     State(state): State<Arc<AppState>>,
@@ -175,22 +371,35 @@ async fn get_products(
 
     let pagination = Pagenation { page, limit };
 
-    let result = if let Some(country) = query.country {
-        log::info!("🌍 Getting products for country: {}", country);
-        state.product_service
-            .get_products_by_country(&country, pagination)
-            .await
-            .map_err(|e| AppError::internal(format!("Failed to get products by country: {}", e)))?
-    } else {
-        log::info!("📦 Getting all products");
-        state.product_service
-            .get_all_products(pagination)
-            .await
-            .map_err(|e| AppError::internal(format!("Failed to get products: {}", e)))?
+    validate_discount_rate_range(query.min_discount_rate, query.max_discount_rate)?;
+    let sort = validate_sort(query.sort.as_deref(), PRODUCT_SORT_FIELDS)?;
+
+    // 배송 국가 필터 (shipping_regions 기준)
+    let country = query.country
+        .as_deref()
+        .filter(|c| !c.is_empty())
+        .map(validate_country)
+        .transpose()?;
+
+    let filter = ProductFilter {
+        country,
+        shop_id: query.shop_id,
+        brand_id: query.brand_id,
+        category_id: query.category_id,
+        include_subcategories: query.include_subcategories.unwrap_or(false),
+        min_discount_rate: query.min_discount_rate,
+        max_discount_rate: query.max_discount_rate,
+        has_discount: query.has_discount,
     };
 
+    let (result, facets) = state.product_service
+        .get_filtered_products(&filter, &sort, pagination)
+        .await
+        .map_err(|e| AppError::internal(format!("Failed to get products: {}", e)))?;
+
     Ok(Json(json!({ 
         "products": result.data,
+        "facets": facets,
         "pagination": {
             "page": result.page,
             "limit": result.limit,
@@ -237,21 +446,64 @@ async fn get_popular_products(
 
 async fn get_product_by_id(
     Path(product_id): Path<i64>,
+    Query(query): Query<LocaleQuery>,
+    headers: HeaderMap,
     State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
-    log::info!("📦 Getting product by ID: {}", product_id);
+    let accept_language = headers.get(ACCEPT_LANGUAGE).and_then(|v| v.to_str().ok());
+    let locale = resolve_locale(query.locale.as_deref(), accept_language)?;
+
+    log::info!("📦 Getting product by ID: {} (locale: {})", product_id, locale);
     
     let product = state.product_service
-        .get_product_by_id(product_id)
+        .get_product_detail(product_id, &locale)
         .await
         .map_err(|e| AppError::internal(format!("Failed to get product: {}", e)))?;
     
     match product {
-        Some(product) => Ok(Json(json!({ "product": product }))),
+        Some(product) => Ok(Json(json!({ "product": product, "locale": locale }))),
         None => Err(AppError::not_found("Product")),
     }
 }
 
+// 상품 가격 이력 + 최저가 요약
+async fn get_product_price_history(
+    Path(product_id): Path<i64>,
+    Query(query): Query<PriceHistoryQuery>,
+    State(state): State<Arc<AppState>>,
+) -> AppResult<Json<serde_json::Value>> {
+    let (page, limit) = validate_pagination(query.page.unwrap_or(1), query.limit.unwrap_or(DEFAULT_PAGE_SIZE))?;
+    if let Some(days) = query.days
+        && (days == 0 || days > MAX_PRICE_HISTORY_DAYS)
+    {
+        return Err(AppError::validation(format!("days must be between 1 and {}", MAX_PRICE_HISTORY_DAYS)));
+    }
+
+    log::info!("📈 Getting price history for product: {}", product_id);
+    let result = state.product_service
+        .get_price_history(product_id, query.days, Pagenation { page, limit })
+        .await
+        .map_err(|e| AppError::internal(format!("Failed to get price history: {}", e)))?;
+
+    let Some((history, summary)) = result else {
+        return Err(AppError::not_found("Product"));
+    };
+
+    Ok(Json(json!({
+        "product_id": product_id,
+        "price_history": history.data,
+        "summary": summary,
+        "pagination": {
+            "page": history.page,
+            "limit": history.limit,
+            "total": history.total,
+            "total_pages": history.total_pages,
+            "has_next": history.has_next,
+            "has_prev": history.has_prev
+        }
+    })))
+}
+
 async fn record_product_click(
     Path(product_id): Path<i64>,
     State(state): State<Arc<AppState>>,
@@ -271,19 +523,109 @@ async fn record_product_click(
 }
 
 // 💰 Phase 1: 할인 핸들러들
+
+// 할인 목록 조회 - 필터, 프리셋(ending_soon/newly_started), 관계 임베딩 지원
+async fn get_discounts(
+    Query(query): Query<DiscountQuery>,
+    State(state): State<Arc<AppState>>,
+) -> AppResult<Json<serde_json::Value>> {
+    let (page, limit) = validate_pagination(query.page.unwrap_or(1), query.limit.unwrap_or(DEFAULT_PAGE_SIZE))?;
+    validate_discount_rate_range(query.min_discount_rate, query.max_discount_rate)?;
+    validate_date_range(query.start_date, query.end_date)?;
+
+    let preset = match query.preset.as_deref().filter(|p| !p.is_empty()) {
+        Some(preset) => Some(validate_discount_preset(preset)?),
+        None => None,
+    };
+    let currency = match query.currency.as_deref().filter(|c| !c.is_empty()) {
+        Some(currency) => Some(validate_currency(currency)?),
+        None => None,
+    };
+    let embed = match query.embed.as_deref() {
+        Some(embed) => validate_discount_embed(embed)?,
+        None => DiscountEmbed::default(),
+    };
+
+    let filter = DiscountFilter {
+        shop_id: query.shop_id,
+        brand_id: query.brand_id,
+        category_id: query.category_id,
+        min_discount_rate: query.min_discount_rate,
+        max_discount_rate: query.max_discount_rate,
+        currency,
+        is_active: query.is_active,
+        start_date: query.start_date,
+        end_date: query.end_date,
+        preset,
+        embed,
+    };
+
+    // 키셋 모드 (opt-in) - count 쿼리 없이 next_cursor/prev_cursor 반환
+    if let Some(cursor) = query.cursor.as_deref() {
+        if query.sort.is_some() {
+            return Err(AppError::validation("sort is not supported with cursor pagination (ordered by created_at desc)"));
+        }
+
+        let pagination = CursorPagenation { cursor: validate_cursor(cursor)?, limit };
+        let result = state.discount_service
+            .get_discounts_by_cursor(&filter, &pagination)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to get discounts: {}", e)))?;
+
+        return Ok(Json(json!({ 
+            "discounts": result.data,
+            "pagination": {
+                "limit": result.limit,
+                "next_cursor": result.next_cursor,
+                "prev_cursor": result.prev_cursor,
+                "has_next": result.has_next,
+                "has_prev": result.has_prev
+            }
+        })));
+    }
+
+    // 프리셋은 sort가 없을 때 자체 기본 정렬 사용 (ending_soon: 종료 임박 순, newly_started: 최신 시작 순)
+    let sort = query.sort.as_deref().or(preset.map(|p| p.default_sort()));
+    let sort = validate_sort(sort, DISCOUNT_SORT_FIELDS)?;
+    let pagination = Pagenation { page, limit };
+
+    log::info!("💰 Getting discounts list");
+    let result = state.discount_service
+        .get_discounts(&filter, pagination, &sort)
+        .await
+        .map_err(|e| AppError::internal(format!("Failed to get discounts: {}", e)))?;
+    
+    Ok(Json(json!({ 
+        "discounts": result.data,
+        "pagination": {
+            "page": result.page,
+            "limit": result.limit,
+            "total": result.total,
+            "total_pages": result.total_pages,
+            "has_next": result.has_next,
+            "has_prev": result.has_prev
+        }
+    })))
+}
+
 async fn get_discount_by_id(
     Path(discount_id): Path<i64>,
+    Query(query): Query<LocaleQuery>,
+    headers: HeaderMap,
     State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
-    log::info!("💰 Getting discount by ID: {}", discount_id);
+    let accept_language = headers.get(ACCEPT_LANGUAGE).and_then(|v| v.to_str().ok());
+    let locale = resolve_locale(query.locale.as_deref(), accept_language)?;
+
+    log::info!("💰 Getting discount by ID: {} (locale: {})", discount_id, locale);
     
     let discount = state.discount_service
-        .get_discount_by_id(discount_id)
+        .get_discount_detail(discount_id, &locale)
         .await
         .map_err(|e| AppError::internal(format!("Failed to get discount: {}", e)))?;
     
     match discount {
-        Some(discount) => Ok(Json(json!({ "discount": discount }))),
+        Some(discount) => Ok(Json(json!({ "discount": discount, "locale": locale }))),
         None => Err(AppError::not_found("Discount")),
     }
 }
@@ -307,14 +649,14 @@ async fn get_shop_by_id(
 }
 
 // 👥 Phase 2: 사용자 프로필 핸들러들
-async fn get_user_profile(
-    Path(user_id): Path<String>,
+async fn get_my_profile(
+    user: AuthUser,
     State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
-    log::info!("👤 Getting user profile: {}", user_id);
+    log::info!("👤 Getting user profile: {}", user.id);
     
     let profile = state.user_service
-        .get_profile(&user_id)
+        .get_profile(&user)
         .await
         .map_err(|e| AppError::internal(format!("Failed to get user profile: {}", e)))?;
     
@@ -324,18 +666,18 @@ async fn get_user_profile(
     }
 }
 
-async fn update_user_profile(
-    Path(user_id): Path<String>,
+async fn update_my_profile(
+    user: AuthUser,
     State(state): State<Arc<AppState>>,
     Json(payload): Json<serde_json::Value>,
 ) -> AppResult<Json<serde_json::Value>> {
-    log::info!("👤 Updating user profile: {}", user_id);
+    log::info!("👤 Updating user profile: {}", user.id);
     
     // Parse payload as Profile - using actual Profile fields
     let profile = crate::domain::entities::user::Profile {
-        user_id: user_id.clone(),
+        user_id: user.id.clone(),
         avatar_url: payload.get("avatar_url").and_then(|v| v.as_str()).map(|s| s.to_string()),
-        email: payload.get("email").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+        email: payload.get("email").and_then(|v| v.as_str()).unwrap_or(&user.email).to_string(),
         preferred_country: payload.get("preferred_country").and_then(|v| v.as_str()).map(|s| s.to_string()),
         detected_country: payload.get("detected_country").and_then(|v| v.as_str()).map(|s| s.to_string()),
         language: payload.get("language").and_then(|v| v.as_str()).map(|s| s.to_string()),
@@ -345,26 +687,26 @@ async fn update_user_profile(
     };
     
     state.user_service
-        .update_profile(profile)
+        .update_profile(&user, profile)
         .await
         .map_err(|e| AppError::internal(format!("Failed to update user profile: {}", e)))?;
     
     Ok(Json(json!({ 
         "success": true,
         "message": "User profile updated successfully",
-        "user_id": user_id 
+        "user_id": user.id 
     })))
 }
 
 // 📋 Phase 2: 구독 관리 핸들러들
 async fn get_my_subscriptions(
-    Path(user_id): Path<String>,
+    user: AuthUser,
     State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
-    log::info!("📋 Getting subscriptions for user: {}", user_id);
+    log::info!("📋 Getting subscriptions for user: {}", user.id);
     
     let subscriptions = state.user_service
-        .get_all_subscriptions(&user_id)
+        .get_all_subscriptions(&user)
         .await
         .map_err(|e| AppError::internal(format!("Failed to get subscriptions: {}", e)))?;
     
@@ -372,13 +714,14 @@ async fn get_my_subscriptions(
 }
 
 async fn add_product_subscription(
-    Path((user_id, product_id)): Path<(String, i64)>,
+    user: AuthUser,
+    Path(product_id): Path<i64>,
     State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
-    log::info!("📦 Adding product subscription: user={}, product={}", user_id, product_id);
+    log::info!("📦 Adding product subscription: user={}, product={}", user.id, product_id);
     
     let subscription = state.user_service
-        .add_product_subscription(&user_id, product_id)
+        .add_product_subscription(&user, product_id)
         .await
         .map_err(|e| AppError::internal(format!("Failed to add product subscription: {}", e)))?;
     
@@ -390,32 +733,34 @@ async fn add_product_subscription(
 }
 
 async fn remove_product_subscription(
-    Path((user_id, product_id)): Path<(String, i64)>,
+    user: AuthUser,
+    Path(product_id): Path<i64>,
     State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
-    log::info!("📦 Removing product subscription: user={}, product={}", user_id, product_id);
+    log::info!("📦 Removing product subscription: user={}, product={}", user.id, product_id);
     
     state.user_service
-        .remove_product_subscription(&user_id, product_id)
+        .remove_product_subscription(&user, product_id)
         .await
         .map_err(|e| AppError::internal(format!("Failed to remove product subscription: {}", e)))?;
     
     Ok(Json(json!({ 
         "success": true,
         "message": "Product subscription removed",
-        "user_id": user_id,
+        "user_id": user.id,
         "product_id": product_id
     })))
 }
 
 async fn add_brand_subscription(
-    Path((user_id, brand_id)): Path<(String, i64)>,
+    user: AuthUser,
+    Path(brand_id): Path<i64>,
     State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
-    log::info!("🏷️ Adding brand subscription: user={}, brand={}", user_id, brand_id);
+    log::info!("🏷️ Adding brand subscription: user={}, brand={}", user.id, brand_id);
     
     let subscription = state.user_service
-        .add_brand_subscription(&user_id, brand_id)
+        .add_brand_subscription(&user, brand_id)
         .await
         .map_err(|e| AppError::internal(format!("Failed to add brand subscription: {}", e)))?;
     
@@ -427,32 +772,34 @@ async fn add_brand_subscription(
 }
 
 async fn remove_brand_subscription(
-    Path((user_id, brand_id)): Path<(String, i64)>,
+    user: AuthUser,
+    Path(brand_id): Path<i64>,
     State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
-    log::info!("🏷️ Removing brand subscription: user={}, brand={}", user_id, brand_id);
+    log::info!("🏷️ Removing brand subscription: user={}, brand={}", user.id, brand_id);
     
     state.user_service
-        .remove_brand_subscription(&user_id, brand_id)
+        .remove_brand_subscription(&user, brand_id)
         .await
         .map_err(|e| AppError::internal(format!("Failed to remove brand subscription: {}", e)))?;
     
     Ok(Json(json!({ 
         "success": true,
         "message": "Brand subscription removed",
-        "user_id": user_id,
+        "user_id": user.id,
         "brand_id": brand_id
     })))
 }
 
 async fn add_shop_subscription(
-    Path((user_id, shop_id)): Path<(String, i64)>,
+    user: AuthUser,
+    Path(shop_id): Path<i64>,
     State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
-    log::info!("🏪 Adding shop subscription: user={}, shop={}", user_id, shop_id);
+    log::info!("🏪 Adding shop subscription: user={}, shop={}", user.id, shop_id);
     
     let subscription = state.user_service
-        .add_shop_subscription(&user_id, shop_id, true) // Default to notifications enabled
+        .add_shop_subscription(&user, shop_id, true) // Default to notifications enabled
         .await
         .map_err(|e| AppError::internal(format!("Failed to add shop subscription: {}", e)))?;
     
@@ -464,20 +811,21 @@ async fn add_shop_subscription(
 }
 
 async fn remove_shop_subscription(
-    Path((user_id, shop_id)): Path<(String, i64)>,
+    user: AuthUser,
+    Path(shop_id): Path<i64>,
     State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
-    log::info!("🏪 Removing shop subscription: user={}, shop={}", user_id, shop_id);
+    log::info!("🏪 Removing shop subscription: user={}, shop={}", user.id, shop_id);
     
     state.user_service
-        .remove_shop_subscription(&user_id, shop_id)
+        .remove_shop_subscription(&user, shop_id)
         .await
         .map_err(|e| AppError::internal(format!("Failed to remove shop subscription: {}", e)))?;
     
     Ok(Json(json!({ 
         "success": true,
         "message": "Shop subscription removed",
-        "user_id": user_id,
+        "user_id": user.id,
         "shop_id": shop_id
     })))
 }
@@ -486,7 +834,7 @@ async fn remove_shop_subscription(
 
 // 매장 목록 조회
 async fn get_shops(
-    Query(query): Query<ProductQuery>,
+    Query(query): Query<ListQuery>,
     State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
     let page = query.page.unwrap_or(1);
@@ -497,10 +845,11 @@ async fn get_shops(
     }
 
     let pagination = Pagenation { page, limit };
+    let sort = validate_sort(query.sort.as_deref(), SHOP_SORT_FIELDS)?;
     
     log::info!("🏪 Getting shops list");
     let result = state.shop_service
-        .get_shops_paginated(pagination)
+        .get_shops_paginated(pagination, &sort)
         .await
         .map_err(|e| AppError::internal(format!("Failed to get shops: {}", e)))?;
     
@@ -519,7 +868,7 @@ async fn get_shops(
 
 // 브랜드 목록 조회
 async fn get_brands(
-    Query(query): Query<ProductQuery>,
+    Query(query): Query<ListQuery>,
     State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
     let page = query.page.unwrap_or(1);
@@ -530,10 +879,11 @@ async fn get_brands(
     }
 
     let pagination = Pagenation { page, limit };
+    let sort = validate_sort(query.sort.as_deref(), BRAND_SORT_FIELDS)?;
     
     log::info!("🏷️ Getting brands list");
     let result = state.shop_service
-        .get_brands_paginated(pagination)
+        .get_brands_paginated(pagination, &sort)
         .await
         .map_err(|e| AppError::internal(format!("Failed to get brands: {}", e)))?;
     
@@ -604,34 +954,31 @@ async fn get_category_by_id(
     }
 }
 
-// 상품 검색
+// 상품 검색 (name/sku + 요청 언어 번역, 관련도 순)
 async fn search_products(
-    Query(search_query): Query<serde_json::Value>,
+    Query(search_query): Query<SearchQuery>,
+    headers: HeaderMap,
     State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
-    let query = search_query.get("q").and_then(|v| v.as_str()).unwrap_or("");
-    let page = search_query.get("page").and_then(|v| v.as_u64()).unwrap_or(1) as u32;
-    let limit = search_query.get("limit").and_then(|v| v.as_u64()).unwrap_or(20) as u32;
-    
-    if query.is_empty() {
-        return Err(AppError::validation("Search query is required"));
-    }
-    
-    if page == 0 || limit == 0 || limit > 100 {
-        return Err(AppError::validation("Invalid page or limit parameters"));
-    }
+    let query = validate_search_query(search_query.q.as_deref().unwrap_or(""))?;
+    let (page, limit) = validate_pagination(
+        search_query.page.unwrap_or(1),
+        search_query.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+    )?;
+    let accept_language = headers.get(ACCEPT_LANGUAGE).and_then(|v| v.to_str().ok());
+    let locale = resolve_locale(search_query.locale.as_deref(), accept_language)?;
 
     let pagination = Pagenation { page, limit };
     
     log::info!("🔍 Searching products with query: '{}'", query);
-    // 임시 구현 - 전체 상품 목록 반환
     let result = state.product_service
-        .get_all_products(pagination)
+        .search_products(&query, &locale, pagination)
         .await
         .map_err(|e| AppError::internal(format!("Failed to search products: {}", e)))?;
     
     Ok(Json(json!({ 
         "query": query,
+        "locale": locale,
         "products": result.data,
         "pagination": {
             "page": result.page,
@@ -646,26 +993,34 @@ async fn search_products(
 
 // 💰 Phase 3: 쿠폰 시스템 핸들러들
 
-// 쿠폰 목록 조회
+// 쿠폰 목록 조회 (사용 가능한 쿠폰, shop_id 필터)
 async fn get_coupons(
-    Query(query): Query<ProductQuery>,
-    State(_state): State<Arc<AppState>>,
+    Query(query): Query<CouponQuery>,
+    State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
     let page = query.page.unwrap_or(1);
     let limit = query.limit.unwrap_or(20);
     
+    if page == 0 || limit == 0 || limit > 100 {
+        return Err(AppError::validation("Invalid page or limit parameters"));
+    }
+
+    let pagination = Pagenation { page, limit };
+    
     log::info!("🎫 Getting coupons list");
+    let result = state.coupon_service
+        .get_active_coupons(query.shop_id, pagination)
+        .await?;
     
-    // 임시 구현 - 빈 쿠폰 목록 반환
     Ok(Json(json!({ 
-        "coupons": [],
+        "coupons": result.data,
         "pagination": {
-            "page": page,
-            "limit": limit,
-            "total": 0,
-            "total_pages": 0,
-            "has_next": false,
-            "has_prev": false
+            "page": result.page,
+            "limit": result.limit,
+            "total": result.total,
+            "total_pages": result.total_pages,
+            "has_next": result.has_next,
+            "has_prev": result.has_prev
         }
     })))
 }
@@ -673,26 +1028,73 @@ async fn get_coupons(
 // 쿠폰 상세 조회
 async fn get_coupon_by_id(
     Path(coupon_id): Path<i64>,
-    State(_state): State<Arc<AppState>>,
+    State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
     log::info!("🎫 Getting coupon by ID: {}", coupon_id);
     
-    // 임시 구현 - 쿠폰 찾을 수 없음
-    Err(AppError::not_found("Coupon"))
+    let coupon = state.coupon_service
+        .get_coupon_by_id(coupon_id)
+        .await?;
+    
+    match coupon {
+        Some(coupon) => Ok(Json(json!({ "coupon": coupon }))),
+        None => Err(AppError::not_found("Coupon")),
+    }
+}
+
+// 쿠폰 코드로 조회
+async fn get_coupon_by_code(
+    Path(code): Path<String>,
+    State(state): State<Arc<AppState>>,
+) -> AppResult<Json<serde_json::Value>> {
+    log::info!("🎫 Getting coupon by code: {}", code);
+    
+    let coupon = state.coupon_service
+        .get_coupon_by_code(&code)
+        .await?;
+    
+    match coupon {
+        Some(coupon) => Ok(Json(json!({ "coupon": coupon }))),
+        None => Err(AppError::not_found("Coupon")),
+    }
+}
+
+// 쿠폰 검증 (코드 + 주문 금액 → 할인 금액 계산)
+async fn validate_coupon(
+    user: AuthUser,
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<ValidateCouponRequest>,
+) -> AppResult<Json<serde_json::Value>> {
+    log::info!("🎫 Validating coupon code: {} (user: {})", payload.code, user.id);
+    
+    if payload.code.trim().is_empty() {
+        return Err(AppError::validation("Coupon code is required"));
+    }
+    
+    let result = state.coupon_service
+        .validate_coupon_code(&user, payload.code.trim(), payload.order_amount)
+        .await?;
+    
+    Ok(Json(json!({ "validation": result })))
 }
 
 // 쿠폰 사용
 async fn use_coupon(
+    user: AuthUser,
     Path(coupon_id): Path<i64>,
-    State(_state): State<Arc<AppState>>,
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<UseCouponRequest>,
 ) -> AppResult<Json<serde_json::Value>> {
-    log::info!("🎫 Using coupon: {}", coupon_id);
+    log::info!("🎫 Using coupon: {} (user: {})", coupon_id, user.id);
+    
+    let usage = state.coupon_service
+        .use_coupon(&user, coupon_id, payload.order_amount)
+        .await?;
     
-    // 임시 구현 - 쿠폰 사용 처리
     Ok(Json(json!({ 
         "success": true,
         "message": "Coupon used successfully",
-        "coupon_id": coupon_id
+        "usage": usage
     })))
 }
 
@@ -700,8 +1102,8 @@ async fn use_coupon(
 
 // 알림 목록 조회
 async fn get_notifications(
-    Path(user_id): Path<String>,
-    Query(query): Query<ProductQuery>,
+    user: AuthUser,
+    Query(query): Query<NotificationQuery>,
     State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
     let page = query.page.unwrap_or(1);
@@ -711,13 +1113,36 @@ async fn get_notifications(
         return Err(AppError::validation("Invalid page or limit parameters"));
     }
 
+    // 키셋 모드 (opt-in) - count 쿼리 없이 next_cursor/prev_cursor 반환
+    if let Some(cursor) = query.cursor.as_deref() {
+        if query.sort.is_some() {
+            return Err(AppError::validation("sort is not supported with cursor pagination (ordered by created_at desc)"));
+        }
+
+        let pagination = CursorPagenation { cursor: validate_cursor(cursor)?, limit };
+        let result = state.notification_service
+            .get_notifications_by_cursor(&user.id, query.is_read, &pagination)
+            .await?;
+
+        return Ok(Json(json!({ 
+            "notifications": result.data,
+            "pagination": {
+                "limit": result.limit,
+                "next_cursor": result.next_cursor,
+                "prev_cursor": result.prev_cursor,
+                "has_next": result.has_next,
+                "has_prev": result.has_prev
+            }
+        })));
+    }
+
     let pagination = Pagenation { page, limit };
+    let sort = validate_sort(query.sort.as_deref(), NOTIFICATION_SORT_FIELDS)?;
     
-    log::info!("🔔 Getting notifications for user: {}", user_id);
+    log::info!("🔔 Getting notifications for user: {}", user.id);
     let result = state.notification_service
-        .get_notifications(&user_id, pagination)
-        .await
-        .map_err(|e| AppError::internal(format!("Failed to get notifications: {}", e)))?;
+        .get_notifications(&user.id, query.is_read, pagination, &sort)
+        .await?;
     
     Ok(Json(json!({ 
         "notifications": result.data,
@@ -732,17 +1157,29 @@ async fn get_notifications(
     })))
 }
 
+// 안읽은 알림 개수
+async fn get_unread_notification_count(
+    user: AuthUser,
+    State(state): State<Arc<AppState>>,
+) -> AppResult<Json<serde_json::Value>> {
+    let unread_count = state.notification_service
+        .get_unread_count(&user.id)
+        .await?;
+
+    Ok(Json(json!({ "unread_count": unread_count })))
+}
+
 // 알림 읽음 처리
 async fn mark_notification_read(
+    user: AuthUser,
     Path(notification_id): Path<i64>,
     State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
     log::info!("📖 Marking notification as read: {}", notification_id);
     
     state.notification_service
-        .mark_notification_read(notification_id)
-        .await
-        .map_err(|e| AppError::internal(format!("Failed to mark notification as read: {}", e)))?;
+        .mark_notification_read(&user.id, notification_id)
+        .await?;
     
     Ok(Json(json!({ 
         "success": true,
@@ -751,49 +1188,85 @@ async fn mark_notification_read(
     })))
 }
 
+// 전체 알림 읽음 처리
+async fn mark_all_notifications_read(
+    user: AuthUser,
+    State(state): State<Arc<AppState>>,
+) -> AppResult<Json<serde_json::Value>> {
+    let updated = state.notification_service
+        .mark_all_read(&user.id)
+        .await?;
+
+    Ok(Json(json!({ 
+        "success": true,
+        "message": "All notifications marked as read",
+        "updated": updated
+    })))
+}
+
+// 알림 삭제
+async fn delete_notification(
+    user: AuthUser,
+    Path(notification_id): Path<i64>,
+    State(state): State<Arc<AppState>>,
+) -> AppResult<Json<serde_json::Value>> {
+    state.notification_service
+        .delete_notification(&user.id, notification_id)
+        .await?;
+
+    Ok(Json(json!({ 
+        "success": true,
+        "message": "Notification deleted",
+        "notification_id": notification_id
+    })))
+}
+
 // 알림 설정 조회
 async fn get_notification_settings(
-    Path(user_id): Path<String>,
+    user: AuthUser,
     State(state): State<Arc<AppState>>,
 ) -> AppResult<Json<serde_json::Value>> {
-    log::info!("⚙️ Getting notification settings for user: {}", user_id);
+    log::info!("⚙️ Getting notification settings for user: {}", user.id);
     
     let settings = state.notification_service
-        .get_notification_settings(&user_id)
-        .await
-        .map_err(|e| AppError::internal(format!("Failed to get notification settings: {}", e)))?;
+        .get_notification_settings(&user.id)
+        .await?;
     
-    match settings {
-        Some(settings) => Ok(Json(json!({ "settings": settings }))),
-        None => Err(AppError::not_found("Notification settings")),
-    }
+    Ok(Json(json!({ "settings": settings })))
 }
 
-// 알림 설정 업데이트
+// 알림 설정 전체 변경 (빠진 필드는 기본값)
 async fn update_notification_settings(
+    user: AuthUser,
     State(state): State<Arc<AppState>>,
-    Path(user_id): Path<String>,
-    Json(payload): Json<serde_json::Value>,
+    Json(payload): Json<NotificationSettingsPatch>,
 ) -> AppResult<Json<serde_json::Value>> {
-    log::info!("🔧 Updating notification settings for user: {}", user_id);
-    
-    // 페이로드를 NotificationSettings로 변환 (실제 필드 구조 사용)
-    let settings = crate::domain::entities::notification::NotificationSettings {
-        user_id: user_id.clone(),
-        push_enabled: payload.get("push_enabled").and_then(|v| v.as_bool()).unwrap_or(true),
-        email_enabled: payload.get("email_enabled").and_then(|v| v.as_bool()).unwrap_or(true),
-        sms_enabled: payload.get("sms_enabled").and_then(|v| v.as_bool()).unwrap_or(false),
-        discount_alerts: payload.get("discount_alerts").and_then(|v| v.as_bool()).unwrap_or(true),
-        price_drop_alerts: payload.get("price_drop_alerts").and_then(|v| v.as_bool()).unwrap_or(true),
-        new_product_alerts: payload.get("new_product_alerts").and_then(|v| v.as_bool()).unwrap_or(true),
-        created_at: chrono::Utc::now(),
-        updated_at: chrono::Utc::now(),
-    };
-    
+    save_notification_settings(&state, &user, payload, true).await
+}
+
+// 알림 설정 부분 변경 (보낸 필드만 변경)
+async fn patch_notification_settings(
+    user: AuthUser,
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<NotificationSettingsPatch>,
+) -> AppResult<Json<serde_json::Value>> {
+    save_notification_settings(&state, &user, payload, false).await
+}
+
+async fn save_notification_settings(
+    state: &AppState,
+    user: &AuthUser,
+    payload: NotificationSettingsPatch,
+    replace: bool,
+) -> AppResult<Json<serde_json::Value>> {
+    log::info!("🔧 Updating notification settings for user: {}", user.id);
+    if let Some(quiet_hours) = &payload.quiet_hours {
+        validate_quiet_hours(quiet_hours)?;
+    }
+
     let updated_settings = state.notification_service
-        .update_notification_settings(&user_id, settings)
-        .await
-        .map_err(|e| AppError::internal(format!("Failed to update notification settings: {}", e)))?;
+        .update_notification_settings(&user.id, payload, replace)
+        .await?;
     
     Ok(Json(json!({ 
         "success": true,
@@ -802,6 +1275,117 @@ async fn update_notification_settings(
     })))
 }
 
+// Web Push 구독 등록
+async fn register_push_subscription(
+    user: AuthUser,
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<PushSubscriptionRequest>,
+) -> AppResult<Json<serde_json::Value>> {
+    validate_push_subscription(&payload)?;
+
+    let subscription = PushSubscription {
+        endpoint: payload.endpoint,
+        p256dh: payload.keys.p256dh,
+        auth: payload.keys.auth,
+    };
+    state.notification_service
+        .register_push_subscription(&user.id, subscription, payload.user_agent.as_deref())
+        .await?;
+
+    Ok(Json(json!({ 
+        "success": true,
+        "message": "Push subscription registered"
+    })))
+}
+
+// Web Push 구독 해제
+async fn remove_push_subscription(
+    user: AuthUser,
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<DeletePushSubscriptionRequest>,
+) -> AppResult<Json<serde_json::Value>> {
+    state.notification_service
+        .remove_push_subscription(&user.id, &payload.endpoint)
+        .await?;
+
+    Ok(Json(json!({ 
+        "success": true,
+        "message": "Push subscription removed"
+    })))
+}
+
+// 실시간 연결 사용자 - Authorization 헤더 또는 ?access_token=
+async fn realtime_user(state: &AppState, headers: &HeaderMap, access_token: Option<&str>) -> AppResult<AuthUser> {
+    let token = match access_token {
+        Some(token) => token,
+        None => bearer_token(headers)?,
+    };
+    extract_user_from_token(&state.jwt_verifier, token).await
+}
+
+// SSE 재연결 위치 - Last-Event-ID 헤더 우선, 없으면 ?last_event_id=
+fn last_event_id(headers: &HeaderMap, query_value: Option<u64>) -> AppResult<Option<u64>> {
+    match headers.get("last-event-id") {
+        Some(value) => value
+            .to_str()
+            .ok()
+            .and_then(|value| value.trim().parse().ok())
+            .map(Some)
+            .ok_or_else(|| AppError::validation("Invalid Last-Event-ID header")),
+        None => Ok(query_value),
+    }
+}
+
+// 실시간 WebSocket - 새 알림과 구독 상품의 할인 변경을 전달
+async fn realtime_websocket(
+    ws: WebSocketUpgrade,
+    headers: HeaderMap,
+    Query(query): Query<RealtimeQuery>,
+    State(state): State<Arc<AppState>>,
+) -> AppResult<Response> {
+    let user = realtime_user(&state, &headers, query.access_token.as_deref()).await?;
+    let product_ids = state.user_service.subscribed_product_ids(&user.id).await?;
+    log::info!("🔌 WebSocket connected for user: {} ({} subscribed products)", user.id, product_ids.len());
+
+    let bus = state.realtime_bus.clone();
+    let user_service = state.user_service.clone();
+    Ok(ws.on_upgrade(move |socket| serve_realtime_socket(socket, bus, user_service, user.id, product_ids)))
+}
+
+// 내 새 알림 SSE 스트림
+async fn notification_stream(
+    headers: HeaderMap,
+    Query(query): Query<RealtimeQuery>,
+    State(state): State<Arc<AppState>>,
+) -> AppResult<Sse<impl Stream<Item = Result<Event, Infallible>>>> {
+    let user = realtime_user(&state, &headers, query.access_token.as_deref()).await?;
+    let last_event_id = last_event_id(&headers, query.last_event_id)?;
+    log::info!("📡 Notification stream opened for user: {} (Last-Event-ID: {:?})", user.id, last_event_id);
+
+    let stream = realtime_sse_stream(&state.realtime_bus, last_event_id, move |event| {
+        matches!(event, RealtimeEvent::Notification { notification } if notification.user_id == user.id)
+    });
+    Ok(Sse::new(stream).keep_alive(sse_keep_alive()))
+}
+
+// 할인 생성/변경 SSE 스트림 (?product_id= 로 한 상품만)
+// Supabase Realtime 을 쓰지 않으면 이 서버의 크롤러가 바꾼 할인만 전달
+async fn discount_stream(
+    headers: HeaderMap,
+    Query(query): Query<DiscountStreamQuery>,
+    State(state): State<Arc<AppState>>,
+) -> AppResult<Sse<impl Stream<Item = Result<Event, Infallible>>>> {
+    let last_event_id = last_event_id(&headers, query.last_event_id)?;
+    log::info!("📡 Discount stream opened (product: {:?}, Last-Event-ID: {:?})", query.product_id, last_event_id);
+
+    let product_id = query.product_id;
+    let stream = realtime_sse_stream(&state.realtime_bus, last_event_id, move |event| match event {
+        RealtimeEvent::DiscountUpdate { discount, .. } => product_id.is_none_or(|id| id == discount.product_id),
+        _ => false,
+    });
+    Ok(Sse::new(stream).keep_alive(sse_keep_alive()))
+}
+
 // 📈 Phase 4: 모니터링 핸들러들
 
 // API 메트릭 조회
@@ -867,3 +1451,74 @@ async fn get_system_health(
     
     Ok(Json(health))
 }
+
+// 크롤링 라우트는 크롤러가 있을 때만 등록되지만 핸들러에서도 확인
+fn crawler(state: &AppState) -> AppResult<&CrawlerService> {
+    state.crawler_service
+        .as_ref()
+        .ok_or_else(|| AppError::internal("Crawler is not configured"))
+}
+
+// 🕷️ 상점 크롤링 시작 - 목록 페이지에서 찾은 상품, 기존 상품 페이지, 요청한 페이지를 백그라운드에서 크롤링해 상품/자동 발견 할인 반영
+// 진행 상황과 결과는 반환한 실행 기록(run.id)으로 조회
+// 본문이 비어 있으면 기본 크롤링, 본문이 있는데 JSON 이 잘못되었으면 400 (잘못된 요청으로 전체 크롤링이 시작되지 않도록)
+async fn crawl_shop(
+    Path(shop_id): Path<i64>,
+    State(state): State<Arc<AppState>>,
+    body: Bytes,
+) -> AppResult<(StatusCode, Json<serde_json::Value>)> {
+    let payload: CrawlShopRequest = if body.iter().all(u8::is_ascii_whitespace) {
+        CrawlShopRequest::default()
+    } else {
+        serde_json::from_slice(&body)
+            .map_err(|e| AppError::validation(format!("Invalid crawl request body: {}", e)))?
+    };
+    log::info!("🕷️ Starting crawl for shop: {} ({} extra urls, {} listing urls)", shop_id, payload.urls.len(), payload.listing_urls.len());
+
+    let run = crawler(&state)?
+        .start_crawl(shop_id, payload, CrawlTrigger::Manual)
+        .await?;
+
+    Ok((StatusCode::ACCEPTED, Json(json!({
+        "success": true,
+        "run": run
+    }))))
+}
+
+// 상점 크롤링 실행 기록 (최신순)
+async fn get_crawl_runs(
+    Path(shop_id): Path<i64>,
+    Query(query): Query<ListQuery>,
+    State(state): State<Arc<AppState>>,
+) -> AppResult<Json<serde_json::Value>> {
+    let (page, limit) = validate_pagination(query.page.unwrap_or(1), query.limit.unwrap_or(DEFAULT_PAGE_SIZE))?;
+
+    log::info!("🕷️ Getting crawl runs for shop: {}", shop_id);
+    let runs = crawler(&state)?
+        .get_crawl_runs(shop_id, Pagenation { page, limit })
+        .await?;
+
+    Ok(Json(json!({
+        "runs": runs.data,
+        "pagination": {
+            "page": runs.page,
+            "limit": runs.limit,
+            "total": runs.total,
+            "total_pages": runs.total_pages,
+            "has_next": runs.has_next,
+            "has_prev": runs.has_prev
+        }
+    })))
+}
+
+async fn get_crawl_run(
+    Path(run_id): Path<i64>,
+    State(state): State<Arc<AppState>>,
+) -> AppResult<Json<serde_json::Value>> {
+    log::info!("🕷️ Getting crawl run: {}", run_id);
+    let run = crawler(&state)?
+        .get_crawl_run(run_id)
+        .await?;
+
+    Ok(Json(json!({ "run": run })))
+}
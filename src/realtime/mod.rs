@@ -0,0 +1,135 @@
+pub mod supabase;
+pub mod socket;
+pub mod sse;
+
+pub use supabase::*;
+pub use socket::*;
+pub use sse::*;
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use tokio::sync::broadcast;
+
+use crate::domain::entities::discount::DiscountInfo;
+use crate::domain::entities::notification::Notification;
+use crate::utils::constants::{REALTIME_BUS_CAPACITY, REALTIME_REPLAY_CAPACITY};
+
+// 실시간으로 클라이언트에 전달하는 이벤트
+//   {"type": "notification", "notification": {...}}
+//   {"type": "discount_update", "change": "INSERT" | "UPDATE", "discount": {...}}
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RealtimeEvent {
+    Notification { notification: Notification },
+    DiscountUpdate { change: String, discount: DiscountInfo },
+}
+
+impl RealtimeEvent {
+    // 이벤트 종류 (JSON type, SSE event 이름)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            RealtimeEvent::Notification { .. } => "notification",
+            RealtimeEvent::DiscountUpdate { .. } => "discount_update",
+        }
+    }
+
+    // 알림은 받는 사용자에게만, 할인 변경은 해당 상품을 구독한 사용자에게만 전달
+    pub fn is_visible_to(&self, user_id: &str, product_ids: &HashSet<i64>) -> bool {
+        match self {
+            RealtimeEvent::Notification { notification } => notification.user_id == user_id,
+            RealtimeEvent::DiscountUpdate { discount, .. } => product_ids.contains(&discount.product_id),
+        }
+    }
+}
+
+// 버스가 발행 순서대로 번호를 붙인 이벤트 (SSE 의 id / Last-Event-ID)
+#[derive(Debug, Clone)]
+pub struct SequencedEvent {
+    pub id: u64,
+    pub event: RealtimeEvent,
+}
+
+// Last-Event-ID 이후 이벤트 - missed 면 버퍼에서 이미 밀려났거나 모르는 id 라 일부를 놓친 상태
+#[derive(Debug, Default)]
+pub struct Replay {
+    pub events: Vec<SequencedEvent>,
+    pub missed: bool,
+}
+
+// 서버 내부 이벤트 버스 - Supabase Realtime 수신기 또는 서버 안의 발행자가 publish 하고
+// WebSocket/SSE 연결마다 subscribe 해서 자기 사용자에게 보이는 이벤트만 보낸다.
+// 최근 REALTIME_REPLAY_CAPACITY 개 이벤트는 재연결한 SSE 클라이언트에 다시 보내기 위해 보관한다.
+#[derive(Clone)]
+pub struct RealtimeBus {
+    sender: broadcast::Sender<SequencedEvent>,
+    replay: Arc<Mutex<ReplayBuffer>>,
+}
+
+struct ReplayBuffer {
+    next_id: u64,
+    events: VecDeque<SequencedEvent>,
+}
+
+impl RealtimeBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(REALTIME_BUS_CAPACITY);
+        Self {
+            sender,
+            replay: Arc::new(Mutex::new(ReplayBuffer {
+                next_id: 1,
+                events: VecDeque::with_capacity(REALTIME_REPLAY_CAPACITY),
+            })),
+        }
+    }
+
+    // 번호를 붙여 보관 후 전송 (구독자가 없으면 보관만)
+    pub fn publish(&self, event: RealtimeEvent) {
+        let mut replay = self.replay.lock().unwrap();
+        let event = SequencedEvent { id: replay.next_id, event };
+        replay.next_id += 1;
+
+        if replay.events.len() == REALTIME_REPLAY_CAPACITY {
+            replay.events.pop_front();
+        }
+        replay.events.push_back(event.clone());
+
+        // 보관과 전송을 같은 잠금 안에서 해야 subscribe_from 의 재전송/실시간 이벤트가 겹치거나 빠지지 않음
+        let _ = self.sender.send(event);
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<SequencedEvent> {
+        self.sender.subscribe()
+    }
+
+    // last_event_id 이후 보관된 이벤트와, 그 다음 이벤트부터 받는 구독을 함께 반환
+    pub fn subscribe_from(&self, last_event_id: Option<u64>) -> (Replay, broadcast::Receiver<SequencedEvent>) {
+        let replay = self.replay.lock().unwrap();
+        let receiver = self.sender.subscribe();
+
+        let Some(last_event_id) = last_event_id else {
+            return (Replay::default(), receiver);
+        };
+
+        // 다른 서버 프로세스(재시작 전)가 준 id 면 어디까지 받았는지 알 수 없음
+        if last_event_id >= replay.next_id {
+            return (Replay { events: Vec::new(), missed: true }, receiver);
+        }
+
+        let oldest_id = replay.events.front().map_or(replay.next_id, |event| event.id);
+        let events = replay.events
+            .iter()
+            .filter(|event| event.id > last_event_id)
+            .cloned()
+            .collect();
+
+        (Replay { events, missed: last_event_id + 1 < oldest_id }, receiver)
+    }
+}
+
+impl Default for RealtimeBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,224 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use axum::extract::ws::{Message, WebSocket};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::json;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::realtime::RealtimeBus;
+use crate::service::UserService;
+use crate::utils::constants::{WS_PING_INTERVAL_SECS, WS_SUBSCRIPTION_REFRESH_SECS};
+
+// /api/v1/ws 연결 하나 - 이벤트 버스에서 이 사용자에게 보이는 이벤트만 JSON 텍스트 메시지로 보낸다.
+// 연결 직후 {"type": "connected", "product_ids": [...]} 를 보내고,
+// 버스를 따라가지 못해 이벤트를 놓치면 {"type": "lagged", "skipped": n} 을 보낸다 (클라이언트는 목록 API로 다시 맞춤).
+// 상품 구독 목록은 WS_SUBSCRIPTION_REFRESH_SECS 마다 다시 읽는다.
+pub async fn serve_realtime_socket(
+    socket: WebSocket,
+    bus: RealtimeBus,
+    user_service: UserService,
+    user_id: String,
+    mut product_ids: HashSet<i64>,
+) {
+    let (mut sender, mut receiver) = socket.split();
+    let mut events = bus.subscribe();
+
+    let connected = json!({ "type": "connected", "product_ids": product_ids });
+    if sender.send(Message::Text(connected.to_string())).await.is_err() {
+        return;
+    }
+
+    let mut ping = tokio::time::interval(Duration::from_secs(WS_PING_INTERVAL_SECS));
+    let mut refresh = tokio::time::interval(Duration::from_secs(WS_SUBSCRIPTION_REFRESH_SECS));
+    ping.tick().await;
+    refresh.tick().await;
+
+    loop {
+        let outgoing = tokio::select! {
+            event = events.recv() => match event {
+                Ok(sequenced) if sequenced.event.is_visible_to(&user_id, &product_ids) => match serde_json::to_string(&sequenced.event) {
+                    Ok(text) => Message::Text(text),
+                    Err(e) => {
+                        log::error!("❌ Failed to serialize realtime event: {}", e);
+                        continue;
+                    }
+                },
+                Ok(_) => continue,
+                Err(RecvError::Lagged(skipped)) => {
+                    log::warn!("🔌 WebSocket for user {} lagged, skipped {} events", user_id, skipped);
+                    Message::Text(json!({ "type": "lagged", "skipped": skipped }).to_string())
+                }
+                Err(RecvError::Closed) => break,
+            },
+            incoming = receiver.next() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => continue, // 클라이언트 메시지는 사용하지 않음 (ping 응답은 자동)
+            },
+            _ = ping.tick() => Message::Ping(Vec::new()),
+            _ = refresh.tick() => {
+                match user_service.subscribed_product_ids(&user_id).await {
+                    Ok(ids) => product_ids = ids,
+                    Err(e) => log::warn!("🔌 Failed to refresh product subscriptions for user {}: {}", user_id, e),
+                }
+                continue;
+            }
+        };
+
+        if sender.send(outgoing).await.is_err() {
+            break;
+        }
+    }
+
+    log::info!("🔌 WebSocket closed for user: {}", user_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::extract::ws::WebSocketUpgrade;
+    use axum::routing::get;
+    use serde_json::Value;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::Message as WsMessage;
+
+    use crate::config::{RealtimeConfig, SupabaseConfig};
+    use crate::realtime::{RealtimeEvent, SupabaseRealtimeClient};
+    use crate::utils::constants::REALTIME_CHANNEL_TOPIC;
+
+    const USER: &str = "00000000-0000-0000-0000-00000000000a";
+    const OTHER_USER: &str = "00000000-0000-0000-0000-00000000000b";
+
+    fn notification(id: i64, user_id: &str) -> Value {
+        json!({
+            "id": id, "user_id": user_id, "title": "title", "message": "message", "type": "price_drop",
+            "is_read": false, "data": null, "created_at": "2026-10-17T12:00:00Z"
+        })
+    }
+
+    fn discount(id: i64, product_id: i64) -> Value {
+        json!({
+            "id": id, "product_id": product_id, "shop_id": null, "brand_id": null,
+            "original_price": 10000.0, "discount_price": 9000.0, "discount_rate": 10.0, "currency": "KRW",
+            "start_at": "2026-10-17T00:00:00Z", "end_at": "2026-10-24T00:00:00Z", "is_active": true,
+            "info_url": null, "source_url": null, "is_auto_discovered": null, "is_event_based": null,
+            "thumbnail_url": null, "click_count": null,
+            "created_at": "2026-10-17T00:00:00Z", "updated_at": "2026-10-17T00:00:00Z"
+        })
+    }
+
+    fn postgres_change(table: &str, change: &str, record: Value) -> String {
+        json!({
+            "topic": REALTIME_CHANNEL_TOPIC,
+            "event": "postgres_changes",
+            "payload": { "data": { "schema": "public", "table": table, "type": change, "record": record } },
+            "ref": null
+        })
+        .to_string()
+    }
+
+    async fn next_message<S>(client: &mut S) -> Value
+    where
+        S: futures_util::Stream<Item = Result<WsMessage, tokio_tungstenite::tungstenite::Error>> + Unpin,
+    {
+        match tokio::time::timeout(Duration::from_secs(5), client.next()).await.expect("timed out waiting for websocket message") {
+            Some(Ok(WsMessage::Text(text))) => serde_json::from_str(&text).unwrap(),
+            other => panic!("unexpected websocket message: {:?}", other),
+        }
+    }
+
+    // Supabase Realtime 대역 서버 → SupabaseRealtimeClient → 내부 버스 → /api/v1/ws 연결
+    // 사용자는 자기 알림과 구독한 상품(1)의 할인 변경만 받는다.
+    #[tokio::test]
+    async fn websocket_receives_only_own_notifications_and_subscribed_discounts() {
+        let bus = RealtimeBus::new();
+
+        // Realtime 대역 서버: 채널 참가 요청에 응답하고, 클라이언트가 연결되면 변경 이벤트 전송
+        let realtime = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let realtime_url = format!("ws://{}/realtime/v1/websocket?apikey=service-key&vsn=1.0.0", realtime.local_addr().unwrap());
+        let (ready_tx, ready_rx) = tokio::sync::oneshot::channel::<()>();
+        let (join_tx, join_rx) = tokio::sync::oneshot::channel::<Value>();
+        tokio::spawn(async move {
+            let (stream, _) = realtime.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+            let join = match socket.next().await {
+                Some(Ok(WsMessage::Text(text))) => serde_json::from_str::<Value>(&text).unwrap(),
+                other => panic!("expected phx_join, got {:?}", other),
+            };
+            let reply = json!({
+                "topic": REALTIME_CHANNEL_TOPIC, "event": "phx_reply",
+                "payload": { "status": "ok", "response": {} }, "ref": "1"
+            });
+            socket.send(WsMessage::Text(reply.to_string())).await.unwrap();
+            join_tx.send(join).unwrap();
+
+            ready_rx.await.unwrap();
+            for message in [
+                postgres_change("notifications", "INSERT", notification(1, OTHER_USER)),
+                postgres_change("notifications", "INSERT", notification(2, USER)),
+                postgres_change("discount_infos", "UPDATE", discount(10, 2)),
+                postgres_change("discount_infos", "INSERT", discount(11, 1)),
+                postgres_change("notifications", "UPDATE", notification(3, USER)), // 구독하지 않은 변경 종류
+            ] {
+                socket.send(WsMessage::Text(message)).await.unwrap();
+            }
+            // 테스트가 끝날 때까지 연결 유지
+            while socket.next().await.is_some() {}
+        });
+
+        let config = SupabaseConfig {
+            client: postgrest::Postgrest::new("http://127.0.0.1:9"),
+            url: "http://127.0.0.1:9".to_string(),
+            anon_key: "anon-key".to_string(),
+            service_key: "service-key".to_string(),
+            jwt_secret: None,
+            database_url: None,
+        };
+        let subscriber = SupabaseRealtimeClient::new(
+            RealtimeConfig { url: realtime_url, access_token: "service-key".to_string() },
+            bus.clone(),
+        )
+        .spawn();
+
+        // /api/v1/ws 와 같은 연결 처리 (인증/구독 조회 대신 사용자와 구독 상품을 고정)
+        let user_service = UserService::new(config);
+        let ws_bus = bus.clone();
+        let app = axum::Router::new().route("/ws", get(move |ws: WebSocketUpgrade| async move {
+            ws.on_upgrade(move |socket| serve_realtime_socket(socket, ws_bus, user_service, USER.to_string(), HashSet::from([1])))
+        }));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{}/ws", addr)).await.unwrap();
+        let connected = next_message(&mut client).await;
+        assert_eq!(connected, json!({ "type": "connected", "product_ids": [1] }));
+
+        let join = join_rx.await.unwrap();
+        assert_eq!(join["event"], "phx_join");
+        assert_eq!(join["topic"], REALTIME_CHANNEL_TOPIC);
+        assert_eq!(join["payload"]["access_token"], "service-key");
+        ready_tx.send(()).unwrap();
+
+        let first = next_message(&mut client).await;
+        assert_eq!((first["type"].as_str(), first["notification"]["id"].as_i64()), (Some("notification"), Some(2)));
+        assert_eq!(first["notification"]["user_id"], USER);
+
+        let second = next_message(&mut client).await;
+        assert_eq!(second["type"], "discount_update");
+        assert_eq!(second["change"], "INSERT");
+        assert_eq!((second["discount"]["id"].as_i64(), second["discount"]["product_id"].as_i64()), (Some(11), Some(1)));
+
+        // 서버 안에서 만든 알림(내부 버스 직접 발행)도 같은 규칙으로 전달
+        let other: crate::domain::entities::notification::Notification = serde_json::from_value(notification(4, OTHER_USER)).unwrap();
+        let own: crate::domain::entities::notification::Notification = serde_json::from_value(notification(5, USER)).unwrap();
+        bus.publish(RealtimeEvent::Notification { notification: other });
+        bus.publish(RealtimeEvent::Notification { notification: own });
+
+        let third = next_message(&mut client).await;
+        assert_eq!((third["type"].as_str(), third["notification"]["id"].as_i64()), (Some("notification"), Some(5)));
+
+        subscriber.abort();
+    }
+}
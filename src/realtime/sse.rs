@@ -0,0 +1,251 @@
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::response::sse::{Event, KeepAlive};
+use futures_util::{stream, Stream};
+use serde_json::json;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::realtime::{RealtimeBus, RealtimeEvent, SequencedEvent};
+use crate::utils::constants::SSE_HEARTBEAT_SECS;
+
+// SSE 이벤트 스트림 - Last-Event-ID 이후 보관된 이벤트를 먼저 보내고 이어서 실시간 이벤트를 보낸다.
+//   id: <버스 번호>, event: notification | discount_update, data: /api/v1/ws 와 같은 JSON
+// 놓친 이벤트가 있으면(버퍼에서 밀려남, 모르는 id, 버스를 따라가지 못함) event: reset 을 보낸다 (클라이언트는 목록 API로 다시 맞춤).
+pub fn realtime_sse_stream<F>(
+    bus: &RealtimeBus,
+    last_event_id: Option<u64>,
+    filter: F,
+) -> impl Stream<Item = Result<Event, Infallible>> + use<F>
+where
+    F: Fn(&RealtimeEvent) -> bool + Send + 'static,
+{
+    let (replay, receiver) = bus.subscribe_from(last_event_id);
+    let state = SseState {
+        reset: replay.missed.then_some("replay_unavailable"),
+        replay: replay.events.into(),
+        receiver,
+        filter,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(reason) = state.reset.take() {
+                return Some((Ok(reset_event(reason)), state));
+            }
+
+            let sequenced = match state.replay.pop_front() {
+                Some(sequenced) => sequenced,
+                None => match state.receiver.recv().await {
+                    Ok(sequenced) => sequenced,
+                    Err(RecvError::Lagged(skipped)) => {
+                        log::warn!("📡 SSE stream lagged, skipped {} events", skipped);
+                        state.reset = Some("lagged");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => return None,
+                },
+            };
+
+            if !(state.filter)(&sequenced.event) {
+                continue;
+            }
+
+            match sse_event(&sequenced) {
+                Ok(event) => return Some((Ok(event), state)),
+                Err(e) => log::error!("❌ Failed to serialize realtime event {}: {}", sequenced.id, e),
+            }
+        }
+    })
+}
+
+// 프록시가 유휴 연결을 끊지 않도록 주기적으로 `: heartbeat` 주석을 보낸다.
+pub fn sse_keep_alive() -> KeepAlive {
+    keep_alive(Duration::from_secs(SSE_HEARTBEAT_SECS))
+}
+
+fn keep_alive(interval: Duration) -> KeepAlive {
+    KeepAlive::new().interval(interval).text("heartbeat")
+}
+
+struct SseState<F> {
+    reset: Option<&'static str>,
+    replay: VecDeque<SequencedEvent>,
+    receiver: broadcast::Receiver<SequencedEvent>,
+    filter: F,
+}
+
+fn sse_event(sequenced: &SequencedEvent) -> Result<Event, serde_json::Error> {
+    Ok(Event::default()
+        .id(sequenced.id.to_string())
+        .event(sequenced.event.kind())
+        .data(serde_json::to_string(&sequenced.event)?))
+}
+
+fn reset_event(reason: &str) -> Event {
+    Event::default()
+        .event("reset")
+        .data(json!({ "type": "reset", "reason": reason }).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{Body, BodyDataStream};
+    use axum::response::{IntoResponse, Sse};
+    use futures_util::StreamExt;
+    use serde_json::Value;
+
+    use crate::domain::entities::notification::Notification;
+    use crate::utils::constants::REALTIME_REPLAY_CAPACITY;
+
+    const USER: &str = "00000000-0000-0000-0000-00000000000a";
+    const OTHER_USER: &str = "00000000-0000-0000-0000-00000000000b";
+
+    fn notification(id: i64, user_id: &str) -> RealtimeEvent {
+        let notification: Notification = serde_json::from_value(json!({
+            "id": id, "user_id": user_id, "title": "title", "message": "message", "type": "price_drop",
+            "is_read": false, "data": null, "created_at": "2026-10-17T12:00:00Z"
+        }))
+        .unwrap();
+        RealtimeEvent::Notification { notification }
+    }
+
+    fn own_notifications(event: &RealtimeEvent) -> bool {
+        matches!(event, RealtimeEvent::Notification { notification } if notification.user_id == USER)
+    }
+
+    // 응답 본문을 SSE 프레임(빈 줄로 구분) 단위로 읽는다.
+    struct SseReader {
+        body: BodyDataStream,
+        buffer: String,
+    }
+
+    impl SseReader {
+        fn new<S>(sse: Sse<S>) -> Self
+        where
+            Sse<S>: IntoResponse,
+        {
+            let body: Body = sse.into_response().into_body();
+            Self { body: body.into_data_stream(), buffer: String::new() }
+        }
+
+        async fn next_frame(&mut self) -> String {
+            loop {
+                if let Some(end) = self.buffer.find("\n\n") {
+                    let frame = self.buffer[..end].to_string();
+                    self.buffer.drain(..end + 2);
+                    return frame;
+                }
+                let chunk = tokio::time::timeout(Duration::from_secs(5), self.body.next())
+                    .await
+                    .expect("timed out waiting for SSE frame")
+                    .expect("SSE body ended")
+                    .unwrap();
+                self.buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+            }
+        }
+
+        // (id, event, data) - 하트비트 주석 프레임은 건너뛴다.
+        async fn next_event(&mut self) -> (Option<u64>, String, Value) {
+            loop {
+                let frame = self.next_frame().await;
+                let (mut id, mut event, mut data) = (None, String::new(), Value::Null);
+                for line in frame.lines() {
+                    if let Some(value) = line.strip_prefix("id: ") {
+                        id = Some(value.parse().unwrap());
+                    } else if let Some(value) = line.strip_prefix("event: ") {
+                        event = value.to_string();
+                    } else if let Some(value) = line.strip_prefix("data: ") {
+                        data = serde_json::from_str(value).unwrap();
+                    }
+                }
+                if !event.is_empty() {
+                    return (id, event, data);
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn replays_events_after_last_event_id_then_streams_live() {
+        let bus = RealtimeBus::new();
+        bus.publish(notification(1, USER)); // id 1 - 이미 받음
+        bus.publish(notification(2, OTHER_USER)); // id 2
+        bus.publish(notification(3, USER)); // id 3
+
+        let mut reader = SseReader::new(Sse::new(realtime_sse_stream(&bus, Some(1), own_notifications)));
+        let (id, event, data) = reader.next_event().await;
+        assert_eq!((id, event.as_str()), (Some(3), "notification"));
+        assert_eq!(data["notification"]["id"], 3);
+
+        bus.publish(notification(4, OTHER_USER));
+        bus.publish(notification(5, USER));
+        let (id, _, data) = reader.next_event().await;
+        assert_eq!(id, Some(5));
+        assert_eq!(data["notification"]["user_id"], USER);
+    }
+
+    #[tokio::test]
+    async fn without_last_event_id_streams_only_new_events() {
+        let bus = RealtimeBus::new();
+        bus.publish(notification(1, USER));
+
+        let mut reader = SseReader::new(Sse::new(realtime_sse_stream(&bus, None, own_notifications)));
+        bus.publish(notification(2, USER));
+        let (id, event, _) = reader.next_event().await;
+        assert_eq!((id, event.as_str()), (Some(2), "notification"));
+    }
+
+    #[tokio::test]
+    async fn evicted_last_event_id_sends_reset_then_retained_events() {
+        let bus = RealtimeBus::new();
+        let published = REALTIME_REPLAY_CAPACITY as i64 + 5;
+        for id in 1..=published {
+            bus.publish(notification(id, USER));
+        }
+        // 버퍼에는 6..=1005 만 남아 있어 3, 4, 5 는 다시 보낼 수 없음
+        let mut reader = SseReader::new(Sse::new(realtime_sse_stream(&bus, Some(2), own_notifications)));
+
+        let (id, event, data) = reader.next_event().await;
+        assert_eq!((id, event.as_str()), (None, "reset"));
+        assert_eq!(data, json!({ "type": "reset", "reason": "replay_unavailable" }));
+
+        for expected in 6..=published as u64 {
+            let (id, event, _) = reader.next_event().await;
+            assert_eq!((id, event.as_str()), (Some(expected), "notification"));
+        }
+    }
+
+    #[tokio::test]
+    async fn unknown_last_event_id_sends_reset() {
+        let bus = RealtimeBus::new();
+        bus.publish(notification(1, USER));
+
+        // 재시작 전 서버가 준 id
+        let mut reader = SseReader::new(Sse::new(realtime_sse_stream(&bus, Some(42), own_notifications)));
+        let (_, event, data) = reader.next_event().await;
+        assert_eq!(event, "reset");
+        assert_eq!(data["reason"], "replay_unavailable");
+
+        bus.publish(notification(2, USER));
+        let (id, _, _) = reader.next_event().await;
+        assert_eq!(id, Some(2));
+    }
+
+    #[tokio::test]
+    async fn idle_stream_sends_heartbeat_comments() {
+        let bus = RealtimeBus::new();
+        let stream = realtime_sse_stream(&bus, None, own_notifications);
+        let mut reader = SseReader::new(Sse::new(stream).keep_alive(keep_alive(Duration::from_millis(20))));
+
+        assert_eq!(reader.next_frame().await, ": heartbeat");
+        assert_eq!(reader.next_frame().await, ": heartbeat");
+
+        // 하트비트 사이에도 이벤트는 그대로 전달
+        bus.publish(notification(1, USER));
+        let (id, event, _) = reader.next_event().await;
+        assert_eq!((id, event.as_str()), (Some(1), "notification"));
+    }
+}
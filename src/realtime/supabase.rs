@@ -0,0 +1,177 @@
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use crate::config::RealtimeConfig;
+use crate::realtime::{RealtimeBus, RealtimeEvent};
+use crate::utils::constants::{
+    REALTIME_CHANNEL_TOPIC, REALTIME_HEARTBEAT_SECS, REALTIME_RECONNECT_BASE_SECS, REALTIME_RECONNECT_MAX_SECS,
+};
+use crate::utils::log_realtime_event;
+
+type RealtimeResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+// Supabase Realtime 수신기 - Phoenix 채널(vsn 1.0.0 JSON)로 postgres_changes 를 구독해 내부 이벤트 버스로 전달
+// notifications INSERT, discount_infos INSERT/UPDATE 를 받는다 (migrations/enable_realtime.sql 필요).
+// 연결이 끊기면 지수 백오프로 다시 연결한다.
+pub struct SupabaseRealtimeClient {
+    config: RealtimeConfig,
+    bus: RealtimeBus,
+}
+
+impl SupabaseRealtimeClient {
+    pub fn new(config: RealtimeConfig, bus: RealtimeBus) -> Self {
+        Self { config, bus }
+    }
+
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut delay_secs = REALTIME_RECONNECT_BASE_SECS;
+            loop {
+                match self.run_session(&mut delay_secs).await {
+                    Ok(()) => log::warn!("📡 Supabase Realtime connection closed"),
+                    Err(e) => log::error!("❌ Supabase Realtime connection failed: {}", e),
+                }
+
+                log::info!("📡 Reconnecting to Supabase Realtime in {}s", delay_secs);
+                tokio::time::sleep(Duration::from_secs(delay_secs)).await;
+                delay_secs = (delay_secs * 2).min(REALTIME_RECONNECT_MAX_SECS);
+            }
+        })
+    }
+
+    // 연결 → 채널 참가 → heartbeat 를 보내며 변경 이벤트 수신 (참가에 성공하면 재연결 대기 시간 초기화)
+    async fn run_session(&self, delay_secs: &mut u64) -> RealtimeResult<()> {
+        let (socket, _) = connect_async(self.config.url.as_str()).await?;
+        let (mut write, mut read) = socket.split();
+
+        write.send(Message::Text(join_message(&self.config.access_token).to_string())).await?;
+
+        let mut heartbeat = tokio::time::interval(Duration::from_secs(REALTIME_HEARTBEAT_SECS));
+        heartbeat.tick().await;
+        let mut message_ref: u64 = 1;
+
+        loop {
+            tokio::select! {
+                _ = heartbeat.tick() => {
+                    message_ref += 1;
+                    write.send(Message::Text(heartbeat_message(message_ref).to_string())).await?;
+                }
+                message = read.next() => {
+                    let text = match message {
+                        Some(Ok(Message::Text(text))) => text,
+                        Some(Ok(Message::Close(_))) | None => return Ok(()),
+                        Some(Ok(_)) => continue,
+                        Some(Err(e)) => return Err(e.into()),
+                    };
+
+                    match parse_realtime_message(&text)? {
+                        RealtimeMessage::Joined => {
+                            log::info!("📡 Joined Supabase Realtime channel {}", REALTIME_CHANNEL_TOPIC);
+                            *delay_secs = REALTIME_RECONNECT_BASE_SECS;
+                        }
+                        RealtimeMessage::Event(event) => self.publish(*event),
+                        RealtimeMessage::Invalid(e) => log::warn!("📡 Skipping Realtime change: {}", e),
+                        RealtimeMessage::Ignored => {}
+                    }
+                }
+            }
+        }
+    }
+
+    fn publish(&self, event: RealtimeEvent) {
+        match &event {
+            RealtimeEvent::Notification { notification } => {
+                log_realtime_event("notification", &notification.user_id, Some(json!({ "id": notification.id })));
+            }
+            RealtimeEvent::DiscountUpdate { change, discount } => {
+                log::debug!("📡 Discount {} {} (product {})", discount.id, change, discount.product_id);
+            }
+        }
+        self.bus.publish(event);
+    }
+}
+
+// 수신 메시지 해석 결과
+#[derive(Debug)]
+pub enum RealtimeMessage {
+    Joined,
+    Event(Box<RealtimeEvent>),
+    Invalid(String), // 해석할 수 없는 변경 레코드 - 연결은 유지
+    Ignored,
+}
+
+// Phoenix 메시지 해석 - 채널 참가 실패/채널 오류는 에러 (재연결 대상)
+// 참가 요청의 ref 는 항상 "1" 이고 heartbeat 응답(topic phoenix)은 무시한다.
+pub fn parse_realtime_message(text: &str) -> Result<RealtimeMessage, String> {
+    let message: Value = serde_json::from_str(text).map_err(|e| format!("Invalid Realtime message: {}", e))?;
+    let topic = message["topic"].as_str().unwrap_or_default();
+    let payload = &message["payload"];
+
+    if topic != REALTIME_CHANNEL_TOPIC {
+        return Ok(RealtimeMessage::Ignored);
+    }
+
+    match message["event"].as_str().unwrap_or_default() {
+        "phx_reply" if message["ref"] == "1" => match payload["status"].as_str() {
+            Some("ok") => Ok(RealtimeMessage::Joined),
+            _ => Err(format!("Realtime channel join rejected: {}", payload["response"])),
+        },
+        "phx_error" | "phx_close" => Err(format!("Realtime channel closed: {}", payload)),
+        "system" if payload["status"] == "error" => Err(format!("Realtime system error: {}", payload["message"])),
+        "postgres_changes" => Ok(match parse_postgres_change(&payload["data"]) {
+            Ok(Some(event)) => RealtimeMessage::Event(Box::new(event)),
+            Ok(None) => RealtimeMessage::Ignored,
+            Err(e) => RealtimeMessage::Invalid(e),
+        }),
+        _ => Ok(RealtimeMessage::Ignored),
+    }
+}
+
+// postgres_changes 의 data.record 를 이벤트로 변환 (구독하지 않은 테이블/종류는 무시)
+pub fn parse_postgres_change(data: &Value) -> Result<Option<RealtimeEvent>, String> {
+    let change = data["type"].as_str().unwrap_or_default();
+    let record = data["record"].clone();
+
+    match (data["table"].as_str().unwrap_or_default(), change) {
+        ("notifications", "INSERT") => serde_json::from_value(record)
+            .map(|notification| Some(RealtimeEvent::Notification { notification }))
+            .map_err(|e| format!("Invalid notification record: {}", e)),
+        ("discount_infos", "INSERT" | "UPDATE") => serde_json::from_value(record)
+            .map(|discount| Some(RealtimeEvent::DiscountUpdate { change: change.to_string(), discount }))
+            .map_err(|e| format!("Invalid discount_infos record: {}", e)),
+        _ => Ok(None),
+    }
+}
+
+fn join_message(access_token: &str) -> Value {
+    json!({
+        "topic": REALTIME_CHANNEL_TOPIC,
+        "event": "phx_join",
+        "payload": {
+            "config": {
+                "broadcast": { "self": false },
+                "presence": { "key": "" },
+                "postgres_changes": [
+                    { "event": "INSERT", "schema": "public", "table": "notifications" },
+                    { "event": "INSERT", "schema": "public", "table": "discount_infos" },
+                    { "event": "UPDATE", "schema": "public", "table": "discount_infos" }
+                ]
+            },
+            "access_token": access_token
+        },
+        "ref": "1",
+        "join_ref": "1"
+    })
+}
+
+fn heartbeat_message(message_ref: u64) -> Value {
+    json!({
+        "topic": "phoenix",
+        "event": "heartbeat",
+        "payload": {},
+        "ref": message_ref.to_string()
+    })
+}
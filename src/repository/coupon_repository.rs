@@ -0,0 +1,104 @@
+use postgrest::Postgrest;
+use serde_json::json;
+
+use crate::domain::entities::coupon::{Coupon, CouponRedemptionResult};
+use crate::domain::dto::pagenation::{Pagenation, PagenationResult};
+use crate::repository::{fetch_count, fetch_page, CountMode};
+
+pub struct CouponRepository {
+    client: Postgrest,
+}
+
+impl CouponRepository {
+    pub fn new(client: Postgrest) -> Self {
+        Self { client }
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Option<Coupon>, Box<dyn std::error::Error>> {
+        let response = self.client
+            .from("coupons")
+            .select("*")
+            .eq("id", id.to_string())
+            .single()
+            .execute()
+            .await?;
+
+        if response.status().is_success() {
+            let text = response.text().await?;
+            let coupon: Coupon = serde_json::from_str(&text)?;
+            Ok(Some(coupon))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub async fn find_by_code(&self, code: &str) -> Result<Option<Coupon>, Box<dyn std::error::Error>> {
+        let response = self.client
+            .from("coupons")
+            .select("*")
+            .eq("code", code)
+            .single()
+            .execute()
+            .await?;
+
+        if response.status().is_success() {
+            let text = response.text().await?;
+            let coupon: Coupon = serde_json::from_str(&text)?;
+            Ok(Some(coupon))
+        } else {
+            Ok(None)
+        }
+    }
+
+    // 사용 가능한 쿠폰 목록 (활성 + 유효기간 내, 매장별 필터링 가능)
+    pub async fn find_active_paginated(&self, shop_id: Option<i64>, pagination: Pagenation) -> Result<PagenationResult<Coupon>, Box<dyn std::error::Error>> {
+        let now = chrono::Utc::now().to_rfc3339();
+
+        let mut query = self.client
+            .from("coupons")
+            .select("*")
+            .eq("is_active", "true")
+            .lte("start_date", &now)
+            .gte("end_date", &now);
+
+        if let Some(shop_id) = shop_id {
+            query = query.eq("shop_id", shop_id.to_string());
+        }
+
+        fetch_page(query.order("end_date.asc,id.asc"), &pagination, CountMode::Exact).await
+    }
+
+    // 사용자별 쿠폰 사용 횟수
+    pub async fn count_usages_by_user(&self, coupon_id: i64, user_id: &str) -> Result<u64, Box<dyn std::error::Error>> {
+        let query = self.client
+            .from("coupon_usages")
+            .select("id")
+            .eq("coupon_id", coupon_id.to_string())
+            .eq("user_id", user_id);
+
+        fetch_count(query, CountMode::Exact).await
+    }
+
+    // 쿠폰 사용 - redeem_coupon RPC로 한도 확인, 사용 기록, used_count 증가를 한 트랜잭션에서 처리
+    pub async fn redeem(&self, coupon_id: i64, user_id: &str, order_amount: f64, discount_amount: f64) -> Result<CouponRedemptionResult, Box<dyn std::error::Error>> {
+        let params = json!({
+            "p_coupon_id": coupon_id,
+            "p_user_id": user_id,
+            "p_order_amount": order_amount,
+            "p_discount_amount": discount_amount,
+        });
+
+        let response = self.client
+            .rpc("redeem_coupon", params.to_string())
+            .execute()
+            .await?;
+
+        if response.status().is_success() {
+            let text = response.text().await?;
+            let result: CouponRedemptionResult = serde_json::from_str(&text)?;
+            Ok(result)
+        } else {
+            Err(format!("Failed to redeem coupon: {}", response.status()).into())
+        }
+    }
+}
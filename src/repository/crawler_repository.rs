@@ -0,0 +1,302 @@
+use chrono::Utc;
+use postgrest::Postgrest;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::crawler::ScrapedProduct;
+use crate::domain::dto::pagenation::{Pagenation, PagenationResult};
+use crate::domain::entities::crawl::{CrawlPage, CrawlPageError, CrawlRun, CrawlRunStatus, CrawlSummary, CrawlTrigger, CrawledProductResult};
+use crate::domain::entities::discount::DiscountInfo;
+use crate::domain::entities::shop::Shop;
+use crate::repository::pagination::{fetch_page, CountMode};
+
+pub struct CrawlerRepository {
+    client: Postgrest,
+}
+
+#[derive(Deserialize)]
+struct CrawlTargetRow {
+    original_url: Option<String>,
+}
+
+impl CrawlerRepository {
+    pub fn new(client: Postgrest) -> Self {
+        Self { client }
+    }
+
+    // 다시 크롤링할 상품 페이지 - 삭제되지 않은 상품의 original_url
+    pub async fn find_crawl_targets(&self, shop_id: i64) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let response = self.client
+            .from("products")
+            .select("original_url")
+            .eq("shop_id", shop_id.to_string())
+            .eq("is_deleted", "false")
+            .not("is", "original_url", "null")
+            .order("id.asc")
+            .execute()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to get crawl targets: {}", response.status()).into());
+        }
+
+        let text = response.text().await?;
+        let rows: Vec<CrawlTargetRow> = serde_json::from_str(&text)?;
+        Ok(rows.into_iter().filter_map(|row| row.original_url).collect())
+    }
+
+    // 상품/자동 발견 할인 upsert (upsert_crawled_product RPC)
+    pub async fn upsert_crawled_product(&self, shop_id: i64, product: &ScrapedProduct, valid_days: u32) -> Result<CrawledProductResult, Box<dyn std::error::Error>> {
+        let params = json!({
+            "p_shop_id": shop_id,
+            "p_url": product.url,
+            "p_name": product.name,
+            "p_sku": product.sku,
+            "p_image_url": product.image_url,
+            "p_price": product.price,
+            "p_sale_price": product.sale_price,
+            "p_currency": product.currency,
+            "p_valid_days": valid_days,
+        });
+
+        let response = self.client
+            .rpc("upsert_crawled_product", params.to_string())
+            .execute()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to upsert crawled product: {}", response.status()).into());
+        }
+
+        let text = response.text().await?;
+        let result: CrawledProductResult = serde_json::from_str(&text)?;
+        Ok(result)
+    }
+
+    // upsert_crawled_product 가 바꾼 할인 (실시간 이벤트 발행용)
+    pub async fn find_discount(&self, discount_id: i64) -> Result<Option<DiscountInfo>, Box<dyn std::error::Error>> {
+        let response = self.client
+            .from("discount_infos")
+            .select("*")
+            .eq("id", discount_id.to_string())
+            .execute()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to get discount: {}", response.status()).into());
+        }
+
+        let text = response.text().await?;
+        let discounts: Vec<DiscountInfo> = serde_json::from_str(&text)?;
+        Ok(discounts.into_iter().next())
+    }
+
+    // 예약 크롤링 대상 - 도메인이 등록된 상점
+    pub async fn find_crawlable_shops(&self) -> Result<Vec<Shop>, Box<dyn std::error::Error>> {
+        let response = self.client
+            .from("shops")
+            .select("*")
+            .neq("domain", "")
+            .order("id.asc")
+            .execute()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to get crawlable shops: {}", response.status()).into());
+        }
+
+        let text = response.text().await?;
+        let shops: Vec<Shop> = serde_json::from_str(&text)?;
+        Ok(shops)
+    }
+
+    // 상점의 페이지별 검증자/추출 결과 (조건부 요청용)
+    pub async fn find_crawl_pages(&self, shop_id: i64) -> Result<Vec<CrawlPage>, Box<dyn std::error::Error>> {
+        let response = self.client
+            .from("crawl_pages")
+            .select("*")
+            .eq("shop_id", shop_id.to_string())
+            .execute()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to get crawl pages: {}", response.status()).into());
+        }
+
+        let text = response.text().await?;
+        let pages: Vec<CrawlPage> = serde_json::from_str(&text)?;
+        Ok(pages)
+    }
+
+    pub async fn save_crawl_page(&self, page: &CrawlPage) -> Result<(), Box<dyn std::error::Error>> {
+        let response = self.client
+            .from("crawl_pages")
+            .upsert(serde_json::to_string(page)?)
+            .on_conflict("shop_id,url")
+            .execute()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to save crawl page: {}", response.status()).into());
+        }
+
+        Ok(())
+    }
+
+    // 실행 기록 생성 (status = running)
+    // 상점에 이미 running 기록이 있으면 None (idx_crawl_runs_one_running 위반 → 409)
+    pub async fn create_crawl_run(&self, shop_id: i64, trigger: CrawlTrigger) -> Result<Option<CrawlRun>, Box<dyn std::error::Error>> {
+        let body = json!({
+            "shop_id": shop_id,
+            "trigger": trigger,
+            "status": CrawlRunStatus::Running,
+        });
+
+        let response = self.client
+            .from("crawl_runs")
+            .insert(body.to_string())
+            .execute()
+            .await?;
+
+        if response.status() == StatusCode::CONFLICT {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(format!("Failed to create crawl run: {}", response.status()).into());
+        }
+
+        let text = response.text().await?;
+        let created: Vec<CrawlRun> = serde_json::from_str(&text)?;
+        created.into_iter().next().map(Some).ok_or_else(|| "Crawl run insert returned no rows".into())
+    }
+
+    // 끝나지 않은 실행 기록(status = running)을 failed 로 정리 - 정리한 수 반환
+    pub async fn fail_running_crawl_runs(&self, failure: &str) -> Result<usize, Box<dyn std::error::Error>> {
+        let body = json!({
+            "status": CrawlRunStatus::Failed,
+            "finished_at": Utc::now(),
+            "failure": failure,
+        });
+
+        let response = self.client
+            .from("crawl_runs")
+            .eq("status", "running")
+            .update(body.to_string())
+            .execute()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to fail running crawl runs: {}", response.status()).into());
+        }
+
+        let text = response.text().await?;
+        let failed: Vec<CrawlRun> = serde_json::from_str(&text)?;
+        Ok(failed.len())
+    }
+
+    // 실행 종료 - 요약과 오류(앞의 error_limit 개) 기록
+    pub async fn finish_crawl_run(&self, run_id: i64, status: CrawlRunStatus, summary: &CrawlSummary, failure: Option<&str>, error_limit: usize) -> Result<(), Box<dyn std::error::Error>> {
+        let errors: Vec<&CrawlPageError> = summary.errors.iter().take(error_limit).collect();
+        let body = json!({
+            "status": status,
+            "finished_at": Utc::now(),
+            "pages_fetched": summary.pages_fetched,
+            "pages_not_modified": summary.pages_not_modified,
+            "pages_skipped": summary.pages_skipped,
+            "products_discovered": summary.products_discovered,
+            "products_upserted": summary.products_upserted,
+            "products_created": summary.products_created,
+            "discounts_changed": summary.discounts_changed,
+            "error_count": summary.errors.len(),
+            "errors": errors,
+            "failure": failure,
+        });
+
+        let response = self.client
+            .from("crawl_runs")
+            .eq("id", run_id.to_string())
+            .update(body.to_string())
+            .execute()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to finish crawl run: {}", response.status()).into());
+        }
+
+        Ok(())
+    }
+
+    // 상점의 실행 기록 (최신순)
+    pub async fn find_crawl_runs(&self, shop_id: i64, pagination: Pagenation) -> Result<PagenationResult<CrawlRun>, Box<dyn std::error::Error>> {
+        let query = self.client
+            .from("crawl_runs")
+            .select("*")
+            .eq("shop_id", shop_id.to_string())
+            .order("started_at.desc,id.desc");
+
+        fetch_page(query, &pagination, CountMode::Exact).await
+    }
+
+    pub async fn find_crawl_run(&self, run_id: i64) -> Result<Option<CrawlRun>, Box<dyn std::error::Error>> {
+        let response = self.client
+            .from("crawl_runs")
+            .select("*")
+            .eq("id", run_id.to_string())
+            .execute()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to get crawl run: {}", response.status()).into());
+        }
+
+        let text = response.text().await?;
+        let runs: Vec<CrawlRun> = serde_json::from_str(&text)?;
+        Ok(runs.into_iter().next())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::http::StatusCode as AxumStatus;
+    use axum::routing::post;
+
+    // crawl_runs insert 에 고정 응답을 돌려주는 PostgREST 대역
+    async fn repo_with_insert_response(status: u16, body: &'static str) -> CrawlerRepository {
+        let app = axum::Router::new().route("/crawl_runs", post(move || async move {
+            (AxumStatus::from_u16(status).unwrap(), body)
+        }));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        CrawlerRepository::new(Postgrest::new(url))
+    }
+
+    #[tokio::test]
+    async fn create_crawl_run_returns_none_while_shop_has_running_run() {
+        let repo = repo_with_insert_response(
+            409,
+            r#"{"code": "23505", "message": "duplicate key value violates unique constraint \"idx_crawl_runs_one_running\""}"#,
+        )
+        .await;
+
+        assert!(repo.create_crawl_run(7, CrawlTrigger::Manual).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn create_crawl_run_returns_inserted_run() {
+        let repo = repo_with_insert_response(
+            201,
+            r#"[{"id": 3, "shop_id": 7, "trigger": "scheduled", "status": "running", "started_at": "2026-01-15T04:00:00Z",
+                 "finished_at": null, "pages_fetched": 0, "pages_not_modified": 0, "pages_skipped": 0, "products_discovered": 0,
+                 "products_upserted": 0, "products_created": 0, "discounts_changed": 0, "error_count": 0, "errors": [], "failure": null}]"#,
+        )
+        .await;
+
+        let run = repo.create_crawl_run(7, CrawlTrigger::Scheduled).await.unwrap().unwrap();
+        assert_eq!((run.id, run.shop_id, run.status), (3, 7, CrawlRunStatus::Running));
+    }
+}
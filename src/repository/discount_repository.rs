@@ -1,8 +1,17 @@
-use postgrest::Postgrest;
-use serde_json::Value;
+use chrono::{DateTime, Duration, Utc};
+use postgrest::{Builder, Postgrest};
 
-use crate::domain::entities::discount::DiscountInfo;
-use crate::domain::dto::pagenation::{Pagenation, PagenationResult};
+use crate::domain::entities::discount::{DiscountDetail, DiscountListItem};
+use crate::domain::dto::pagenation::{CursorPagenation, CursorPagenationResult, Pagenation, PagenationResult, Sort};
+use crate::domain::dto::request::{DiscountFilter, DiscountPreset};
+use crate::utils::constants::{DISCOUNT_ENDING_SOON_HOURS, DISCOUNT_NEWLY_STARTED_HOURS};
+use crate::repository::{apply_keyset, fetch_page, CountMode};
+
+const DISCOUNT_DETAIL_SELECT: &str = "*,\
+    product:products!product_id(*,translations:product_translations(locale,name,description)),\
+    shop:shops!shop_id(*),\
+    brand:brands!brand_id(*),\
+    translations:discount_info_translations(locale,description,terms_conditions)";
 
 pub struct DiscountRepository {
     client: Postgrest,
@@ -13,72 +22,127 @@ impl DiscountRepository {
         Self { client }
     }
 
-    pub async fn find_by_id(&self, id: i64) -> Result<Option<DiscountInfo>, Box<dyn std::error::Error>> {
+    // 할인 상세 - 상품(번역 포함)/매장/브랜드/번역을 한 번에 임베딩, 번역은 요청 언어만
+    pub async fn find_detail(&self, id: i64, locale: &str) -> Result<Option<DiscountDetail>, Box<dyn std::error::Error>> {
         let response = self.client
             .from("discount_infos")
-            .select("*")
-            .eq("id", &id.to_string())
+            .select(DISCOUNT_DETAIL_SELECT)
+            .eq("id", id.to_string())
+            .eq("translations.locale", locale)
+            .eq("product.translations.locale", locale)
             .single()
             .execute()
             .await?;
 
         if response.status().is_success() {
             let text = response.text().await?;
-            let discount: DiscountInfo = serde_json::from_str(&text)?;
+            let discount: DiscountDetail = serde_json::from_str(&text)?;
             Ok(Some(discount))
         } else {
             Ok(None)
         }
     }
 
-    pub async fn find_all_paginated(&self, pagination: Pagenation) -> Result<PagenationResult<DiscountInfo>, Box<dyn std::error::Error>> {
-        let offset = (pagination.page - 1) * pagination.limit;
-
-        // 데이터 조회
-        let response = self.client
+    // 할인 목록 (필터 + 프리셋 + 임베딩)
+    pub async fn find_filtered(&self, filter: &DiscountFilter, sort: &Sort, pagination: Pagenation) -> Result<PagenationResult<DiscountListItem>, Box<dyn std::error::Error>> {
+        let query = self.client
             .from("discount_infos")
-            .select("*")
-            .order("created_at.desc")
-            .range(offset as usize, (offset + pagination.limit - 1) as usize)
-            .execute()
-            .await?;
+            .select(discount_select(filter));
 
-        let discounts: Vec<DiscountInfo> = if response.status().is_success() {
-            let text = response.text().await?;
-            serde_json::from_str(&text)?
-        } else {
-            Vec::new()
-        };
+        let query = apply_discount_filter(query, filter, Utc::now())
+            .order(sort.to_postgrest_order());
+
+        // 변경이 잦은 큰 테이블이므로 추정 개수 사용
+        fetch_page(query, &pagination, CountMode::Estimated).await
+    }
 
-        // 전체 개수 조회
-        let count_response = self.client
+    // 키셋 페이지네이션 - (created_at, id) 커서 기준 조회, count 쿼리 없음
+    pub async fn find_by_cursor(&self, filter: &DiscountFilter, pagination: &CursorPagenation) -> Result<CursorPagenationResult<DiscountListItem>, Box<dyn std::error::Error>> {
+        let query = self.client
             .from("discount_infos")
-            .select("count")
+            .select(discount_select(filter));
+
+        let response = apply_keyset(apply_discount_filter(query, filter, Utc::now()), pagination)
             .execute()
             .await?;
 
-        let total: u64 = if count_response.status().is_success() {
-            let text = count_response.text().await?;
-            let count_result: Value = serde_json::from_str(&text)?;
-            count_result.as_array()
-                .and_then(|arr| arr.get(0))
-                .and_then(|obj| obj.get("count"))
-                .and_then(|c| c.as_u64())
-                .unwrap_or(0)
-        } else {
-            0
-        };
-
-        let total_pages = (total as f64 / pagination.limit as f64).ceil() as u32;
-
-        Ok(PagenationResult {
-            data: discounts,
-            total,
-            page: pagination.page,
-            limit: pagination.limit,
-            total_pages,
-            has_next: pagination.page < total_pages,
-            has_prev: pagination.page > 1,
-        })
-    }
-}
\ No newline at end of file
+        if !response.status().is_success() {
+            return Err(format!("Failed to get discounts: {}", response.status()).into());
+        }
+
+        let text = response.text().await?;
+        let discounts: Vec<DiscountListItem> = serde_json::from_str(&text)?;
+        Ok(CursorPagenationResult::from_rows(discounts, pagination))
+    }
+}
+
+// 할인 목록 select 절 - 요청한 관계만 임베딩
+pub fn discount_select(filter: &DiscountFilter) -> String {
+    let mut columns = vec!["*"];
+    if filter.embed.product {
+        columns.push("product:products(*)");
+    }
+    if filter.embed.shop {
+        columns.push("shop:shops(*)");
+    }
+    if filter.embed.brand {
+        columns.push("brand:brands(*)");
+    }
+    // discount_infos에는 category_id가 없으므로 상품을 inner join 해서 필터링 (응답 역직렬화 시 무시됨)
+    if filter.category_id.is_some() {
+        columns.push("category_scope:products!inner(category_id)");
+    }
+    columns.join(",")
+}
+
+// 할인 목록 필터 적용 - 프리셋은 진행 중(is_active + 기간 내)인 할인으로 한정
+pub fn apply_discount_filter(mut query: Builder, filter: &DiscountFilter, now: DateTime<Utc>) -> Builder {
+    if let Some(shop_id) = filter.shop_id {
+        query = query.eq("shop_id", shop_id.to_string());
+    }
+    if let Some(brand_id) = filter.brand_id {
+        query = query.eq("brand_id", brand_id.to_string());
+    }
+    if let Some(category_id) = filter.category_id {
+        query = query.eq("category_scope.category_id", category_id.to_string());
+    }
+    if let Some(min_rate) = filter.min_discount_rate {
+        query = query.gte("discount_rate", min_rate.to_string());
+    }
+    if let Some(max_rate) = filter.max_discount_rate {
+        query = query.lte("discount_rate", max_rate.to_string());
+    }
+    if let Some(currency) = &filter.currency {
+        query = query.eq("currency", currency);
+    }
+    if let Some(is_active) = filter.is_active {
+        query = query.eq("is_active", is_active.to_string());
+    }
+    // 기간 필터는 할인 기간이 [start_date, end_date]와 겹치는지로 판단
+    if let Some(start_date) = filter.start_date {
+        query = query.gte("end_at", start_date.to_rfc3339());
+    }
+    if let Some(end_date) = filter.end_date {
+        query = query.lte("start_at", end_date.to_rfc3339());
+    }
+
+    match filter.preset {
+        Some(DiscountPreset::EndingSoon) => {
+            let until = now + Duration::hours(DISCOUNT_ENDING_SOON_HOURS);
+            query
+                .eq("is_active", "true")
+                .lte("start_at", now.to_rfc3339())
+                .gte("end_at", now.to_rfc3339())
+                .lte("end_at", until.to_rfc3339())
+        }
+        Some(DiscountPreset::NewlyStarted) => {
+            let since = now - Duration::hours(DISCOUNT_NEWLY_STARTED_HOURS);
+            query
+                .eq("is_active", "true")
+                .gte("start_at", since.to_rfc3339())
+                .lte("start_at", now.to_rfc3339())
+                .gte("end_at", now.to_rfc3339())
+        }
+        None => query,
+    }
+}
@@ -2,10 +2,20 @@ pub mod shop_repository;
 pub mod product_repository;
 pub mod discount_repository;
 pub mod user_repository;
+pub mod coupon_repository;
+pub mod notification_repository;
+pub mod notification_queue_repository;
+pub mod crawler_repository;
+pub mod pagination;
 pub mod repository_factory;
 
 pub use shop_repository::*;
 pub use product_repository::*;
 pub use discount_repository::*;
 pub use user_repository::*;
+pub use coupon_repository::*;
+pub use notification_repository::*;
+pub use notification_queue_repository::*;
+pub use crawler_repository::*;
+pub use pagination::*;
 pub use repository_factory::*;
\ No newline at end of file
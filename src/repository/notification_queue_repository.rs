@@ -0,0 +1,225 @@
+use sqlx::PgPool;
+
+use crate::domain::entities::notification::{DeliveryOutcome, QueuedNotification};
+
+// 알림 발송 큐 - PostgREST로는 행 잠금을 걸 수 없으므로 DATABASE_URL 직접 연결(sqlx) 사용
+pub struct NotificationQueueRepository {
+    pool: PgPool,
+}
+
+impl NotificationQueueRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // 발송할 항목 클레임 - 여러 워커가 동시에 실행해도 SKIP LOCKED로 같은 행을 가져가지 않음
+    // 클레임한 행은 processing 상태로 lock_secs 동안 숨겨지고, 그 안에 결과가 기록되지 않으면 다시 클레임된다.
+    // 마지막 시도에서 결과 없이 클레임이 만료된 행은 다시 보내지 않고 failed 로 바꾸고 발송 로그를 남긴다.
+    // 사용자의 채널 방해 금지 시간에 걸린 행은 시도 횟수를 늘리지 않고 방해 금지 시간이 끝날 때로 미룬다.
+    pub async fn claim(&self, limit: u32, lock_secs: u64) -> Result<Vec<QueuedNotification>, Box<dyn std::error::Error>> {
+        let items = sqlx::query_as::<_, QueuedNotification>(
+            r#"
+            WITH due AS (
+                SELECT id, attempts >= max_attempts AS exhausted,
+                       notification_quiet_until(user_id, channel, NOW()) AS quiet_until
+                FROM notification_queue
+                WHERE status IN ('pending', 'processing')
+                  AND next_attempt_at <= NOW()
+                ORDER BY next_attempt_at, id
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            ),
+            failed AS (
+                UPDATE notification_queue q
+                SET status = 'failed',
+                    last_error = CASE
+                        WHEN q.status = 'processing' THEN 'Claim expired without a delivery result'
+                        ELSE COALESCE(q.last_error, 'Max attempts reached')
+                    END,
+                    updated_at = NOW()
+                FROM due
+                WHERE q.id = due.id AND due.exhausted
+                RETURNING q.id, q.notification_id, q.user_id, q.channel, q.attempts, q.last_error
+            ),
+            failed_logs AS (
+                INSERT INTO notification_logs
+                    (queue_id, user_id, notification_type, channel, title, content, status, attempt, error_message)
+                SELECT f.id, f.user_id, n.type, f.channel, COALESCE(n.title, ''), n.message, 'failed', f.attempts, f.last_error
+                FROM failed f
+                JOIN notifications n ON n.id = f.notification_id
+                RETURNING id
+            ),
+            deferred AS (
+                UPDATE notification_queue q
+                SET status = 'pending',
+                    next_attempt_at = due.quiet_until,
+                    updated_at = NOW()
+                FROM due
+                WHERE q.id = due.id AND NOT due.exhausted AND due.quiet_until IS NOT NULL
+                RETURNING q.id
+            ),
+            picked AS (
+                SELECT id FROM due WHERE NOT exhausted AND quiet_until IS NULL
+            )
+            UPDATE notification_queue q
+            SET status = 'processing',
+                attempts = q.attempts + 1,
+                next_attempt_at = NOW() + make_interval(secs => $2),
+                updated_at = NOW()
+            FROM picked, notifications n
+            WHERE q.id = picked.id AND n.id = q.notification_id
+            RETURNING q.id, q.notification_id, q.user_id::text AS user_id, q.channel, q.attempts, q.max_attempts,
+                      n.type AS notification_type, COALESCE(n.title, '') AS title, n.message AS content, n.data,
+                      (SELECT u.email::text FROM auth.users u WHERE u.id = q.user_id) AS email,
+                      COALESCE((
+                          SELECT jsonb_agg(jsonb_build_object('endpoint', ps.endpoint, 'p256dh', ps.p256dh, 'auth', ps.auth))
+                          FROM push_subscriptions ps
+                          WHERE ps.user_id = q.user_id
+                      ), '[]'::jsonb) AS push_subscriptions
+            "#,
+        )
+        .bind(limit as i64)
+        .bind(lock_secs as f64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(items)
+    }
+
+    // 발송 결과 반영 - 큐 상태 변경과 발송 로그 기록을 한 트랜잭션에서 처리
+    // 이 시도의 클레임이 만료되어 다른 워커가 다시 클레임했다면 그 결과를 덮어쓰지 않고 false
+    pub async fn complete(&self, item: &QueuedNotification, outcome: &DeliveryOutcome) -> Result<bool, Box<dyn std::error::Error>> {
+        let mut tx = self.pool.begin().await?;
+
+        let (status, delay_secs) = match outcome {
+            DeliveryOutcome::Sent => ("sent", 0),
+            DeliveryOutcome::Retry { delay_secs, .. } => ("pending", *delay_secs),
+            DeliveryOutcome::Failed { .. } => ("failed", 0),
+        };
+
+        let updated = sqlx::query(
+            r#"
+            UPDATE notification_queue
+            SET status = $2,
+                last_error = $3,
+                next_attempt_at = NOW() + make_interval(secs => $4),
+                updated_at = NOW()
+            WHERE id = $1
+              AND status = 'processing'
+              AND attempts = $5
+            "#,
+        )
+        .bind(item.id)
+        .bind(status)
+        .bind(outcome.error())
+        .bind(delay_secs as f64)
+        .bind(item.attempts)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        if updated == 0 {
+            tx.rollback().await?;
+            return Ok(false);
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO notification_logs
+                (queue_id, user_id, notification_type, channel, title, content, status, attempt, error_message, sent_at)
+            VALUES ($1, $2::uuid, $3, $4, $5, $6, $7, $8, $9, CASE WHEN $7 = 'sent' THEN NOW() END)
+            "#,
+        )
+        .bind(item.id)
+        .bind(&item.user_id)
+        .bind(&item.notification_type)
+        .bind(&item.channel)
+        .bind(&item.title)
+        .bind(&item.content)
+        .bind(outcome.log_status())
+        .bind(item.attempts)
+        .bind(outcome.error())
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::postgres::PgPoolOptions;
+
+    // notification_quiet_until(): profiles.timezone 기준, start > end 면 자정을 넘기는 구간
+    // TEST_DATABASE_URL 이 없으면 건너뜀 (create_basic_tables.sql, reconcile_notification_settings.sql 적용 필요)
+    #[tokio::test]
+    async fn quiet_until_follows_profile_timezone_across_midnight() {
+        let Ok(database_url) = std::env::var("TEST_DATABASE_URL") else {
+            eprintln!("TEST_DATABASE_URL not set, skipping");
+            return;
+        };
+        let pool = PgPoolOptions::new().max_connections(2).connect(&database_url).await.unwrap();
+
+        let new_york = uuid::Uuid::new_v4().to_string();
+        let no_profile = uuid::Uuid::new_v4().to_string();
+        for (user_id, quiet_hours) in [
+            (&new_york, r#"{"push": {"start": "22:00", "end": "08:00"}}"#),
+            (&no_profile, r#"{"push": {"start": "09:00", "end": "18:00"}}"#),
+        ] {
+            sqlx::query("INSERT INTO auth.users (id, email) VALUES ($1::uuid, 'quiet-hours@example.com')")
+                .bind(user_id)
+                .execute(&pool)
+                .await
+                .unwrap();
+            sqlx::query("INSERT INTO notification_settings (user_id, quiet_hours) VALUES ($1::uuid, $2::jsonb)")
+                .bind(user_id)
+                .bind(quiet_hours)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+        sqlx::query("INSERT INTO profiles (id, user_id, timezone) VALUES ($1::uuid, $1::uuid, 'America/New_York')")
+            .bind(&new_york)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let mut results = Vec::new();
+        for (user_id, channel, now) in [
+            (&new_york, "push", "2026-01-15T04:00:00Z"),   // 뉴욕 23:00 → 다음날 08:00
+            (&new_york, "push", "2026-01-15T12:00:00Z"),   // 뉴욕 07:00 → 같은 날 08:00
+            (&new_york, "push", "2026-01-15T14:00:00Z"),   // 뉴욕 09:00 → 방해 금지 아님
+            (&new_york, "push", "2026-01-15T23:00:00Z"),   // UTC 로는 구간이지만 뉴욕 18:00
+            (&new_york, "email", "2026-01-15T04:00:00Z"),  // 구간이 없는 채널
+            (&no_profile, "push", "2026-01-15T10:00:00Z"), // 프로필 없으면 UTC
+        ] {
+            let until: Option<String> = sqlx::query_scalar(
+                "SELECT to_char(notification_quiet_until($1::uuid, $2, $3::timestamptz) AT TIME ZONE 'UTC', 'YYYY-MM-DD\"T\"HH24:MI:SS\"Z\"')",
+            )
+            .bind(user_id)
+            .bind(channel)
+            .bind(now)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+            results.push(until);
+        }
+
+        sqlx::query("DELETE FROM auth.users WHERE id IN ($1::uuid, $2::uuid)")
+            .bind(&new_york)
+            .bind(&no_profile)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(results, vec![
+            Some("2026-01-15T13:00:00Z".to_string()),
+            Some("2026-01-15T13:00:00Z".to_string()),
+            None,
+            None,
+            None,
+            Some("2026-01-15T18:00:00Z".to_string()),
+        ]);
+    }
+}
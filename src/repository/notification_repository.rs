@@ -0,0 +1,306 @@
+use chrono::{DateTime, Utc};
+use postgrest::{Builder, Postgrest};
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+
+use crate::domain::entities::notification::{Notification, NotificationSettings, PriceDropAlert, PushSubscription};
+use crate::domain::dto::pagenation::{CursorPagenation, CursorPagenationResult, Pagenation, PagenationResult, Sort};
+use crate::repository::{apply_keyset, fetch_count, fetch_page, CountMode};
+
+pub struct NotificationRepository {
+    client: Postgrest,
+}
+
+impl NotificationRepository {
+    pub fn new(client: Postgrest) -> Self {
+        Self { client }
+    }
+
+    // 사용자 알림 목록 (is_read 가 주어지면 읽음/안읽음 필터)
+    pub async fn find_paginated(&self, user_id: &str, is_read: Option<bool>, sort: &Sort, pagination: Pagenation) -> Result<PagenationResult<Notification>, Box<dyn std::error::Error>> {
+        let query = self.user_notifications(user_id, is_read)
+            .order(sort.to_postgrest_order());
+
+        fetch_page(query, &pagination, CountMode::Exact).await
+    }
+
+    // 키셋 페이지네이션 - (created_at, id) 커서 기준 조회, count 쿼리 없음
+    pub async fn find_by_cursor(&self, user_id: &str, is_read: Option<bool>, pagination: &CursorPagenation) -> Result<CursorPagenationResult<Notification>, Box<dyn std::error::Error>> {
+        let response = apply_keyset(self.user_notifications(user_id, is_read), pagination)
+            .execute()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to get notifications: {}", response.status()).into());
+        }
+
+        let text = response.text().await?;
+        let notifications: Vec<Notification> = serde_json::from_str(&text)?;
+        Ok(CursorPagenationResult::from_rows(notifications, pagination))
+    }
+
+    pub async fn count_unread(&self, user_id: &str) -> Result<u64, Box<dyn std::error::Error>> {
+        let query = self.client
+            .from("notifications")
+            .select("id")
+            .eq("user_id", user_id)
+            .eq("is_read", "false");
+
+        fetch_count(query, CountMode::Exact).await
+    }
+
+    pub async fn create(&self, user_id: &str, notification_type: &str, title: &str, content: &str, data: Option<Value>) -> Result<Notification, Box<dyn std::error::Error>> {
+        let body = json!({
+            "user_id": user_id,
+            "type": notification_type,
+            "title": title,
+            "message": content,
+            "data": data,
+        });
+
+        let response = self.client
+            .from("notifications")
+            .insert(body.to_string())
+            .execute()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to create notification: {}", response.status()).into());
+        }
+
+        let text = response.text().await?;
+        let created: Vec<Notification> = serde_json::from_str(&text)?;
+        created.into_iter().next().ok_or_else(|| "Notification insert returned no rows".into())
+    }
+
+    // 채널별 발송 큐 등록 (실제 발송은 NotificationQueueService 워커가 처리)
+    pub async fn enqueue(&self, notification: &Notification, channels: &[&str]) -> Result<(), Box<dyn std::error::Error>> {
+        if channels.is_empty() {
+            return Ok(());
+        }
+
+        let rows: Vec<Value> = channels
+            .iter()
+            .map(|channel| json!({
+                "notification_id": notification.id,
+                "user_id": notification.user_id,
+                "channel": channel,
+            }))
+            .collect();
+
+        let response = self.client
+            .from("notification_queue")
+            .insert(Value::Array(rows).to_string())
+            .execute()
+            .await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(format!("Failed to enqueue notification: {}", response.status()).into())
+        }
+    }
+
+    pub async fn find_settings(&self, user_id: &str) -> Result<Option<NotificationSettings>, Box<dyn std::error::Error>> {
+        let response = self.client
+            .from("notification_settings")
+            .select("*")
+            .eq("user_id", user_id)
+            .execute()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to get notification settings: {}", response.status()).into());
+        }
+
+        let text = response.text().await?;
+        let settings: Vec<NotificationSettings> = serde_json::from_str(&text)?;
+        Ok(settings.into_iter().next())
+    }
+
+    // 설정 저장 (사용자당 한 행 upsert, created_at/updated_at은 DB에서 관리)
+    pub async fn upsert_settings(&self, settings: &NotificationSettings) -> Result<NotificationSettings, Box<dyn std::error::Error>> {
+        let body = json!({
+            "user_id": settings.user_id,
+            "push_enabled": settings.push_enabled,
+            "email_enabled": settings.email_enabled,
+            "sms_enabled": settings.sms_enabled,
+            "discount_updates": settings.discount_updates,
+            "subscription_changes": settings.subscription_changes,
+            "quiet_hours": settings.quiet_hours,
+        });
+
+        let response = self.client
+            .from("notification_settings")
+            .upsert(body.to_string())
+            .on_conflict("user_id")
+            .execute()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to save notification settings: {}", response.status()).into());
+        }
+
+        let text = response.text().await?;
+        let saved: Vec<NotificationSettings> = serde_json::from_str(&text)?;
+        saved.into_iter().next().ok_or_else(|| "Notification settings upsert returned no rows".into())
+    }
+
+    // Web Push 구독 등록 - 본인 구독이면 키 갱신, 없으면 추가
+    // endpoint 가 다른 사용자의 구독이면 false (다른 사용자의 구독을 가져갈 수 없음)
+    pub async fn upsert_push_subscription(&self, user_id: &str, subscription: &PushSubscription, user_agent: Option<&str>) -> Result<bool, Box<dyn std::error::Error>> {
+        if self.update_push_subscription(user_id, subscription, user_agent).await? {
+            return Ok(true);
+        }
+
+        let body = json!({
+            "user_id": user_id,
+            "endpoint": subscription.endpoint,
+            "p256dh": subscription.p256dh,
+            "auth": subscription.auth,
+            "user_agent": user_agent,
+        });
+
+        let response = self.client
+            .from("push_subscriptions")
+            .insert(body.to_string())
+            .execute()
+            .await?;
+
+        match response.status() {
+            status if status.is_success() => Ok(true),
+            // endpoint UNIQUE 충돌 - 동시에 본인이 등록한 경우만 갱신으로 처리
+            StatusCode::CONFLICT => self.update_push_subscription(user_id, subscription, user_agent).await,
+            status => Err(format!("Failed to save push subscription: {}", status).into()),
+        }
+    }
+
+    async fn update_push_subscription(&self, user_id: &str, subscription: &PushSubscription, user_agent: Option<&str>) -> Result<bool, Box<dyn std::error::Error>> {
+        let body = json!({
+            "p256dh": subscription.p256dh,
+            "auth": subscription.auth,
+            "user_agent": user_agent,
+            "updated_at": chrono::Utc::now(),
+        });
+
+        let query = self.client
+            .from("push_subscriptions")
+            .eq("user_id", user_id)
+            .eq("endpoint", &subscription.endpoint)
+            .update(body.to_string());
+
+        Ok(Self::execute_returning_ids(query, "update push subscription").await? > 0)
+    }
+
+    // Web Push 구독 해제 - 본인 구독이 아니거나 없으면 false
+    pub async fn delete_push_subscription(&self, user_id: &str, endpoint: &str) -> Result<bool, Box<dyn std::error::Error>> {
+        let query = self.client
+            .from("push_subscriptions")
+            .eq("user_id", user_id)
+            .eq("endpoint", endpoint)
+            .delete();
+
+        Ok(Self::execute_returning_ids(query, "delete push subscription").await? > 0)
+    }
+
+    // 읽음 처리 - 본인 알림이 아니거나 없으면 false
+    pub async fn mark_read(&self, user_id: &str, id: i64) -> Result<bool, Box<dyn std::error::Error>> {
+        let query = self.client
+            .from("notifications")
+            .eq("id", id.to_string())
+            .eq("user_id", user_id)
+            .update(json!({ "is_read": true }).to_string());
+
+        Ok(Self::execute_returning_ids(query, "mark notification as read").await? > 0)
+    }
+
+    // 안읽은 알림 전체 읽음 처리 - 변경된 개수 반환
+    pub async fn mark_all_read(&self, user_id: &str) -> Result<u64, Box<dyn std::error::Error>> {
+        let query = self.client
+            .from("notifications")
+            .eq("user_id", user_id)
+            .eq("is_read", "false")
+            .update(json!({ "is_read": true }).to_string());
+
+        Self::execute_returning_ids(query, "mark all notifications as read").await
+    }
+
+    // 삭제 - 본인 알림이 아니거나 없으면 false
+    pub async fn delete(&self, user_id: &str, id: i64) -> Result<bool, Box<dyn std::error::Error>> {
+        let query = self.client
+            .from("notifications")
+            .eq("id", id.to_string())
+            .eq("user_id", user_id)
+            .delete();
+
+        Ok(Self::execute_returning_ids(query, "delete notification").await? > 0)
+    }
+
+    // 가격 인하 알림 대상 가져오기 - claim_price_drop_alerts RPC가 변경 이벤트 처리, 사용자별 중복 제거, 재시도 대상 클레임을 한 트랜잭션에서 수행
+    pub async fn claim_price_drop_alerts(&self, limit: u32, lock_secs: u64, max_attempts: u32) -> Result<Vec<PriceDropAlert>, Box<dyn std::error::Error>> {
+        let params = json!({ "p_limit": limit, "p_lock_secs": lock_secs, "p_max_attempts": max_attempts });
+
+        let response = self.client
+            .rpc("claim_price_drop_alerts", params.to_string())
+            .execute()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to claim price drop alerts: {}", response.status()).into());
+        }
+
+        let text = response.text().await?;
+        let alerts: Vec<PriceDropAlert> = serde_json::from_str(&text)?;
+        Ok(alerts)
+    }
+
+    // 가격 인하 알림 생성 완료 - 클레임된 발송 기록을 sent 로 변경
+    pub async fn complete_price_drop_alert(&self, alert: &PriceDropAlert, notification_id: i64) -> Result<bool, Box<dyn std::error::Error>> {
+        let query = self.price_drop_delivery(alert)
+            .update(json!({ "status": "sent", "notification_id": notification_id, "last_error": null }).to_string());
+
+        Ok(Self::execute_returning_ids(query, "complete price drop alert").await? > 0)
+    }
+
+    // 가격 인하 알림 생성 실패 - retry_at 이후 매처 실행 때 다시 클레임되도록 pending 으로 되돌림
+    pub async fn release_price_drop_alert(&self, alert: &PriceDropAlert, error: &str, retry_at: DateTime<Utc>) -> Result<bool, Box<dyn std::error::Error>> {
+        let query = self.price_drop_delivery(alert)
+            .update(json!({ "status": "pending", "next_attempt_at": retry_at, "last_error": error }).to_string());
+
+        Ok(Self::execute_returning_ids(query, "release price drop alert").await? > 0)
+    }
+
+    fn price_drop_delivery(&self, alert: &PriceDropAlert) -> Builder {
+        self.client
+            .from("price_drop_alert_deliveries")
+            .eq("user_id", &alert.user_id)
+            .eq("discount_info_id", alert.discount_info_id.to_string())
+            .eq("status", "processing")
+    }
+
+    fn user_notifications(&self, user_id: &str, is_read: Option<bool>) -> Builder {
+        let mut query = self.client
+            .from("notifications")
+            .select("*")
+            .eq("user_id", user_id);
+
+        if let Some(is_read) = is_read {
+            query = query.eq("is_read", is_read.to_string());
+        }
+
+        query
+    }
+
+    // 변경/삭제 요청을 id만 돌려받도록 실행하고 영향받은 행 수 반환
+    async fn execute_returning_ids(query: Builder, action: &str) -> Result<u64, Box<dyn std::error::Error>> {
+        let response = query.select("id").execute().await?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to {}: {}", action, response.status()).into());
+        }
+
+        let text = response.text().await?;
+        let rows: Vec<Value> = serde_json::from_str(&text)?;
+        Ok(rows.len() as u64)
+    }
+}
@@ -0,0 +1,197 @@
+use chrono::SecondsFormat;
+use postgrest::Builder;
+use reqwest::header::CONTENT_RANGE;
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+
+use crate::domain::dto::pagenation::{CursorDirection, CursorPagenation, Pagenation, PagenationResult};
+
+// 전체 개수 계산 방식 (PostgREST `Prefer: count=...`)
+// - Exact: COUNT(*) 실행, 정확하지만 큰 테이블에서 느림
+// - Planned: 쿼리 플래너 통계 기반 추정치
+// - Estimated: db-max-rows 이하면 정확한 값, 그 이상이면 플래너 추정치
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CountMode {
+    Exact,
+    Planned,
+    Estimated,
+}
+
+impl CountMode {
+    fn apply(self, builder: Builder) -> Builder {
+        match self {
+            CountMode::Exact => builder.exact_count(),
+            CountMode::Planned => builder.planned_count(),
+            CountMode::Estimated => builder.estimated_count(),
+        }
+    }
+}
+
+// 페이지 데이터와 전체 개수를 한 번의 요청으로 조회 - 전체 개수는 Content-Range 헤더("0-19/123")에서 읽는다
+pub async fn fetch_page<T: DeserializeOwned>(builder: Builder, pagination: &Pagenation, mode: CountMode) -> Result<PagenationResult<T>, Box<dyn std::error::Error>> {
+    let offset = (pagination.page - 1) * pagination.limit;
+
+    // count 설정이 Range 헤더를 0-0으로 덮어쓰므로 range는 반드시 그 뒤에 지정
+    let response = mode
+        .apply(builder)
+        .range(offset as usize, (offset + pagination.limit - 1) as usize)
+        .execute()
+        .await?;
+
+    let status = response.status();
+    let total = response
+        .headers()
+        .get(CONTENT_RANGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_content_range_total);
+
+    // 마지막 페이지를 넘어선 요청은 416 + "*/123" - 빈 페이지로 처리
+    if status == StatusCode::RANGE_NOT_SATISFIABLE {
+        let total = total.ok_or("Missing total in Content-Range header")?;
+        return Ok(PagenationResult::new(Vec::new(), total, pagination));
+    }
+
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Request failed: {} {}", status, body).into());
+    }
+
+    let total = total.ok_or("Missing total in Content-Range header")?;
+    let text = response.text().await?;
+    let data: Vec<T> = serde_json::from_str(&text)?;
+
+    Ok(PagenationResult::new(data, total, pagination))
+}
+
+// 행 데이터 없이 개수만 조회 (Range 0-0, Content-Range의 전체 개수 사용)
+pub async fn fetch_count(builder: Builder, mode: CountMode) -> Result<u64, Box<dyn std::error::Error>> {
+    let response = mode.apply(builder).execute().await?;
+
+    let status = response.status();
+    if !status.is_success() && status != StatusCode::RANGE_NOT_SATISFIABLE {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Count request failed: {} {}", status, body).into());
+    }
+
+    let total = response
+        .headers()
+        .get(CONTENT_RANGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_content_range_total)
+        .ok_or("Missing total in Content-Range header")?;
+
+    Ok(total)
+}
+
+// "0-19/123", "*/0" 형식에서 전체 개수 추출 (count를 요청하지 않으면 "0-19/*")
+pub fn parse_content_range_total(value: &str) -> Option<u64> {
+    value.rsplit_once('/')?.1.trim().parse().ok()
+}
+
+// 키셋 조건 적용 - (created_at, id) 기준으로 커서 이후/이전 행을 limit + 1건 조회
+// Prev 방향은 오름차순으로 가져오므로 CursorPagenationResult::from_rows에서 다시 뒤집는다.
+pub fn apply_keyset(builder: Builder, pagination: &CursorPagenation) -> Builder {
+    let builder = match &pagination.cursor {
+        None => builder.order("created_at.desc,id.desc"),
+        Some(cursor) => {
+            let created_at = cursor.created_at.to_rfc3339_opts(SecondsFormat::Micros, true);
+            let (op, order) = match cursor.direction {
+                CursorDirection::Next => ("lt", "created_at.desc,id.desc"),
+                CursorDirection::Prev => ("gt", "created_at.asc,id.asc"),
+            };
+
+            builder
+                .or(format!(
+                    "created_at.{op}.\"{created_at}\",and(created_at.eq.\"{created_at}\",id.{op}.{id})",
+                    op = op,
+                    created_at = created_at,
+                    id = cursor.id,
+                ))
+                .order(order)
+        }
+    };
+
+    builder.limit(pagination.limit as usize + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    use axum::http::{HeaderMap, StatusCode as AxumStatus};
+    use axum::routing::get;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Row {
+        id: i64,
+    }
+
+    #[test]
+    fn parses_total_from_content_range() {
+        assert_eq!(parse_content_range_total("0-19/123"), Some(123));
+        assert_eq!(parse_content_range_total("*/0"), Some(0));
+        assert_eq!(parse_content_range_total(" 40-59/ 60"), Some(60));
+        assert_eq!(parse_content_range_total("0-19/*"), None);
+        assert_eq!(parse_content_range_total("0-19"), None);
+        assert_eq!(parse_content_range_total(""), None);
+    }
+
+    // 고정 응답을 돌려주는 PostgREST 대역 - 받은 Range/Prefer 헤더를 기록
+    async fn fake_postgrest(status: u16, content_range: &'static str, body: &'static str) -> (String, Arc<Mutex<Vec<(String, String)>>>) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        let app = axum::Router::new().route("/items", get(move |headers: HeaderMap| async move {
+            let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).unwrap_or_default().to_string();
+            recorded.lock().unwrap().push((header("range"), header("prefer")));
+            (AxumStatus::from_u16(status).unwrap(), [("content-range", content_range)], body)
+        }));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (url, requests)
+    }
+
+    #[tokio::test]
+    async fn fetch_page_reads_rows_and_total() {
+        let (url, requests) = fake_postgrest(206, "20-21/123", r#"[{"id": 21}, {"id": 22}]"#).await;
+        let builder = postgrest::Postgrest::new(url).from("items").select("id");
+
+        let page: PagenationResult<Row> = fetch_page(builder, &Pagenation { page: 2, limit: 20 }, CountMode::Exact).await.unwrap();
+        assert_eq!(page.data, vec![Row { id: 21 }, Row { id: 22 }]);
+        assert_eq!((page.total, page.total_pages, page.has_next, page.has_prev), (123, 7, true, true));
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].0, "20-39");
+        assert!(requests[0].1.contains("count=exact"));
+    }
+
+    #[tokio::test]
+    async fn fetch_page_past_the_end_is_empty() {
+        let (url, _) = fake_postgrest(416, "*/25", r#"{"code": "PGRST103", "message": "Requested range not satisfiable"}"#).await;
+        let builder = postgrest::Postgrest::new(url).from("items").select("id");
+
+        let page: PagenationResult<Row> = fetch_page(builder, &Pagenation { page: 5, limit: 10 }, CountMode::Planned).await.unwrap();
+        assert!(page.data.is_empty());
+        assert_eq!((page.total, page.total_pages, page.has_next), (25, 3, false));
+    }
+
+    #[tokio::test]
+    async fn fetch_page_without_total_is_an_error() {
+        let (url, _) = fake_postgrest(200, "0-1/*", r#"[{"id": 1}, {"id": 2}]"#).await;
+        let builder = postgrest::Postgrest::new(url).from("items").select("id");
+
+        let error = fetch_page::<Row>(builder, &Pagenation { page: 1, limit: 20 }, CountMode::Exact).await.unwrap_err();
+        assert!(error.to_string().contains("Missing total"));
+    }
+
+    #[tokio::test]
+    async fn fetch_count_reads_total_from_empty_range() {
+        let (url, _) = fake_postgrest(206, "0-0/57", r#"[{"id": 1}]"#).await;
+        let builder = postgrest::Postgrest::new(url).from("items").select("id");
+
+        assert_eq!(fetch_count(builder, CountMode::Estimated).await.unwrap(), 57);
+    }
+}
@@ -1,8 +1,18 @@
+use chrono::{DateTime, Utc};
 use postgrest::Postgrest;
-use serde_json::Value;
+use serde_json::{json, Value};
 
-use crate::domain::entities::product::Product;
-use crate::domain::dto::pagenation::{Pagenation, PagenationResult};
+use crate::domain::entities::product::{PriceHistory, Product, ProductDetail, ProductListItem, ProductSearchHit};
+use crate::domain::dto::pagenation::{Pagenation, PagenationResult, Sort};
+use crate::domain::dto::{PriceSummary, ProductFacets, ProductFilter};
+use crate::repository::{fetch_page, CountMode};
+
+const PRODUCT_DETAIL_SELECT: &str = "*,\
+    shop:shops!shop_id(*),\
+    brand:brands!brand_id(*),\
+    category:categories!category_id(*),\
+    translations:product_translations(locale,name,description),\
+    active_discounts:discount_infos!product_id(*)";
 
 pub struct ProductRepository {
     client: Postgrest,
@@ -31,321 +41,137 @@ impl ProductRepository {
         }
     }
 
-    // 나라별 상품 조회 - shipping_regions 테이블과 조인하여 구현
-    pub async fn find_by_country(&self, country: &str, pagination: Pagenation) -> Result<PagenationResult<Product>, Box<dyn std::error::Error>> {
-        let offset = (pagination.page - 1) * pagination.limit;
-
-        // 임시로 전체 상품을 반환 (PostgREST 조인 문법 확인 후 개선 필요)
+    // 상품 상세 - 매장/브랜드/카테고리/번역/진행 중 할인을 한 번에 임베딩
+    // (discount_infos가 products-shops/brands를 잇는 조인 테이블로도 해석되므로 FK 컬럼 힌트로 관계를 지정)
+    pub async fn find_detail(&self, id: i64, locale: &str, now: DateTime<Utc>) -> Result<Option<ProductDetail>, Box<dyn std::error::Error>> {
+        let now = now.to_rfc3339();
         let response = self.client
             .from("products")
-            .select("*")
-            .eq("is_deleted", "false")
-            .order("created_at.desc")
-            .range(offset as usize, (offset + pagination.limit - 1) as usize)
+            .select(PRODUCT_DETAIL_SELECT)
+            .eq("id", id.to_string())
+            .eq("translations.locale", locale)
+            .eq("active_discounts.is_active", "true")
+            .lte("active_discounts.start_at", &now)
+            .gte("active_discounts.end_at", &now)
+            .single()
             .execute()
             .await?;
 
-        let products: Vec<Product> = if response.status().is_success() {
+        if response.status().is_success() {
             let text = response.text().await?;
-            serde_json::from_str(&text)?
+            let detail: ProductDetail = serde_json::from_str(&text)?;
+            Ok(Some(detail))
         } else {
-            Vec::new()
-        };
-
-        // 카운트 쿼리
-        let count_response = self.client
-            .from("products")
-            .select("count")
-            .eq("is_deleted", "false")
-            .execute()
-            .await?;
-
-        let total: u64 = if count_response.status().is_success() {
-            let text = count_response.text().await?;
-            let count_result: Value = serde_json::from_str(&text)?;
-            count_result.as_array()
-                .and_then(|arr| arr.get(0))
-                .and_then(|obj| obj.get("count"))
-                .and_then(|c| c.as_u64())
-                .unwrap_or(0)
-        } else {
-            0
-        };
-
-        let total_pages = (total as f64 / pagination.limit as f64).ceil() as u32;
-
-        Ok(PagenationResult {
-            data: products,
-            total,
-            page: pagination.page,
-            limit: pagination.limit,
-            total_pages,
-            has_next: pagination.page < total_pages,
-            has_prev: pagination.page > 1,
-        })
+            Ok(None)
+        }
     }
 
     // 인기 상품 조회 (클릭 수 기준)
     pub async fn find_popular_products(&self, pagination: Pagenation) -> Result<PagenationResult<Product>, Box<dyn std::error::Error>> {
-        let offset = (pagination.page - 1) * pagination.limit;
-
-        let response = self.client
+        let query = self.client
             .from("products")
             .select("*")
             .eq("is_deleted", "false")
-            .order("click_count.desc")  // 클릭 수 기준 내림차순
-            .range(offset as usize, (offset + pagination.limit - 1) as usize)
-            .execute()
-            .await?;
+            .order("click_count.desc,id.desc");  // 클릭 수 기준 내림차순
 
-        let products: Vec<Product> = if response.status().is_success() {
-            let text = response.text().await?;
-            serde_json::from_str(&text)?
-        } else {
-            Vec::new()
-        };
-
-        let count_response = self.client
-            .from("products")
-            .select("count")
-            .eq("is_deleted", "false")
-            .execute()
-            .await?;
-
-        let total: u64 = if count_response.status().is_success() {
-            let text = count_response.text().await?;
-            let count_result: Value = serde_json::from_str(&text)?;
-            count_result.as_array()
-                .and_then(|arr| arr.get(0))
-                .and_then(|obj| obj.get("count"))
-                .and_then(|c| c.as_u64())
-                .unwrap_or(0)
-        } else {
-            0
-        };
-
-        let total_pages = (total as f64 / pagination.limit as f64).ceil() as u32;
-
-        Ok(PagenationResult {
-            data: products,
-            total,
-            page: pagination.page,
-            limit: pagination.limit,
-            total_pages,
-            has_next: pagination.page < total_pages,
-            has_prev: pagination.page > 1,
-        })
+        // 필터가 거의 없는 전체 목록이므로 플래너 통계 추정치로 충분
+        fetch_page(query, &pagination, CountMode::Planned).await
     }
 
-    // 전체 상품 조회 (나라별 필터링 없음)
-    pub async fn find_all_paginated(&self, pagination: Pagenation) -> Result<PagenationResult<Product>, Box<dyn std::error::Error>> {
+    // 패싯 필터 상품 목록 - filter_products RPC (배송 국가/매장/브랜드/카테고리/할인율/할인 여부 + 패싯 카운트)
+    pub async fn find_filtered(&self, filter: &ProductFilter, sort: &Sort, pagination: Pagenation) -> Result<(PagenationResult<ProductListItem>, ProductFacets), Box<dyn std::error::Error>> {
         let offset = (pagination.page - 1) * pagination.limit;
+        let params = json!({
+            "p_filter": filter,
+            "p_sort": sort,
+            "p_limit": pagination.limit,
+            "p_offset": offset,
+        });
 
         let response = self.client
-            .from("products")
-            .select("*")
-            .eq("is_deleted", "false")
-            .order("created_at.desc")
-            .range(offset as usize, (offset + pagination.limit - 1) as usize)
+            .rpc("filter_products", params.to_string())
             .execute()
             .await?;
 
-        let products: Vec<Product> = if response.status().is_success() {
-            let text = response.text().await?;
-            serde_json::from_str(&text)?
-        } else {
-            Vec::new()
-        };
-
-        let count_response = self.client
-            .from("products")
-            .select("count")
-            .eq("is_deleted", "false")
-            .execute()
-            .await?;
+        if !response.status().is_success() {
+            return Err(format!("Failed to filter products: {}", response.status()).into());
+        }
 
-        let total: u64 = if count_response.status().is_success() {
-            let text = count_response.text().await?;
-            let count_result: Value = serde_json::from_str(&text)?;
-            count_result.as_array()
-                .and_then(|arr| arr.get(0))
-                .and_then(|obj| obj.get("count"))
-                .and_then(|c| c.as_u64())
-                .unwrap_or(0)
-        } else {
-            0
+        let text = response.text().await?;
+        let result: Value = serde_json::from_str(&text)?;
+        let total = result.get("total").and_then(|t| t.as_u64()).ok_or("Missing or invalid total in filter_products response")?;
+        let products: Vec<ProductListItem> = match result.get("items") {
+            Some(items) => serde_json::from_value(items.clone())?,
+            None => Vec::new(),
+        };
+        let facets: ProductFacets = match result.get("facets") {
+            Some(facets) => serde_json::from_value(facets.clone())?,
+            None => ProductFacets::default(),
         };
 
-        let total_pages = (total as f64 / pagination.limit as f64).ceil() as u32;
-
-        Ok(PagenationResult {
-            data: products,
-            total,
-            page: pagination.page,
-            limit: pagination.limit,
-            total_pages,
-            has_next: pagination.page < total_pages,
-            has_prev: pagination.page > 1,
-        })
+        Ok((PagenationResult::new(products, total, &pagination), facets))
     }
 
-    pub async fn find_by_shop_id(&self, shop_id: &str, pagination: Pagenation) -> Result<PagenationResult<Product>, Box<dyn std::error::Error>> {
+    // 상품 검색 - search_products RPC (name/sku + 요청 언어 번역 대상, 관련도 순)
+    pub async fn search(&self, query: &str, locale: &str, pagination: Pagenation) -> Result<PagenationResult<ProductSearchHit>, Box<dyn std::error::Error>> {
         let offset = (pagination.page - 1) * pagination.limit;
+        let params = json!({
+            "p_query": query,
+            "p_locale": locale,
+            "p_limit": pagination.limit,
+            "p_offset": offset,
+        });
 
         let response = self.client
-            .from("products")
-            .select("*")
-            .eq("shop_id", shop_id)
-            .eq("is_deleted", "false")
-            .order("created_at.desc")
-            .range(offset as usize, (offset + pagination.limit - 1) as usize)
+            .rpc("search_products", params.to_string())
             .execute()
             .await?;
 
-        let products: Vec<Product> = if response.status().is_success() {
-            let text = response.text().await?;
-            serde_json::from_str(&text)?
-        } else {
-            Vec::new()
-        };
-
-        let count_response = self.client
-            .from("products")
-            .select("count")
-            .eq("shop_id", shop_id)
-            .eq("is_deleted", "false")
-            .execute()
-            .await?;
+        if !response.status().is_success() {
+            return Err(format!("Failed to search products: {}", response.status()).into());
+        }
 
-        let total: u64 = if count_response.status().is_success() {
-            let text = count_response.text().await?;
-            let count_result: Value = serde_json::from_str(&text)?;
-            count_result.as_array()
-                .and_then(|arr| arr.get(0))
-                .and_then(|obj| obj.get("count"))
-                .and_then(|c| c.as_u64())
-                .unwrap_or(0)
-        } else {
-            0
+        let text = response.text().await?;
+        let result: Value = serde_json::from_str(&text)?;
+        let total = result.get("total").and_then(|t| t.as_u64()).ok_or("Missing or invalid total in search_products response")?;
+        let hits: Vec<ProductSearchHit> = match result.get("items") {
+            Some(items) => serde_json::from_value(items.clone())?,
+            None => Vec::new(),
         };
 
-        let total_pages = (total as f64 / pagination.limit as f64).ceil() as u32;
-
-        Ok(PagenationResult {
-            data: products,
-            total,
-            page: pagination.page,
-            limit: pagination.limit,
-            total_pages,
-            has_next: pagination.page < total_pages,
-            has_prev: pagination.page > 1,
-        })
+        Ok(PagenationResult::new(hits, total, &pagination))
     }
 
-    pub async fn find_by_brand_id(&self, brand_id: &str, pagination: Pagenation) -> Result<PagenationResult<Product>, Box<dyn std::error::Error>> {
-        let offset = (pagination.page - 1) * pagination.limit;
-
-        let response = self.client
-            .from("products")
+    // 가격 이력 (최신순) - since 가 있으면 그 이후 기록만
+    pub async fn find_price_history(&self, product_id: i64, since: Option<DateTime<Utc>>, pagination: Pagenation) -> Result<PagenationResult<PriceHistory>, Box<dyn std::error::Error>> {
+        let mut query = self.client
+            .from("price_history")
             .select("*")
-            .eq("brand_id", brand_id)
-            .eq("is_deleted", "false")
-            .order("created_at.desc")
-            .range(offset as usize, (offset + pagination.limit - 1) as usize)
-            .execute()
-            .await?;
-
-        let products: Vec<Product> = if response.status().is_success() {
-            let text = response.text().await?;
-            serde_json::from_str(&text)?
-        } else {
-            Vec::new()
-        };
+            .eq("product_id", product_id.to_string());
 
-        let count_response = self.client
-            .from("products")
-            .select("count")
-            .eq("brand_id", brand_id)
-            .eq("is_deleted", "false")
-            .execute()
-            .await?;
-
-        let total: u64 = if count_response.status().is_success() {
-            let text = count_response.text().await?;
-            let count_result: Value = serde_json::from_str(&text)?;
-            count_result.as_array()
-                .and_then(|arr| arr.get(0))
-                .and_then(|obj| obj.get("count"))
-                .and_then(|c| c.as_u64())
-                .unwrap_or(0)
-        } else {
-            0
-        };
+        if let Some(since) = since {
+            query = query.gte("recorded_at", since.to_rfc3339());
+        }
 
-        let total_pages = (total as f64 / pagination.limit as f64).ceil() as u32;
-
-        Ok(PagenationResult {
-            data: products,
-            total,
-            page: pagination.page,
-            limit: pagination.limit,
-            total_pages,
-            has_next: pagination.page < total_pages,
-            has_prev: pagination.page > 1,
-        })
+        fetch_page(query.order("recorded_at.desc,id.desc"), &pagination, CountMode::Exact).await
     }
 
-    pub async fn find_by_category_id(&self, category_id: &str, pagination: Pagenation) -> Result<PagenationResult<Product>, Box<dyn std::error::Error>> {
-        let offset = (pagination.page - 1) * pagination.limit;
+    // 가격 요약 - 역대/30일/90일 최저가, N일 내 최저가 여부
+    pub async fn find_price_summary(&self, product_id: i64) -> Result<PriceSummary, Box<dyn std::error::Error>> {
+        let params = json!({ "p_product_id": product_id });
 
         let response = self.client
-            .from("products")
-            .select("*")
-            .eq("category_id", category_id)
-            .eq("is_deleted", "false")
-            .order("created_at.desc")
-            .range(offset as usize, (offset + pagination.limit - 1) as usize)
+            .rpc("product_price_summary", params.to_string())
             .execute()
             .await?;
 
-        let products: Vec<Product> = if response.status().is_success() {
-            let text = response.text().await?;
-            serde_json::from_str(&text)?
-        } else {
-            Vec::new()
-        };
-
-        let count_response = self.client
-            .from("products")
-            .select("count")
-            .eq("category_id", category_id)
-            .eq("is_deleted", "false")
-            .execute()
-            .await?;
-
-        let total: u64 = if count_response.status().is_success() {
-            let text = count_response.text().await?;
-            let count_result: Value = serde_json::from_str(&text)?;
-            count_result.as_array()
-                .and_then(|arr| arr.get(0))
-                .and_then(|obj| obj.get("count"))
-                .and_then(|c| c.as_u64())
-                .unwrap_or(0)
-        } else {
-            0
-        };
+        if !response.status().is_success() {
+            return Err(format!("Failed to get price summary: {}", response.status()).into());
+        }
 
-        let total_pages = (total as f64 / pagination.limit as f64).ceil() as u32;
-
-        Ok(PagenationResult {
-            data: products,
-            total,
-            page: pagination.page,
-            limit: pagination.limit,
-            total_pages,
-            has_next: pagination.page < total_pages,
-            has_prev: pagination.page > 1,
-        })
+        let text = response.text().await?;
+        let summary: PriceSummary = serde_json::from_str(&text)?;
+        Ok(summary)
     }
 
     pub async fn create(&self, product: Product) -> Result<Product, Box<dyn std::error::Error>> {
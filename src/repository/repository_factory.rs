@@ -1,6 +1,6 @@
 use crate::config::SupabaseConfig;
 use crate::repository::{
-    DiscountRepository, ShopRepository, ProductRepository, UserRepository
+    DiscountRepository, ShopRepository, ProductRepository, UserRepository, CouponRepository, NotificationRepository, CrawlerRepository
 };
 
 #[derive(Clone)]
@@ -26,9 +26,34 @@ impl RepositoryFactory {
         ProductRepository::new(self.config.public_client())
     }
 
+    pub fn public_coupon_repo(&self) -> CouponRepository {
+        CouponRepository::new(self.config.public_client())
+    }
+
     // 인증된 사용자용 Repository들 (RLS 적용, user token 사용)
     pub fn authenticated_user_repo(&self, user_token: &str) -> UserRepository {
         UserRepository::new(self.config.authenticated_client(user_token))
     }
+
+    // 관리자 Repository들 (RLS 우회, 서버 내부 조회 전용)
+    pub fn admin_user_repo(&self) -> UserRepository {
+        UserRepository::new(self.config.admin_client().clone())
+    }
+
+    pub fn admin_coupon_repo(&self) -> CouponRepository {
+        CouponRepository::new(self.config.admin_client().clone())
+    }
+
+    pub fn admin_notification_repo(&self) -> NotificationRepository {
+        NotificationRepository::new(self.config.admin_client().clone())
+    }
+
+    pub fn admin_shop_repo(&self) -> ShopRepository {
+        ShopRepository::new(self.config.admin_client().clone())
+    }
+
+    pub fn admin_crawler_repo(&self) -> CrawlerRepository {
+        CrawlerRepository::new(self.config.admin_client().clone())
+    }
 }
 
@@ -1,8 +1,8 @@
 use postgrest::Postgrest;
-use serde_json::Value;
 
 use crate::domain::entities::shop::{Shop, Brand, Category};
-use crate::domain::dto::pagenation::{Pagenation, PagenationResult};
+use crate::domain::dto::pagenation::{Pagenation, PagenationResult, Sort};
+use crate::repository::{fetch_page, CountMode};
 
 pub struct ShopRepository {
     client: Postgrest,
@@ -32,53 +32,13 @@ impl ShopRepository {
         }
     }
 
-    pub async fn find_shops_paginated(&self, pagination: Pagenation) -> Result<PagenationResult<Shop>, Box<dyn std::error::Error>> {
-        let offset = (pagination.page - 1) * pagination.limit;
-
-        let response = self.client
+    pub async fn find_shops_paginated(&self, pagination: Pagenation, sort: &Sort) -> Result<PagenationResult<Shop>, Box<dyn std::error::Error>> {
+        let query = self.client
             .from("shops")
             .select("*")
-            .order("created_at.desc")
-            .range(offset as usize, (offset + pagination.limit - 1) as usize)
-            .execute()
-            .await?;
-
-        let shops: Vec<Shop> = if response.status().is_success() {
-            let text = response.text().await?;
-            serde_json::from_str(&text)?
-        } else {
-            Vec::new()
-        };
-
-        let count_response = self.client
-            .from("shops")
-            .select("count")
-            .execute()
-            .await?;
-
-        let total: u64 = if count_response.status().is_success() {
-            let text = count_response.text().await?;
-            let count_result: Value = serde_json::from_str(&text)?;
-            count_result.as_array()
-                .and_then(|arr| arr.get(0))
-                .and_then(|obj| obj.get("count"))
-                .and_then(|c| c.as_u64())
-                .unwrap_or(0)
-        } else {
-            0
-        };
+            .order(sort.to_postgrest_order());
 
-        let total_pages = (total as f64 / pagination.limit as f64).ceil() as u32;
-
-        Ok(PagenationResult {
-            data: shops,
-            total,
-            page: pagination.page,
-            limit: pagination.limit,
-            total_pages,
-            has_next: pagination.page < total_pages,
-            has_prev: pagination.page > 1,
-        })
+        fetch_page(query, &pagination, CountMode::Exact).await
     }
 
     pub async fn create_shop(&self, shop: Shop) -> Result<Shop, Box<dyn std::error::Error>> {
@@ -116,53 +76,13 @@ impl ShopRepository {
         }
     }
 
-    pub async fn find_brands_paginated(&self, pagination: Pagenation) -> Result<PagenationResult<Brand>, Box<dyn std::error::Error>> {
-        let offset = (pagination.page - 1) * pagination.limit;
-
-        let response = self.client
+    pub async fn find_brands_paginated(&self, pagination: Pagenation, sort: &Sort) -> Result<PagenationResult<Brand>, Box<dyn std::error::Error>> {
+        let query = self.client
             .from("brands")
             .select("*")
-            .order("created_at.desc")
-            .range(offset as usize, (offset + pagination.limit - 1) as usize)
-            .execute()
-            .await?;
-
-        let brands: Vec<Brand> = if response.status().is_success() {
-            let text = response.text().await?;
-            serde_json::from_str(&text)?
-        } else {
-            Vec::new()
-        };
-
-        let count_response = self.client
-            .from("brands")
-            .select("count")
-            .execute()
-            .await?;
-
-        let total: u64 = if count_response.status().is_success() {
-            let text = count_response.text().await?;
-            let count_result: Value = serde_json::from_str(&text)?;
-            count_result.as_array()
-                .and_then(|arr| arr.get(0))
-                .and_then(|obj| obj.get("count"))
-                .and_then(|c| c.as_u64())
-                .unwrap_or(0)
-        } else {
-            0
-        };
+            .order(sort.to_postgrest_order());
 
-        let total_pages = (total as f64 / pagination.limit as f64).ceil() as u32;
-
-        Ok(PagenationResult {
-            data: brands,
-            total,
-            page: pagination.page,
-            limit: pagination.limit,
-            total_pages,
-            has_next: pagination.page < total_pages,
-            has_prev: pagination.page > 1,
-        })
+        fetch_page(query, &pagination, CountMode::Exact).await
     }
 
     // Category CRUD
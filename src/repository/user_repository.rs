@@ -49,6 +49,24 @@ impl UserRepository {
         }
     }
 
+    // 권한 조회 (user_roles)
+    pub async fn find_roles_by_user_id(&self, user_id: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let response = self.client
+            .from("user_roles")
+            .select("user_id,role")
+            .eq("user_id", user_id)
+            .execute()
+            .await?;
+
+        if response.status().is_success() {
+            let text = response.text().await?;
+            let roles: Vec<UserRole> = serde_json::from_str(&text)?;
+            Ok(roles.into_iter().map(|r| r.role).collect())
+        } else {
+            Err(format!("Failed to get user roles: {}", response.status()).into())
+        }
+    }
+
     // 상품 구독 관리
     pub async fn add_product_subscription(&self, user_id: &str, product_id: i64) -> Result<ProductSubscription, Box<dyn std::error::Error>> {
         let subscription = ProductSubscription {
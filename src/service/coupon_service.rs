@@ -0,0 +1,352 @@
+use chrono::{DateTime, Utc};
+
+use crate::auth::AuthUser;
+use crate::config::SupabaseConfig;
+use crate::repository::RepositoryFactory;
+use crate::domain::entities::coupon::{Coupon, CouponUsage, CouponValidationResult};
+use crate::domain::dto::pagenation::{Pagenation, PagenationResult};
+use crate::error::{AppError, AppResult};
+
+#[derive(Clone)]
+pub struct CouponService {
+    factory: RepositoryFactory,
+}
+
+impl CouponService {
+    pub fn new(config: SupabaseConfig) -> Self {
+        Self {
+            factory: RepositoryFactory::new(config),
+        }
+    }
+
+    // 사용 가능한 쿠폰 목록 조회
+    pub async fn get_active_coupons(&self, shop_id: Option<i64>, pagination: Pagenation) -> AppResult<PagenationResult<Coupon>> {
+        log::info!("🎫 Getting active coupons (shop_id: {:?})", shop_id);
+        let repo = self.factory.public_coupon_repo();
+        repo.find_active_paginated(shop_id, pagination)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to get coupons: {}", e)))
+    }
+
+    pub async fn get_coupon_by_id(&self, coupon_id: i64) -> AppResult<Option<Coupon>> {
+        log::info!("🎫 Getting coupon by ID: {}", coupon_id);
+        let repo = self.factory.public_coupon_repo();
+        repo.find_by_id(coupon_id)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to get coupon: {}", e)))
+    }
+
+    pub async fn get_coupon_by_code(&self, code: &str) -> AppResult<Option<Coupon>> {
+        log::info!("🎫 Getting coupon by code: {}", code);
+        let repo = self.factory.public_coupon_repo();
+        repo.find_by_code(code)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to get coupon: {}", e)))
+    }
+
+    // 쿠폰 코드 검증 - 주문 금액 기준 할인 금액 계산
+    pub async fn validate_coupon_code(&self, user: &AuthUser, code: &str, order_amount: f64) -> AppResult<CouponValidationResult> {
+        log::info!("🎫 Validating coupon - User: {}, Code: {}, Order: {}", user.id, code, order_amount);
+
+        let coupon = match self.get_coupon_by_code(code).await? {
+            Some(coupon) => coupon,
+            None => return Ok(invalid("Coupon not found")),
+        };
+
+        let user_usage_count = self.count_user_usages(&coupon, user).await?;
+        Ok(validate_coupon(&coupon, order_amount, user_usage_count, chrono::Utc::now()))
+    }
+
+    // 쿠폰 사용 - 검증 후 사용 내역(CouponUsage) 기록
+    pub async fn use_coupon(&self, user: &AuthUser, coupon_id: i64, order_amount: f64) -> AppResult<CouponUsage> {
+        log::info!("🎫 Using coupon - User: {}, Coupon: {}, Order: {}", user.id, coupon_id, order_amount);
+
+        let coupon = self.get_coupon_by_id(coupon_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Coupon"))?;
+
+        let user_usage_count = self.count_user_usages(&coupon, user).await?;
+        let validation = validate_coupon(&coupon, order_amount, user_usage_count, chrono::Utc::now());
+        if !validation.is_valid {
+            return Err(AppError::validation(validation.error_message.unwrap_or_default()));
+        }
+
+        // 사전 검증 이후 다른 요청이 먼저 한도를 소진했을 수 있으므로 DB 잠금 안에서 다시 확인
+        let repo = self.factory.admin_coupon_repo();
+        let redemption = repo.redeem(coupon.id, &user.id, order_amount, validation.discount_amount)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to use coupon: {}", e)))?;
+
+        match (redemption.status.as_str(), redemption.usage) {
+            ("redeemed", Some(usage)) => Ok(usage),
+            ("usage_limit_reached", _) => Err(AppError::conflict("Coupon usage limit has been reached")),
+            ("user_limit_reached", _) => Err(AppError::conflict("You have already used this coupon the maximum number of times")),
+            ("not_found", _) => Err(AppError::not_found("Coupon")),
+            ("unavailable", _) => Err(AppError::validation("Coupon is not currently available")),
+            (status, _) => Err(AppError::internal(format!("Unexpected coupon redemption status: {}", status))),
+        }
+    }
+
+    async fn count_user_usages(&self, coupon: &Coupon, user: &AuthUser) -> AppResult<u64> {
+        // 사용자당 제한이 없는 쿠폰은 조회 생략
+        if coupon.per_user_limit.is_none() {
+            return Ok(0);
+        }
+
+        let repo = self.factory.admin_coupon_repo();
+        repo.count_usages_by_user(coupon.id, &user.id)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to count coupon usages: {}", e)))
+    }
+}
+
+/// 쿠폰 유효성 검증 및 할인 금액 계산 (DB/HTTP 의존성 없는 순수 함수)
+///
+/// 기간, 활성 여부, 전체/사용자별 사용 한도, 최소 주문 금액을 확인한 뒤
+/// percentage / fixed_amount 할인을 계산하고 max_discount_amount 및 주문 금액으로 상한을 적용한다.
+pub fn validate_coupon(coupon: &Coupon, order_amount: f64, user_usage_count: u64, now: DateTime<Utc>) -> CouponValidationResult {
+    if order_amount <= 0.0 {
+        return invalid("Order amount must be greater than 0");
+    }
+    if !coupon.is_active {
+        return invalid("Coupon is not active");
+    }
+    if now < coupon.start_date {
+        return invalid("Coupon is not yet available");
+    }
+    if now > coupon.end_date {
+        return invalid("Coupon has expired");
+    }
+    if let Some(limit) = coupon.usage_limit
+        && coupon.used_count >= limit
+    {
+        return invalid("Coupon usage limit has been reached");
+    }
+    if let Some(limit) = coupon.per_user_limit
+        && user_usage_count >= limit.max(0) as u64
+    {
+        return invalid("You have already used this coupon the maximum number of times");
+    }
+    if let Some(min_amount) = coupon.min_order_amount
+        && order_amount < min_amount
+    {
+        return invalid(format!("Minimum order amount is {}", min_amount));
+    }
+
+    let mut discount_amount = match coupon.discount_type.as_str() {
+        "percentage" => order_amount * coupon.discount_value / 100.0,
+        "fixed_amount" => coupon.discount_value,
+        other => return invalid(format!("Unsupported discount type: {}", other)),
+    };
+
+    if let Some(max_amount) = coupon.max_discount_amount {
+        discount_amount = discount_amount.min(max_amount);
+    }
+    discount_amount = discount_amount.min(order_amount).max(0.0);
+
+    CouponValidationResult {
+        is_valid: true,
+        discount_amount: (discount_amount * 100.0).round() / 100.0,
+        error_message: None,
+    }
+}
+
+fn invalid<T: Into<String>>(message: T) -> CouponValidationResult {
+    CouponValidationResult {
+        is_valid: false,
+        discount_amount: 0.0,
+        error_message: Some(message.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 17, 12, 0, 0).unwrap()
+    }
+
+    // 10% 할인, 최대 5,000원, 최소 주문 20,000원, 전체 100회 / 사용자당 1회
+    fn coupon() -> Coupon {
+        Coupon {
+            id: 1,
+            code: "WELCOME10".to_string(),
+            name: "Welcome".to_string(),
+            description: None,
+            discount_type: "percentage".to_string(),
+            discount_value: 10.0,
+            min_order_amount: Some(20000.0),
+            max_discount_amount: Some(5000.0),
+            usage_limit: Some(100),
+            used_count: 0,
+            per_user_limit: Some(1),
+            start_date: now() - Duration::days(1),
+            end_date: now() + Duration::days(1),
+            is_active: true,
+            shop_id: None,
+            created_at: now() - Duration::days(2),
+            updated_at: now() - Duration::days(2),
+        }
+    }
+
+    #[test]
+    fn validate_coupon_cases() {
+        // (이름, 쿠폰 변경, 주문 금액, 사용자 사용 횟수, 기대 결과: Ok(할인 금액) / Err(오류 메시지))
+        type Case = (&'static str, fn(&mut Coupon), f64, u64, Result<f64, &'static str>);
+        let cases: Vec<Case> = vec![
+            ("percentage", |_| {}, 30000.0, 0, Ok(3000.0)),
+            ("percentage capped by max_discount_amount", |_| {}, 80000.0, 0, Ok(5000.0)),
+            ("percentage without cap", |c| c.max_discount_amount = None, 80000.0, 0, Ok(8000.0)),
+            ("percentage rounded to cents", |c| { c.discount_value = 7.5; c.min_order_amount = None; }, 33.33, 0, Ok(2.5)),
+            ("fixed amount", |c| { c.discount_type = "fixed_amount".to_string(); c.discount_value = 3000.0; }, 25000.0, 0, Ok(3000.0)),
+            ("fixed amount capped by order amount", |c| { c.discount_type = "fixed_amount".to_string(); c.discount_value = 3000.0; c.min_order_amount = None; }, 2000.0, 0, Ok(2000.0)),
+            ("expired", |c| c.end_date = now() - Duration::seconds(1), 30000.0, 0, Err("Coupon has expired")),
+            ("not yet valid", |c| c.start_date = now() + Duration::seconds(1), 30000.0, 0, Err("Coupon is not yet available")),
+            ("inactive", |c| c.is_active = false, 30000.0, 0, Err("Coupon is not active")),
+            ("below min order", |_| {}, 19999.0, 0, Err("Minimum order amount is 20000")),
+            ("exactly min order", |_| {}, 20000.0, 0, Ok(2000.0)),
+            ("per-user limit reached", |_| {}, 30000.0, 1, Err("You have already used this coupon the maximum number of times")),
+            ("per-user limit not reached", |c| c.per_user_limit = Some(2), 30000.0, 1, Ok(3000.0)),
+            ("global limit reached", |c| c.used_count = 100, 30000.0, 0, Err("Coupon usage limit has been reached")),
+            ("no global limit", |c| { c.usage_limit = None; c.used_count = 1000; }, 30000.0, 0, Ok(3000.0)),
+            ("non-positive order", |_| {}, 0.0, 0, Err("Order amount must be greater than 0")),
+            ("unsupported type", |c| c.discount_type = "bogo".to_string(), 30000.0, 0, Err("Unsupported discount type: bogo")),
+        ];
+
+        for (name, modify, order_amount, user_usage_count, expected) in cases {
+            let mut coupon = coupon();
+            modify(&mut coupon);
+            let result = validate_coupon(&coupon, order_amount, user_usage_count, now());
+
+            match expected {
+                Ok(discount) => {
+                    assert!(result.is_valid, "{}: {:?}", name, result.error_message);
+                    assert_eq!(result.discount_amount, discount, "{}", name);
+                    assert_eq!(result.error_message, None, "{}", name);
+                }
+                Err(message) => {
+                    assert!(!result.is_valid, "{}", name);
+                    assert_eq!(result.discount_amount, 0.0, "{}", name);
+                    assert_eq!(result.error_message.as_deref(), Some(message), "{}", name);
+                }
+            }
+        }
+    }
+
+    // 동시 사용: usage_limit=1 쿠폰을 N명이 동시에 사용하면 redeem_coupon 의 FOR UPDATE 잠금으로 1명만 성공
+    // TEST_DATABASE_URL (create_coupon_tables.sql, add_coupon_per_user_limit.sql,
+    // create_redeem_coupon_function.sql 적용된 DB)이 없으면 건너뜀.
+    // PostgREST 대신 같은 DB의 실제 redeem_coupon 함수를 호출하는 로컬 서버를 사용한다.
+    #[tokio::test]
+    async fn concurrent_use_coupon_redeems_last_slot_once() {
+        use std::collections::HashMap;
+        use std::sync::Arc;
+        use axum::extract::{Query, State};
+        use axum::routing::{get, post};
+        use axum::{Json, Router};
+        use sqlx::postgres::PgPoolOptions;
+        use sqlx::PgPool;
+        use tokio::sync::Barrier;
+
+        const USERS: usize = 8;
+
+        let Ok(database_url) = std::env::var("TEST_DATABASE_URL") else {
+            eprintln!("TEST_DATABASE_URL not set, skipping");
+            return;
+        };
+        let pool = PgPoolOptions::new().max_connections(USERS as u32 + 2).connect(&database_url).await.unwrap();
+
+        let code = format!("RACE-{}", uuid::Uuid::new_v4());
+        let coupon_id: i64 = sqlx::query_scalar(
+            "INSERT INTO coupons (code, name, discount_type, discount_value, usage_limit, start_date, end_date)
+             VALUES ($1, 'Race', 'fixed_amount', 1000, 1, NOW() - INTERVAL '1 day', NOW() + INTERVAL '1 day')
+             RETURNING id",
+        )
+        .bind(&code)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let user_ids: Vec<String> = (0..USERS).map(|_| uuid::Uuid::new_v4().to_string()).collect();
+        for user_id in &user_ids {
+            sqlx::query("INSERT INTO auth.users (id) VALUES ($1::uuid)").bind(user_id).execute(&pool).await.unwrap();
+        }
+
+        // 모든 요청이 사전 검증(used_count=0)을 통과한 뒤 동시에 redeem_coupon 을 호출하도록 맞춤
+        type FakeState = (PgPool, Arc<Barrier>);
+        async fn find_coupon(State((pool, _)): State<FakeState>, Query(query): Query<HashMap<String, String>>) -> Json<serde_json::Value> {
+            let id: i64 = query["id"].trim_start_matches("eq.").parse().unwrap();
+            let row: String = sqlx::query_scalar("SELECT to_jsonb(c)::text FROM coupons c WHERE id = $1")
+                .bind(id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+            Json(serde_json::from_str(&row).unwrap())
+        }
+        async fn redeem(State((pool, barrier)): State<FakeState>, Json(params): Json<serde_json::Value>) -> Json<serde_json::Value> {
+            barrier.wait().await;
+            let result: String = sqlx::query_scalar("SELECT redeem_coupon($1, $2::uuid, $3::numeric, $4::numeric)::text")
+                .bind(params["p_coupon_id"].as_i64().unwrap())
+                .bind(params["p_user_id"].as_str().unwrap())
+                .bind(params["p_order_amount"].as_f64().unwrap())
+                .bind(params["p_discount_amount"].as_f64().unwrap())
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+            Json(serde_json::from_str(&result).unwrap())
+        }
+
+        let app = Router::new()
+            .route("/coupons", get(find_coupon))
+            .route("/rpc/redeem_coupon", post(redeem))
+            .with_state((pool.clone(), Arc::new(Barrier::new(USERS))));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let service = CouponService::new(SupabaseConfig {
+            client: postgrest::Postgrest::new(&url),
+            url,
+            anon_key: "anon".to_string(),
+            service_key: "service".to_string(),
+            jwt_secret: None,
+            database_url: None,
+        });
+
+        let attempts = user_ids.iter().map(|user_id| {
+            let service = service.clone();
+            let user = AuthUser {
+                id: user_id.clone(),
+                email: format!("{}@example.com", user_id),
+                role: Some("authenticated".to_string()),
+                app_role: None,
+                token: String::new(),
+            };
+            tokio::spawn(async move { service.use_coupon(&user, coupon_id, 10000.0).await })
+        });
+        let results: Vec<AppResult<CouponUsage>> = futures_util::future::join_all(attempts)
+            .await
+            .into_iter()
+            .map(|joined| joined.unwrap())
+            .collect();
+
+        let (used_count, usages): (i32, i64) = sqlx::query_as(
+            "SELECT used_count, (SELECT COUNT(*) FROM coupon_usages WHERE coupon_id = $1) FROM coupons WHERE id = $1",
+        )
+        .bind(coupon_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query("DELETE FROM coupons WHERE id = $1").bind(coupon_id).execute(&pool).await.unwrap();
+        sqlx::query("DELETE FROM auth.users WHERE id = ANY($1::uuid[])").bind(&user_ids).execute(&pool).await.unwrap();
+
+        let succeeded = results.iter().filter(|result| result.is_ok()).count();
+        let conflicts = results.iter().filter(|result| matches!(result, Err(AppError::Conflict(_)))).count();
+        assert_eq!(succeeded, 1, "{:?}", results);
+        assert_eq!(conflicts, USERS - 1, "{:?}", results);
+        assert_eq!((used_count, usages), (1, 1));
+    }
+}
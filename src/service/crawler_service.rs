@@ -0,0 +1,552 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use chrono::Utc;
+use futures_util::stream::{self, StreamExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::config::crawler::CrawlerConfig;
+use crate::config::SupabaseConfig;
+use crate::crawler::{
+    normalize_domain, resolve_url, CrawlError, DomainLimiter, FetchedPage, FixtureFetcher, HttpFetcher, PageFetcher,
+    PageValidators, PlatformScraper, RobotsRules, ScraperRegistry, SelectorProfiles,
+};
+use crate::domain::dto::pagenation::{Pagenation, PagenationResult};
+use crate::domain::dto::CrawlShopRequest;
+use crate::domain::entities::{CrawlPage, CrawlPageError, CrawlPageType, CrawlRun, CrawlRunStatus, CrawlSummary, CrawlTrigger, CrawledProductResult};
+use crate::domain::entities::shop::Shop;
+use crate::error::{AppError, AppResult};
+use crate::realtime::{RealtimeBus, RealtimeEvent};
+use crate::repository::{CrawlerRepository, RepositoryFactory};
+use crate::utils::constants::{CRAWLER_DISCOUNT_VALID_DAYS, CRAWLER_MAX_LISTING_PAGES, CRAWLER_RUN_ERROR_LIMIT};
+
+// 상점 크롤러 - 카테고리 목록에서 상품 페이지를 찾고, 상품 페이지를 상점 플랫폼의 스크레이퍼로 해석한 뒤
+// products / discount_infos(is_auto_discovered) 에 반영한다.
+// 크롤링은 백그라운드에서 실행되고 crawl_runs 에 기록되며, 모든 요청은
+//   - robots.txt 가 허용한 페이지만
+//   - 상점 도메인별 동시 요청 수/요청 간격(DomainLimiter) 안에서
+//   - 지난번 응답의 ETag/Last-Modified 로 조건부 요청 (304 면 crawl_pages 에 저장된 추출 결과 사용)
+// 으로 보낸다.
+#[derive(Clone)]
+pub struct CrawlerService {
+    factory: RepositoryFactory,
+    fetcher: Arc<dyn PageFetcher>,
+    scrapers: Arc<ScraperRegistry>,
+    limiter: Arc<DomainLimiter>,
+    user_agent: String,
+    page_concurrency: usize,
+    running: Arc<Mutex<HashSet<i64>>>, // 크롤링 중인 상점 - 같은 상점은 한 번에 하나만 실행
+    event_bus: Option<RealtimeBus>,
+}
+
+// 크롤링 한 번의 상태
+struct CrawlContext {
+    shop: Shop,
+    scraper: Arc<dyn PlatformScraper>,
+    repo: CrawlerRepository,
+    pages: HashMap<String, CrawlPage>,                        // 지난 크롤링의 페이지별 검증자/추출 결과
+    robots: AsyncMutex<HashMap<String, Arc<RobotsRules>>>,   // 출처(scheme://host:port)별 robots.txt
+    summary: Mutex<CrawlSummary>,
+}
+
+impl CrawlContext {
+    fn summary(&self) -> MutexGuard<'_, CrawlSummary> {
+        self.summary.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn record_error(&self, url: &str, error: String) {
+        self.summary().errors.push(CrawlPageError { url: url.to_string(), error });
+    }
+}
+
+// 페이지 요청 결과
+enum PageFetch {
+    Skipped, // robots.txt 가 막은 페이지
+    Fetched(FetchedPage),
+    NotModified { validators: PageValidators, extracted: serde_json::Value }, // 304 - 지난번 추출 결과
+}
+
+// 크롤링이 바꾼 할인을 Supabase Realtime 의 discount_infos 변경과 같은 형식으로 발행
+// (종료일만 늘어난 extended 도 행이 바뀌므로 UPDATE 로 발행)
+async fn publish_discount_change(bus: &RealtimeBus, repo: &CrawlerRepository, result: &CrawledProductResult) {
+    let change = match result.discount_change.as_str() {
+        "created" => "INSERT",
+        "updated" | "extended" | "ended" => "UPDATE",
+        _ => return,
+    };
+    let Some(discount_id) = result.discount_id else {
+        return;
+    };
+
+    match repo.find_discount(discount_id).await {
+        Ok(Some(discount)) => bus.publish(RealtimeEvent::DiscountUpdate { change: change.to_string(), discount }),
+        Ok(None) => log::warn!("⚠️ Crawled discount {} not found, change not published", discount_id),
+        Err(e) => log::warn!("⚠️ Crawled discount {} change not published: {}", discount_id, e),
+    }
+}
+
+// 크롤링 중 표시 - 실행이 끝나면 해제
+struct RunningShop {
+    running: Arc<Mutex<HashSet<i64>>>,
+    shop_id: i64,
+}
+
+impl Drop for RunningShop {
+    fn drop(&mut self) {
+        self.running.lock().unwrap_or_else(|e| e.into_inner()).remove(&self.shop_id);
+    }
+}
+
+impl CrawlerService {
+    pub fn new(config: SupabaseConfig, crawler_config: &CrawlerConfig, fetcher: Arc<dyn PageFetcher>, scrapers: ScraperRegistry) -> Self {
+        Self {
+            factory: RepositoryFactory::new(config),
+            fetcher,
+            scrapers: Arc::new(scrapers),
+            limiter: Arc::new(DomainLimiter::new(crawler_config)),
+            user_agent: crawler_config.user_agent.clone(),
+            page_concurrency: crawler_config.domain_concurrency.max(1),
+            running: Arc::new(Mutex::new(HashSet::new())),
+            event_bus: None,
+        }
+    }
+
+    // 크롤링으로 바뀐 할인을 내부 이벤트 버스로도 발행 (Supabase Realtime 을 쓰지 않을 때 - 쓰면 Realtime 이 발행)
+    pub fn with_event_bus(mut self, bus: RealtimeBus) -> Self {
+        self.event_bus = Some(bus);
+        self
+    }
+
+    // CRAWLER_FIXTURE_DIR 이 있으면 저장된 HTML, 없으면 HTTP 로 읽음
+    pub fn from_config(config: SupabaseConfig, crawler_config: &CrawlerConfig) -> Result<Self, CrawlError> {
+        let fetcher: Arc<dyn PageFetcher> = match &crawler_config.fixture_dir {
+            Some(dir) => {
+                log::info!("🕷️ Crawler reads fixture pages from {}", dir.display());
+                Arc::new(FixtureFetcher::new(dir))
+            }
+            None => Arc::new(HttpFetcher::new(crawler_config)?),
+        };
+
+        let profiles = match &crawler_config.profiles_path {
+            Some(path) => SelectorProfiles::load(path).unwrap_or_else(|e| {
+                log::warn!("🕷️ {}, using built-in selector profiles", e);
+                SelectorProfiles::builtin()
+            }),
+            None => SelectorProfiles::builtin(),
+        };
+
+        Ok(Self::new(config, crawler_config, fetcher, ScraperRegistry::from_profiles(profiles)))
+    }
+
+    // 상점 크롤링 시작 - 실행 기록(status = running)을 만들고 크롤링은 백그라운드에서 진행
+    pub async fn start_crawl(&self, shop_id: i64, request: CrawlShopRequest, trigger: CrawlTrigger) -> AppResult<CrawlRun> {
+        let shop = self.factory.admin_shop_repo()
+            .find_shop_by_id(shop_id)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to get shop: {}", e)))?
+            .ok_or_else(|| AppError::not_found(format!("Shop {}", shop_id)))?;
+
+        if normalize_domain(&shop.domain).is_empty() {
+            return Err(AppError::validation(format!("Shop {} has no domain", shop_id)));
+        }
+        if let Some(url) = request.urls.iter().chain(&request.listing_urls).find(|url| !is_shop_url(&shop, url)) {
+            return Err(AppError::validation(format!("URL is not on shop domain {}: {}", shop.domain, url)));
+        }
+
+        self.start_run(shop, request, trigger).await
+    }
+
+    async fn start_run(&self, shop: Shop, request: CrawlShopRequest, trigger: CrawlTrigger) -> AppResult<CrawlRun> {
+        let running = self.mark_running(shop.id)
+            .ok_or_else(|| AppError::conflict(format!("Shop {} is already being crawled", shop.id)))?;
+
+        let repo = self.factory.admin_crawler_repo();
+        // 다른 서버가 크롤링 중이거나 이전 실행 기록이 아직 running 이면 crawl_runs 에서 걸러짐
+        let run = repo.create_crawl_run(shop.id, trigger)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to create crawl run: {}", e)))?
+            .ok_or_else(|| AppError::conflict(format!("Shop {} already has a running crawl", shop.id)))?;
+
+        let service = self.clone();
+        let run_id = run.id;
+        tokio::spawn(async move {
+            let _running = running;
+            let shop_id = shop.id;
+
+            let (status, summary, failure) = match service.crawl_shop(shop, &request).await {
+                Ok(summary) => (CrawlRunStatus::Completed, summary, None),
+                Err(e) => {
+                    log::error!("❌ Crawl run {} for shop {} failed: {}", run_id, shop_id, e);
+                    (CrawlRunStatus::Failed, CrawlSummary { shop_id, ..CrawlSummary::default() }, Some(e.to_string()))
+                }
+            };
+
+            if let Err(e) = repo.finish_crawl_run(run_id, status, &summary, failure.as_deref(), CRAWLER_RUN_ERROR_LIMIT).await {
+                log::error!("❌ Failed to finish crawl run {}: {}", run_id, e);
+            }
+        });
+
+        Ok(run)
+    }
+
+    // 서버 시작 시 - 이전 프로세스가 끝내지 못한 실행 기록은 다시 이어갈 수 없으므로 failed 로 정리
+    // (그대로 두면 해당 상점은 running 기록 때문에 다시 크롤링할 수 없음)
+    pub async fn fail_interrupted_runs(&self) -> AppResult<usize> {
+        self.factory.admin_crawler_repo()
+            .fail_running_crawl_runs("Interrupted by server restart")
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to clean up interrupted crawl runs: {}", e)))
+    }
+
+    fn mark_running(&self, shop_id: i64) -> Option<RunningShop> {
+        let mut running = self.running.lock().unwrap_or_else(|e| e.into_inner());
+        running.insert(shop_id).then(|| RunningShop { running: self.running.clone(), shop_id })
+    }
+
+    // 예약 크롤링 - interval 마다 도메인이 있는 모든 상점을 크롤링 (크롤링 중인 상점은 건너뜀)
+    pub fn spawn_scheduler(self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            // 서버를 다시 시작할 때마다 모든 상점을 크롤링하지 않도록 첫 실행은 한 주기 뒤
+            let mut ticker = tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+            loop {
+                ticker.tick().await;
+
+                let shops = match self.factory.admin_crawler_repo().find_crawlable_shops().await {
+                    Ok(shops) => shops,
+                    Err(e) => {
+                        log::warn!("⚠️ Failed to get shops to crawl: {}", e);
+                        continue;
+                    }
+                };
+
+                for shop in shops {
+                    let shop_id = shop.id;
+                    match self.start_run(shop, CrawlShopRequest::default(), CrawlTrigger::Scheduled).await {
+                        Ok(run) => log::info!("🕷️ Started scheduled crawl run {} for shop {}", run.id, shop_id),
+                        Err(e) => log::warn!("⚠️ Scheduled crawl for shop {} not started: {}", shop_id, e),
+                    }
+                }
+            }
+        })
+    }
+
+    pub async fn get_crawl_runs(&self, shop_id: i64, pagination: Pagenation) -> AppResult<PagenationResult<CrawlRun>> {
+        self.factory.admin_crawler_repo()
+            .find_crawl_runs(shop_id, pagination)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to get crawl runs: {}", e)))
+    }
+
+    pub async fn get_crawl_run(&self, run_id: i64) -> AppResult<CrawlRun> {
+        self.factory.admin_crawler_repo()
+            .find_crawl_run(run_id)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to get crawl run: {}", e)))?
+            .ok_or_else(|| AppError::not_found(format!("Crawl run {}", run_id)))
+    }
+
+    // 목록 페이지에서 찾은 상품, 상점의 기존 상품 페이지(original_url), 요청한 페이지를 크롤링
+    // 페이지별 실패는 요약의 errors 에 담고 나머지 페이지는 계속 진행
+    async fn crawl_shop(&self, shop: Shop, request: &CrawlShopRequest) -> AppResult<CrawlSummary> {
+        let repo = self.factory.admin_crawler_repo();
+        let known = repo.find_crawl_targets(shop.id)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to get crawl targets: {}", e)))?;
+        let pages = repo.find_crawl_pages(shop.id)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to get crawl pages: {}", e)))?;
+
+        let ctx = CrawlContext {
+            scraper: self.scrapers.for_shop(&shop),
+            summary: Mutex::new(CrawlSummary { shop_id: shop.id, ..CrawlSummary::default() }),
+            pages: pages.into_iter().map(|page| (page.url.clone(), page)).collect(),
+            robots: AsyncMutex::new(HashMap::new()),
+            repo,
+            shop,
+        };
+
+        // 목록 URL 을 지정하지 않으면 플랫폼 기본 목록 경로
+        let listing_urls = if request.listing_urls.is_empty() {
+            let base_url = format!("https://{}/", normalize_domain(&ctx.shop.domain));
+            ctx.scraper.listing_paths().iter().map(|path| resolve_url(&base_url, path)).collect()
+        } else {
+            request.listing_urls.clone()
+        };
+        let discovered = self.discover_products(&ctx, &listing_urls).await;
+        ctx.summary().products_discovered = discovered.len() as u32;
+
+        let mut urls: Vec<String> = Vec::new();
+        for url in discovered.into_iter().chain(known).chain(request.urls.iter().cloned()) {
+            if !urls.contains(&url) {
+                urls.push(url);
+            }
+        }
+
+        log::info!("🕷️ Crawling shop {} ({}, {}) - {} product pages", ctx.shop.id, ctx.shop.domain, ctx.shop.platform, urls.len());
+
+        // 도메인 동시 요청 수만큼 함께 진행 (실제 요청 간격은 DomainLimiter 가 조절)
+        stream::iter(&urls)
+            .for_each_concurrent(self.page_concurrency, |url| self.crawl_product(&ctx, url))
+            .await;
+
+        let summary = ctx.summary.into_inner().unwrap_or_else(|e| e.into_inner());
+        log::info!(
+            "🕷️ Crawled shop {} - {} pages ({} not modified, {} skipped by robots.txt), {} products ({} new), {} discount changes, {} errors",
+            summary.shop_id, summary.pages_fetched, summary.pages_not_modified, summary.pages_skipped,
+            summary.products_upserted, summary.products_created, summary.discounts_changed, summary.errors.len()
+        );
+        Ok(summary)
+    }
+
+    // 목록 페이지를 다음 페이지 링크를 따라가며 읽고 상점 도메인의 상품 주소를 모음
+    // (목록 URL 하나당 최대 CRAWLER_MAX_LISTING_PAGES 장, 이미 읽은 페이지는 다시 읽지 않음)
+    async fn discover_products(&self, ctx: &CrawlContext, listing_urls: &[String]) -> Vec<String> {
+        let mut visited = HashSet::new();
+        let mut product_urls: Vec<String> = Vec::new();
+
+        for listing_url in listing_urls {
+            let mut next_page = Some(listing_url.clone());
+            let mut pages = 0;
+
+            while let Some(page_url) = next_page.take() {
+                if pages >= CRAWLER_MAX_LISTING_PAGES || !visited.insert(page_url.clone()) {
+                    break;
+                }
+                pages += 1;
+
+                let listing = self
+                    .fetch_extracted(ctx, &page_url, CrawlPageType::Listing, |body| Ok(ctx.scraper.parse_listing(body, &page_url)))
+                    .await;
+                let listing = match listing {
+                    Ok(Some(listing)) => listing,
+                    Ok(None) => break,
+                    Err(e) => {
+                        log::warn!("🕷️ {}", e);
+                        ctx.record_error(&page_url, e.to_string());
+                        break;
+                    }
+                };
+
+                for url in listing.product_urls {
+                    if is_shop_url(&ctx.shop, &url) && !product_urls.contains(&url) {
+                        product_urls.push(url);
+                    }
+                }
+                next_page = listing.next_page.filter(|url| is_shop_url(&ctx.shop, url));
+            }
+        }
+
+        product_urls
+    }
+
+    async fn crawl_product(&self, ctx: &CrawlContext, url: &str) {
+        let product = match self.fetch_extracted(ctx, url, CrawlPageType::Product, |body| ctx.scraper.extract_product(body, url)).await {
+            Ok(Some(product)) => product,
+            Ok(None) => return,
+            Err(e) => {
+                log::warn!("🕷️ {}", e);
+                ctx.record_error(url, e.to_string());
+                return;
+            }
+        };
+
+        let result = match ctx.repo.upsert_crawled_product(ctx.shop.id, &product, CRAWLER_DISCOUNT_VALID_DAYS).await {
+            Ok(result) => result,
+            Err(e) => {
+                log::error!("❌ Failed to save crawled product {}: {}", url, e);
+                ctx.record_error(url, e.to_string());
+                return;
+            }
+        };
+
+        {
+            let mut summary = ctx.summary();
+            summary.products_upserted += 1;
+            if result.product_created {
+                summary.products_created += 1;
+            }
+            if result.discount_change != "none" && result.discount_change != "extended" {
+                summary.discounts_changed += 1;
+            }
+        }
+        if let Some(bus) = &self.event_bus {
+            publish_discount_change(bus, &ctx.repo, &result).await;
+        }
+    }
+
+    // 페이지를 읽어 추출하고, 성공하면 검증자와 추출 결과를 crawl_pages 에 저장
+    // 304 면 지난번 추출 결과를 다시 사용 (상품은 다시 반영해 자동 발견 할인 종료일을 연장)
+    // robots.txt 가 막은 페이지는 None
+    async fn fetch_extracted<T, F>(&self, ctx: &CrawlContext, url: &str, page_type: CrawlPageType, extract: F) -> Result<Option<T>, CrawlError>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnOnce(&str) -> Result<T, CrawlError>,
+    {
+        let (validators, status, extracted) = match self.fetch_page(ctx, url, page_type).await? {
+            PageFetch::Skipped => return Ok(None),
+            PageFetch::Fetched(page) => (page.validators, page.status, extract(&page.body)?),
+            PageFetch::NotModified { validators, extracted } => {
+                let extracted = serde_json::from_value(extracted)
+                    .map_err(|e| CrawlError::extract(url, format!("Invalid stored page: {}", e)))?;
+                (validators, 304, extracted)
+            }
+        };
+
+        let page = CrawlPage {
+            shop_id: ctx.shop.id,
+            url: url.to_string(),
+            page_type,
+            etag: validators.etag,
+            last_modified: validators.last_modified,
+            extracted: serde_json::to_value(&extracted).ok(),
+            last_status: Some(status as i32),
+            last_crawled_at: Utc::now(),
+        };
+        if let Err(e) = ctx.repo.save_crawl_page(&page).await {
+            log::warn!("⚠️ Failed to save crawl page {}: {}", url, e);
+        }
+
+        Ok(Some(extracted))
+    }
+
+    // robots.txt 확인 → 도메인 제한 → 조건부 요청
+    async fn fetch_page(&self, ctx: &CrawlContext, url: &str, page_type: CrawlPageType) -> Result<PageFetch, CrawlError> {
+        let parsed = reqwest::Url::parse(url).map_err(|e| CrawlError::fetch(url, e.to_string()))?;
+        let robots = self.robots_rules(ctx, &parsed).await;
+
+        let path = match parsed.query() {
+            Some(query) => format!("{}?{}", parsed.path(), query),
+            None => parsed.path().to_string(),
+        };
+        if !robots.is_allowed(&path) {
+            log::info!("🤖 Skipping {} (disallowed by robots.txt)", url);
+            ctx.summary().pages_skipped += 1;
+            return Ok(PageFetch::Skipped);
+        }
+
+        // 지난번에 추출까지 성공한 페이지만 조건부 요청
+        let stored = ctx.pages
+            .get(url)
+            .filter(|page| page.page_type == page_type && page.extracted.is_some());
+        let validators = stored
+            .map(|page| PageValidators { etag: page.etag.clone(), last_modified: page.last_modified.clone() })
+            .unwrap_or_default();
+
+        let page = {
+            let _permit = self.limiter.acquire(&ctx.shop.domain, robots.crawl_delay()).await;
+            self.fetcher.fetch(url, &validators).await?
+        };
+
+        if !page.is_not_modified() {
+            ctx.summary().pages_fetched += 1;
+            return Ok(PageFetch::Fetched(page));
+        }
+
+        match stored.and_then(|page| page.extracted.clone()) {
+            Some(extracted) => {
+                ctx.summary().pages_not_modified += 1;
+                Ok(PageFetch::NotModified { validators, extracted })
+            }
+            // 조건부 요청이 아닌데 304 인 경우
+            None => Err(CrawlError::Status { url: url.to_string(), status: page.status }),
+        }
+    }
+
+    // 출처별 robots.txt (크롤링 한 번에 한 번만 읽음)
+    //   - 4xx: robots.txt 없음 → 모두 허용
+    //   - 5xx, 네트워크 오류: 모두 금지
+    async fn robots_rules(&self, ctx: &CrawlContext, url: &reqwest::Url) -> Arc<RobotsRules> {
+        let origin = url.origin().ascii_serialization();
+        let mut robots = ctx.robots.lock().await;
+        if let Some(rules) = robots.get(&origin) {
+            return rules.clone();
+        }
+
+        let robots_url = format!("{}/robots.txt", origin);
+        let fetched = {
+            let _permit = self.limiter.acquire(&ctx.shop.domain, None).await;
+            self.fetcher.fetch(&robots_url, &PageValidators::default()).await
+        };
+
+        let rules = match fetched {
+            Ok(page) => RobotsRules::parse(&page.body, &self.user_agent),
+            Err(CrawlError::Status { status, .. }) if (400..500).contains(&status) => RobotsRules::allow_all(),
+            Err(e) => {
+                log::warn!("🤖 {}, not crawling {}", e, origin);
+                RobotsRules::disallow_all()
+            }
+        };
+
+        let rules = Arc::new(rules);
+        robots.insert(origin, rules.clone());
+        rules
+    }
+}
+
+// 상점 도메인(또는 그 하위 도메인)의 http(s) 주소인지
+fn is_shop_url(shop: &Shop, url: &str) -> bool {
+    let Ok(parsed) = reqwest::Url::parse(url) else {
+        return false;
+    };
+    let Some(host) = parsed.host_str() else {
+        return false;
+    };
+
+    let domain = normalize_domain(&shop.domain);
+    let host = normalize_domain(host);
+    matches!(parsed.scheme(), "http" | "https") && (host == domain || host.ends_with(&format!(".{}", domain)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::routing::get;
+
+    // discount_infos 조회에 한 행을 돌려주는 PostgREST 대역
+    async fn repo_with_discount(discount_id: i64, product_id: i64) -> CrawlerRepository {
+        let body = serde_json::json!([{
+            "id": discount_id, "product_id": product_id, "shop_id": 1, "brand_id": null,
+            "original_price": 10000.0, "discount_price": 8000.0, "discount_rate": 20.0, "currency": "KRW",
+            "start_at": "2026-10-17T00:00:00Z", "end_at": "2026-10-24T00:00:00Z", "is_active": true,
+            "info_url": null, "source_url": null, "is_auto_discovered": true, "is_event_based": null,
+            "thumbnail_url": null, "click_count": null,
+            "created_at": "2026-10-17T00:00:00Z", "updated_at": "2026-10-17T00:00:00Z"
+        }])
+        .to_string();
+        let app = axum::Router::new().route("/discount_infos", get(move || async move { body }));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        CrawlerRepository::new(postgrest::Postgrest::new(url))
+    }
+
+    fn result(discount_id: Option<i64>, discount_change: &str) -> CrawledProductResult {
+        CrawledProductResult { product_id: 5, product_created: false, discount_id, discount_change: discount_change.to_string() }
+    }
+
+    #[tokio::test]
+    async fn publishes_crawled_discount_changes_like_realtime() {
+        let bus = RealtimeBus::new();
+        let mut receiver = bus.subscribe();
+        let repo = repo_with_discount(9, 5).await;
+
+        for change in ["created", "extended", "none"] {
+            publish_discount_change(&bus, &repo, &result(Some(9), change)).await;
+        }
+        publish_discount_change(&bus, &repo, &result(None, "ended")).await;
+
+        let mut published = Vec::new();
+        while let Ok(event) = receiver.try_recv() {
+            match event.event {
+                RealtimeEvent::DiscountUpdate { change, discount } => published.push((change, discount.id, discount.product_id)),
+                other => panic!("unexpected event {:?}", other),
+            }
+        }
+        assert_eq!(published, vec![("INSERT".to_string(), 9, 5), ("UPDATE".to_string(), 9, 5)]);
+    }
+}
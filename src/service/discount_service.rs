@@ -1,6 +1,9 @@
 use crate::config::SupabaseConfig;
 use crate::repository::RepositoryFactory;
-use crate::domain::entities::discount::DiscountInfo;
+use crate::domain::entities::discount::DiscountListItem;
+use crate::domain::dto::request::DiscountFilter;
+use crate::domain::dto::response::DiscountInfoDetailResponse;
+use crate::domain::dto::pagenation::{CursorPagenation, CursorPagenationResult, Pagenation, PagenationResult, Sort};
 
 #[derive(Clone)]
 pub struct DiscountService {
@@ -14,10 +17,25 @@ impl DiscountService {
         }
     }
 
-    // 기본 할인 조회만 남김
-    pub async fn get_discount_by_id(&self, discount_id: i64) -> Result<Option<DiscountInfo>, Box<dyn std::error::Error>> {
-        log::info!("💰 Getting discount by ID: {}", discount_id);
+    // 할인 상세 조회 - 상품/매장/브랜드와 요청 언어 번역 포함
+    pub async fn get_discount_detail(&self, discount_id: i64, locale: &str) -> Result<Option<DiscountInfoDetailResponse>, Box<dyn std::error::Error>> {
+        log::info!("💰 Getting discount detail - ID: {}, locale: {}", discount_id, locale);
         let repo = self.factory.public_discount_repo();
-        repo.find_by_id(discount_id).await
+        let detail = repo.find_detail(discount_id, locale).await?;
+        Ok(detail.map(Into::into))
     }
-}
\ No newline at end of file
+
+    // 할인 목록 조회 (필터/프리셋/정렬/임베딩)
+    pub async fn get_discounts(&self, filter: &DiscountFilter, pagination: Pagenation, sort: &Sort) -> Result<PagenationResult<DiscountListItem>, Box<dyn std::error::Error>> {
+        log::info!("💰 Getting discounts list (preset: {:?}, sort: {})", filter.preset, sort.to_postgrest_order());
+        let repo = self.factory.public_discount_repo();
+        repo.find_filtered(filter, sort, pagination).await
+    }
+
+    // 할인 목록 조회 (키셋 커서 페이지네이션)
+    pub async fn get_discounts_by_cursor(&self, filter: &DiscountFilter, pagination: &CursorPagenation) -> Result<CursorPagenationResult<DiscountListItem>, Box<dyn std::error::Error>> {
+        log::info!("💰 Getting discounts list by cursor (preset: {:?}, limit: {})", filter.preset, pagination.limit);
+        let repo = self.factory.public_discount_repo();
+        repo.find_by_cursor(filter, pagination).await
+    }
+}
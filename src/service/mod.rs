@@ -5,10 +5,18 @@ pub mod product_service;
 pub mod user_service;
 pub mod notification_service;
 pub mod monitoring_service;
+pub mod coupon_service;
+pub mod price_alert_service;
+pub mod notification_queue_service;
+pub mod crawler_service;
 
 pub use discount_service::*;
 pub use shop_service::*;
 pub use product_service::*;
 pub use user_service::*;
 pub use notification_service::*;
-pub use monitoring_service::*;
\ No newline at end of file
+pub use monitoring_service::*;
+pub use coupon_service::*;
+pub use price_alert_service::*;
+pub use notification_queue_service::*;
+pub use crawler_service::*;
\ No newline at end of file
@@ -0,0 +1,358 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::future::join_all;
+use sqlx::PgPool;
+
+use crate::channels::{ChannelError, NotificationChannels};
+use crate::repository::NotificationQueueRepository;
+use crate::domain::entities::notification::{DeliveryOutcome, QueuedNotification};
+use crate::error::{AppError, AppResult};
+use crate::utils::constants::{
+    NOTIFICATION_QUEUE_BATCH_SIZE, NOTIFICATION_QUEUE_INTERVAL_SECS, NOTIFICATION_QUEUE_LOCK_SECS,
+    NOTIFICATION_RETRY_BASE_SECS, NOTIFICATION_RETRY_MAX_SECS,
+};
+
+// 알림 발송 큐 워커
+// notification_queue 에서 발송 시각이 된 항목을 클레임해 설정된 발송 채널로 보내고,
+// 재시도 가능한 실패는 지수 백오프로 재시도를 예약한다. 시도 결과는 notification_logs 에 남는다.
+#[derive(Clone)]
+pub struct NotificationQueueService {
+    repo: Arc<NotificationQueueRepository>,
+    channels: NotificationChannels,
+}
+
+impl NotificationQueueService {
+    pub fn new(pool: PgPool, channels: NotificationChannels) -> Self {
+        Self {
+            repo: Arc::new(NotificationQueueRepository::new(pool)),
+            channels,
+        }
+    }
+
+    // 한 배치 처리 후 클레임한 항목 수 반환 - 채널 발송은 배치 안에서 동시에 진행
+    pub async fn process_pending(&self) -> AppResult<usize> {
+        let items = self.repo.claim(NOTIFICATION_QUEUE_BATCH_SIZE, NOTIFICATION_QUEUE_LOCK_SECS)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to claim notification queue: {}", e)))?;
+
+        join_all(items.iter().map(|item| self.deliver(item))).await;
+
+        Ok(items.len())
+    }
+
+    async fn deliver(&self, item: &QueuedNotification) {
+        let outcome = delivery_outcome(item, self.dispatch(item).await);
+        match &outcome {
+            DeliveryOutcome::Sent => log::info!("📨 Sent notification {} via {}", item.notification_id, item.channel),
+            DeliveryOutcome::Retry { delay_secs, error } => log::warn!(
+                "⚠️ Notification {} via {} failed (attempt {}/{}), retrying in {}s: {}",
+                item.notification_id, item.channel, item.attempts, item.max_attempts, delay_secs, error
+            ),
+            DeliveryOutcome::Failed { error } => log::error!(
+                "❌ Notification {} via {} failed permanently after {} attempts: {}",
+                item.notification_id, item.channel, item.attempts, error
+            ),
+        }
+
+        // 결과 기록에 실패하면 클레임 만료 후 다시 발송될 수 있음 (at-least-once)
+        match self.repo.complete(item, &outcome).await {
+            Ok(true) => {}
+            Ok(false) => log::warn!(
+                "⚠️ Claim for queue item {} (attempt {}) expired before its result was recorded, result discarded",
+                item.id, item.attempts
+            ),
+            Err(e) => log::error!("❌ Failed to record delivery outcome for queue item {}: {}", item.id, e),
+        }
+    }
+
+    // 큐 채널에 매핑된 발송 채널로 전송 - 매핑이 없으면 재시도해도 소용없으므로 영구 실패
+    async fn dispatch(&self, item: &QueuedNotification) -> Result<(), ChannelError> {
+        let channel = self.channels.get(&item.channel)
+            .ok_or_else(|| ChannelError::permanent(format!("No sender configured for channel: {}", item.channel)))?;
+
+        channel.send(item).await
+    }
+
+    // 백그라운드 워커 시작 - 배치가 가득 차면 쉬지 않고 다음 배치 처리
+    pub fn spawn_worker(self) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(NOTIFICATION_QUEUE_INTERVAL_SECS));
+            loop {
+                interval.tick().await;
+                loop {
+                    match self.process_pending().await {
+                        Ok(count) if count < NOTIFICATION_QUEUE_BATCH_SIZE as usize => break,
+                        Ok(_) => {}
+                        Err(e) => {
+                            log::warn!("⚠️ Notification queue worker failed: {}", e);
+                            break;
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// 발송 결과를 큐 상태로 변환 - 재시도 가능한 실패이고 남은 시도가 있으면 재시도, 아니면 실패 (순수 함수)
+pub fn delivery_outcome(item: &QueuedNotification, result: Result<(), ChannelError>) -> DeliveryOutcome {
+    match result {
+        Ok(()) => DeliveryOutcome::Sent,
+        Err(error) if !error.retryable || item.attempts >= item.max_attempts => DeliveryOutcome::Failed { error: error.message },
+        Err(error) => DeliveryOutcome::Retry { delay_secs: retry_delay_secs(item.attempts), error: error.message },
+    }
+}
+
+/// n번째 시도 실패 후 대기 시간 - BASE * 2^(n-1), 최대 MAX
+pub fn retry_delay_secs(attempt: i32) -> u64 {
+    let exponent = attempt.saturating_sub(1).clamp(0, 31) as u32;
+    NOTIFICATION_RETRY_BASE_SECS
+        .saturating_mul(1u64 << exponent)
+        .min(NOTIFICATION_RETRY_MAX_SECS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(attempts: i32, max_attempts: i32) -> QueuedNotification {
+        QueuedNotification {
+            id: 1,
+            notification_id: 10,
+            user_id: "00000000-0000-0000-0000-000000000001".to_string(),
+            channel: "email".to_string(),
+            attempts,
+            max_attempts,
+            notification_type: "price_drop".to_string(),
+            title: "title".to_string(),
+            content: "content".to_string(),
+            data: None,
+            email: Some("user@example.com".to_string()),
+            push_subscriptions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn retry_delay_doubles_from_base_up_to_max() {
+        assert_eq!(retry_delay_secs(1), NOTIFICATION_RETRY_BASE_SECS);
+        assert_eq!(retry_delay_secs(2), NOTIFICATION_RETRY_BASE_SECS * 2);
+        assert_eq!(retry_delay_secs(3), NOTIFICATION_RETRY_BASE_SECS * 4);
+        assert_eq!(retry_delay_secs(4), NOTIFICATION_RETRY_BASE_SECS * 8);
+        assert_eq!(retry_delay_secs(20), NOTIFICATION_RETRY_MAX_SECS);
+        assert_eq!(retry_delay_secs(i32::MAX), NOTIFICATION_RETRY_MAX_SECS);
+        // 클레임 전(0회) 또는 잘못된 값도 기본 간격
+        assert_eq!(retry_delay_secs(0), NOTIFICATION_RETRY_BASE_SECS);
+        assert_eq!(retry_delay_secs(-3), NOTIFICATION_RETRY_BASE_SECS);
+    }
+
+    #[test]
+    fn sent_on_success() {
+        assert_eq!(delivery_outcome(&item(1, 5), Ok(())), DeliveryOutcome::Sent);
+        assert_eq!(delivery_outcome(&item(5, 5), Ok(())), DeliveryOutcome::Sent);
+    }
+
+    #[test]
+    fn retries_retryable_error_with_backoff() {
+        assert_eq!(
+            delivery_outcome(&item(1, 5), Err(ChannelError::retryable("timeout"))),
+            DeliveryOutcome::Retry { delay_secs: NOTIFICATION_RETRY_BASE_SECS, error: "timeout".to_string() }
+        );
+        assert_eq!(
+            delivery_outcome(&item(3, 5), Err(ChannelError::retryable("503"))),
+            DeliveryOutcome::Retry { delay_secs: NOTIFICATION_RETRY_BASE_SECS * 4, error: "503".to_string() }
+        );
+    }
+
+    #[test]
+    fn fails_when_max_attempts_reached() {
+        assert_eq!(
+            delivery_outcome(&item(5, 5), Err(ChannelError::retryable("timeout"))),
+            DeliveryOutcome::Failed { error: "timeout".to_string() }
+        );
+        assert_eq!(
+            delivery_outcome(&item(6, 5), Err(ChannelError::retryable("timeout"))),
+            DeliveryOutcome::Failed { error: "timeout".to_string() }
+        );
+    }
+
+    #[test]
+    fn fails_permanent_error_without_retry() {
+        assert_eq!(
+            delivery_outcome(&item(1, 5), Err(ChannelError::permanent("410 Gone"))),
+            DeliveryOutcome::Failed { error: "410 Gone".to_string() }
+        );
+    }
+
+    // 큐를 클레임하는 DB 테스트끼리 서로의 항목을 가져가지 않도록 순서대로 실행
+    static QUEUE_DB: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+    // 큐 워커 → 발송 채널: email 은 발송, push 는 재시도 예약, sms 는 발송 채널이 없어 실패
+    // TEST_DATABASE_URL (fix_notifications_table.sql, create_notification_queue.sql, reconcile_notification_settings.sql,
+    // create_push_subscriptions.sql 적용된 DB)이 없으면 건너뜀. 대기 중인 다른 큐 항목도 함께 처리하므로 테스트 전용 DB 사용.
+    #[tokio::test]
+    async fn worker_delivers_through_memory_channel() {
+        use crate::channels::MemoryChannel;
+        use sqlx::postgres::PgPoolOptions;
+
+        let Ok(database_url) = std::env::var("TEST_DATABASE_URL") else {
+            eprintln!("TEST_DATABASE_URL not set, skipping");
+            return;
+        };
+        let _queue = QUEUE_DB.lock().await;
+        let pool = PgPoolOptions::new().max_connections(2).connect(&database_url).await.unwrap();
+
+        let user_id = uuid::Uuid::new_v4().to_string();
+        sqlx::query("INSERT INTO auth.users (id, email) VALUES ($1::uuid, 'worker-test@example.com')")
+            .bind(&user_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let notification_id: i64 = sqlx::query_scalar(
+            "INSERT INTO notifications (user_id, type, title, message) VALUES ($1::uuid, 'price_drop', 'title', 'content') RETURNING id",
+        )
+        .bind(&user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        for channel in ["email", "push", "sms"] {
+            sqlx::query("INSERT INTO notification_queue (notification_id, user_id, channel) VALUES ($1, $2::uuid, $3)")
+                .bind(notification_id)
+                .bind(&user_id)
+                .bind(channel)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let email = MemoryChannel::new();
+        let push = MemoryChannel::new();
+        push.fail_with(Some(ChannelError::retryable("push service unavailable")));
+        let channels = NotificationChannels::new()
+            .with_channel("email", Arc::new(email.clone()))
+            .with_channel("push", Arc::new(push.clone()));
+
+        let processed = NotificationQueueService::new(pool.clone(), channels).process_pending().await;
+
+        let queue: Vec<(String, String, i32, Option<String>)> = sqlx::query_as(
+            "SELECT channel, status, attempts, last_error FROM notification_queue WHERE notification_id = $1 ORDER BY channel",
+        )
+        .bind(notification_id)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        let logs: Vec<(String, String, i32)> = sqlx::query_as(
+            "SELECT channel, status, attempt FROM notification_logs WHERE user_id = $1::uuid ORDER BY channel",
+        )
+        .bind(&user_id)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query("DELETE FROM notification_logs WHERE user_id = $1::uuid").bind(&user_id).execute(&pool).await.unwrap();
+        sqlx::query("DELETE FROM notifications WHERE id = $1").bind(notification_id).execute(&pool).await.unwrap();
+        sqlx::query("DELETE FROM auth.users WHERE id = $1::uuid").bind(&user_id).execute(&pool).await.unwrap();
+
+        assert!(processed.unwrap() >= 3);
+
+        let sent: Vec<_> = email.sent().into_iter().filter(|item| item.notification_id == notification_id).collect();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].email.as_deref(), Some("worker-test@example.com"));
+        assert_eq!((sent[0].title.as_str(), sent[0].content.as_str()), ("title", "content"));
+        assert!(push.sent().is_empty());
+
+        assert_eq!(queue, vec![
+            ("email".to_string(), "sent".to_string(), 1, None),
+            ("push".to_string(), "pending".to_string(), 1, Some("push service unavailable".to_string())),
+            ("sms".to_string(), "failed".to_string(), 1, Some("No sender configured for channel: sms".to_string())),
+        ]);
+        assert_eq!(logs, vec![
+            ("email".to_string(), "sent".to_string(), 1),
+            ("push".to_string(), "pending".to_string(), 1),
+            ("sms".to_string(), "failed".to_string(), 1),
+        ]);
+    }
+
+    // 마지막 시도의 클레임이 결과 없이 만료되면 failed + 로그, 만료된 클레임의 늦은 결과는 다시 클레임한 워커의 결과를 덮어쓰지 않음
+    // TEST_DATABASE_URL 이 없으면 건너뜀 (worker_delivers_through_memory_channel 과 같은 DB)
+    #[tokio::test]
+    async fn expired_claims_fail_at_max_attempts_and_stale_results_are_ignored() {
+        use sqlx::postgres::PgPoolOptions;
+
+        let Ok(database_url) = std::env::var("TEST_DATABASE_URL") else {
+            eprintln!("TEST_DATABASE_URL not set, skipping");
+            return;
+        };
+        let _queue = QUEUE_DB.lock().await;
+        let pool = PgPoolOptions::new().max_connections(2).connect(&database_url).await.unwrap();
+        let repo = NotificationQueueRepository::new(pool.clone());
+
+        let user_id = uuid::Uuid::new_v4().to_string();
+        sqlx::query("INSERT INTO auth.users (id, email) VALUES ($1::uuid, 'expired-claim@example.com')")
+            .bind(&user_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let notification_id: i64 = sqlx::query_scalar(
+            "INSERT INTO notifications (user_id, type, title, message) VALUES ($1::uuid, 'price_drop', 'title', 'content') RETURNING id",
+        )
+        .bind(&user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        // 둘 다 클레임이 만료된 processing - email 은 마지막(5/5) 시도, push 는 2/5 시도
+        let insert_expired = |channel: &'static str, attempts: i32| {
+            sqlx::query_scalar::<_, i64>(
+                "INSERT INTO notification_queue (notification_id, user_id, channel, status, attempts, max_attempts, next_attempt_at, last_error)
+                 VALUES ($1, $2::uuid, $3, 'processing', $4, 5, NOW() - INTERVAL '1 minute', 'previous timeout') RETURNING id",
+            )
+            .bind(notification_id)
+            .bind(user_id.clone())
+            .bind(channel)
+            .bind(attempts)
+            .fetch_one(&pool)
+        };
+        let exhausted_id = insert_expired("email", 5).await.unwrap();
+        let retried_id = insert_expired("push", 2).await.unwrap();
+
+        let claimed = repo.claim(1000, 300).await.unwrap();
+        let retried = claimed.iter().find(|item| item.id == retried_id).cloned().unwrap();
+        assert!(claimed.iter().all(|item| item.id != exhausted_id));
+        assert_eq!(retried.attempts, 3);
+
+        // 2번째 시도를 맡았던 늦은 워커의 결과는 버려지고, 3번째 시도의 결과만 기록
+        let stale = QueuedNotification { attempts: 2, ..retried.clone() };
+        let stale_recorded = repo.complete(&stale, &DeliveryOutcome::Failed { error: "late".to_string() }).await.unwrap();
+        let recorded = repo.complete(&retried, &DeliveryOutcome::Sent).await.unwrap();
+
+        let queue: Vec<(String, String, i32, Option<String>)> = sqlx::query_as(
+            "SELECT channel, status, attempts, last_error FROM notification_queue WHERE notification_id = $1 ORDER BY channel",
+        )
+        .bind(notification_id)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        let logs: Vec<(String, String, i32, Option<String>)> = sqlx::query_as(
+            "SELECT channel, status, attempt, error_message FROM notification_logs WHERE user_id = $1::uuid ORDER BY channel",
+        )
+        .bind(&user_id)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query("DELETE FROM notification_logs WHERE user_id = $1::uuid").bind(&user_id).execute(&pool).await.unwrap();
+        sqlx::query("DELETE FROM notifications WHERE id = $1").bind(notification_id).execute(&pool).await.unwrap();
+        sqlx::query("DELETE FROM auth.users WHERE id = $1::uuid").bind(&user_id).execute(&pool).await.unwrap();
+
+        assert!(!stale_recorded);
+        assert!(recorded);
+        assert_eq!(queue, vec![
+            ("email".to_string(), "failed".to_string(), 5, Some("Claim expired without a delivery result".to_string())),
+            ("push".to_string(), "sent".to_string(), 3, None),
+        ]);
+        assert_eq!(logs, vec![
+            ("email".to_string(), "failed".to_string(), 5, Some("Claim expired without a delivery result".to_string())),
+            ("push".to_string(), "sent".to_string(), 3, None),
+        ]);
+    }
+}
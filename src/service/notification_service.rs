@@ -1,109 +1,244 @@
+use serde_json::{json, Value};
+
 use crate::config::SupabaseConfig;
 use crate::repository::RepositoryFactory;
 use crate::domain::entities::notification::*;
-use crate::domain::dto::pagenation::{Pagenation, PagenationResult};
+use crate::domain::dto::request::NotificationSettingsPatch;
+use crate::domain::dto::pagenation::{CursorPagenation, CursorPagenationResult, Pagenation, PagenationResult, Sort};
 use crate::error::{AppError, AppResult};
+use crate::realtime::{RealtimeBus, RealtimeEvent};
+use crate::utils::constants::DEFAULT_CURRENCY;
 
 #[derive(Clone)]
 pub struct NotificationService {
     factory: RepositoryFactory,
+    event_bus: Option<RealtimeBus>,
 }
 
 impl NotificationService {
     pub fn new(config: SupabaseConfig) -> Self {
         Self {
             factory: RepositoryFactory::new(config),
+            event_bus: None,
         }
     }
 
-    // 알림 목록 조회
-    pub async fn get_notifications(&self, user_id: &str, pagination: Pagenation) -> AppResult<PagenationResult<Notification>> {
-        log::info!("🔔 Getting notifications for user: {}", user_id);
-        // 임시 구현 - 빈 결과 반환
-        Ok(PagenationResult {
-            data: Vec::new(),
-            total: 0,
-            page: pagination.page,
-            limit: pagination.limit,
-            total_pages: 0,
-            has_next: false,
-            has_prev: false,
-        })
+    // 만든 알림을 내부 이벤트 버스로도 발행 (Supabase Realtime 을 쓰지 않을 때 - 쓰면 Realtime 이 발행)
+    pub fn with_event_bus(mut self, bus: RealtimeBus) -> Self {
+        self.event_bus = Some(bus);
+        self
+    }
+
+    // 알림 목록 조회 (is_read 로 읽음/안읽음 필터)
+    pub async fn get_notifications(&self, user_id: &str, is_read: Option<bool>, pagination: Pagenation, sort: &Sort) -> AppResult<PagenationResult<Notification>> {
+        log::info!("🔔 Getting notifications for user: {} (sort: {})", user_id, sort.to_postgrest_order());
+        let repo = self.factory.admin_notification_repo();
+        repo.find_paginated(user_id, is_read, sort, pagination)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to get notifications: {}", e)))
+    }
+
+    // 알림 목록 조회 (키셋 커서 페이지네이션)
+    pub async fn get_notifications_by_cursor(&self, user_id: &str, is_read: Option<bool>, pagination: &CursorPagenation) -> AppResult<CursorPagenationResult<Notification>> {
+        log::info!("🔔 Getting notifications by cursor for user: {} (limit: {})", user_id, pagination.limit);
+        let repo = self.factory.admin_notification_repo();
+        repo.find_by_cursor(user_id, is_read, pagination)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to get notifications: {}", e)))
+    }
+
+    // 안읽은 알림 개수
+    pub async fn get_unread_count(&self, user_id: &str) -> AppResult<u64> {
+        let repo = self.factory.admin_notification_repo();
+        repo.count_unread(user_id)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to count unread notifications: {}", e)))
+    }
+
+    // 알림 읽음 처리 - 다른 사용자의 알림은 존재 여부를 드러내지 않도록 not_found
+    pub async fn mark_notification_read(&self, user_id: &str, notification_id: i64) -> AppResult<()> {
+        log::info!("📖 Marking notification as read: {} (user: {})", notification_id, user_id);
+        let repo = self.factory.admin_notification_repo();
+        let updated = repo.mark_read(user_id, notification_id)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to mark notification as read: {}", e)))?;
+
+        if updated { Ok(()) } else { Err(AppError::not_found("Notification")) }
+    }
+
+    // 전체 읽음 처리 - 읽음 처리된 개수 반환
+    pub async fn mark_all_read(&self, user_id: &str) -> AppResult<u64> {
+        log::info!("📖 Marking all notifications as read (user: {})", user_id);
+        let repo = self.factory.admin_notification_repo();
+        repo.mark_all_read(user_id)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to mark all notifications as read: {}", e)))
+    }
+
+    // 알림 삭제 - 본인 알림만
+    pub async fn delete_notification(&self, user_id: &str, notification_id: i64) -> AppResult<()> {
+        log::info!("🗑️ Deleting notification: {} (user: {})", notification_id, user_id);
+        let repo = self.factory.admin_notification_repo();
+        let deleted = repo.delete(user_id, notification_id)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to delete notification: {}", e)))?;
+
+        if deleted { Ok(()) } else { Err(AppError::not_found("Notification")) }
     }
 
-    // 알림 읽음 처리
-    pub async fn mark_notification_read(&self, notification_id: i64) -> AppResult<()> {
-        log::info!("📖 Marking notification as read: {}", notification_id);
-        // 임시 구현
-        Ok(())
+    // Web Push 구독 등록
+    pub async fn register_push_subscription(&self, user_id: &str, subscription: PushSubscription, user_agent: Option<&str>) -> AppResult<()> {
+        log::info!("📱 Registering push subscription (user: {})", user_id);
+        let repo = self.factory.admin_notification_repo();
+        let saved = repo.upsert_push_subscription(user_id, &subscription, user_agent)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to save push subscription: {}", e)))?;
+
+        if saved { Ok(()) } else { Err(AppError::conflict("Push endpoint is registered to another user")) }
     }
 
-    // 알림 설정 조회
-    pub async fn get_notification_settings(&self, user_id: &str) -> AppResult<Option<NotificationSettings>> {
+    // Web Push 구독 해제
+    pub async fn remove_push_subscription(&self, user_id: &str, endpoint: &str) -> AppResult<()> {
+        log::info!("📱 Removing push subscription (user: {})", user_id);
+        let repo = self.factory.admin_notification_repo();
+        let deleted = repo.delete_push_subscription(user_id, endpoint)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to delete push subscription: {}", e)))?;
+
+        if deleted { Ok(()) } else { Err(AppError::not_found("Push subscription")) }
+    }
+
+    // 알림 설정 조회 - 저장된 설정이 없으면 기본값
+    pub async fn get_notification_settings(&self, user_id: &str) -> AppResult<NotificationSettings> {
         log::info!("⚙️ Getting notification settings for user: {}", user_id);
-        // 임시 구현 - 기본 설정 반환
-        Ok(Some(NotificationSettings {
-            user_id: user_id.to_string(),
-            push_enabled: true,
-            email_enabled: true,
-            sms_enabled: false,
-            discount_alerts: true,
-            price_drop_alerts: true,
-            new_product_alerts: true,
-            created_at: chrono::Utc::now(),
-            updated_at: chrono::Utc::now(),
-        }))
-    }
-
-    // 알림 설정 업데이트
-    pub async fn update_notification_settings(&self, user_id: &str, settings: NotificationSettings) -> AppResult<NotificationSettings> {
-        log::info!("🔧 Updating notification settings for user: {}", user_id);
-        // 임시 구현 - 설정 그대로 반환
-        Ok(settings)
+        let repo = self.factory.admin_notification_repo();
+        let settings = repo.find_settings(user_id)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to get notification settings: {}", e)))?;
+
+        Ok(settings.unwrap_or_else(|| NotificationSettings::defaults(user_id)))
+    }
+
+    // 알림 설정 변경 - replace면 기본값 위에, 아니면 현재 설정 위에 변경 내용을 적용해 저장
+    pub async fn update_notification_settings(&self, user_id: &str, patch: NotificationSettingsPatch, replace: bool) -> AppResult<NotificationSettings> {
+        log::info!("🔧 Updating notification settings for user: {} (replace: {})", user_id, replace);
+        let mut settings = if replace {
+            NotificationSettings::defaults(user_id)
+        } else {
+            self.get_notification_settings(user_id).await?
+        };
+        patch.apply_to(&mut settings);
+
+        let repo = self.factory.admin_notification_repo();
+        repo.upsert_settings(&settings)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to update notification settings: {}", e)))
     }
 
     // 새 알림 생성
-    pub async fn create_notification(&self, user_id: &str, title: &str, content: &str, notification_type: &str) -> AppResult<Notification> {
+    pub async fn create_notification(&self, user_id: &str, title: &str, content: &str, notification_type: &str, data: Option<Value>) -> AppResult<Notification> {
         log::info!("📢 Creating notification for user: {} - {}", user_id, title);
-        
-        let notification = Notification {
-            id: 0, // DB에서 자동 생성될 ID
-            user_id: user_id.to_string(),
-            title: title.to_string(),
-            content: content.to_string(),
-            notification_type: notification_type.to_string(),
-            is_read: false,
-            data: None,
-            created_at: chrono::Utc::now(),
-        };
+        let repo = self.factory.admin_notification_repo();
+        let notification = repo.create(user_id, notification_type, title, content, data)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to create notification: {}", e)))?;
+
+        if let Some(bus) = &self.event_bus {
+            bus.publish(RealtimeEvent::Notification { notification: notification.clone() });
+        }
 
-        // 임시 구현 - 그대로 반환
         Ok(notification)
     }
 
-    // 알림 전송
-    pub async fn send_notification(&self, user_id: &str, title: &str, content: &str, notification_type: &str) -> AppResult<()> {
+    // 알림 전송 - 사용자가 끈 종류의 알림이면 만들지 않고 None, 아니면 저장 후 켜진 채널로 발송 큐에 등록
+    pub async fn send_notification(&self, user_id: &str, title: &str, content: &str, notification_type: &str) -> AppResult<Option<Notification>> {
         log::info!("🚀 Sending notification to user: {} - {}", user_id, title);
-        
-        // 1. 알림 생성
-        let _notification = self.create_notification(user_id, title, content, notification_type).await?;
-        
-        // 2. 알림 설정 확인
+
         let settings = self.get_notification_settings(user_id).await?;
-        
-        if let Some(settings) = settings {
-            // 3. 설정에 따라 알림 전송
-            if settings.push_enabled {
-                log::info!("📱 Would send push notification");
-            }
-            if settings.email_enabled {
-                log::info!("📧 Would send email notification");
-            }
-            if settings.sms_enabled {
-                log::info!("💬 Would send SMS notification");
-            }
+        if !allows_notification_type(&settings, notification_type) {
+            log::info!("🔕 Notification type {} disabled by user: {}", notification_type, user_id);
+            return Ok(None);
         }
-        
-        Ok(())
+
+        let notification = self.create_notification(user_id, title, content, notification_type, None).await?;
+        self.enqueue_delivery(&notification, &settings).await?;
+
+        Ok(Some(notification))
+    }
+
+    // 발송 큐 등록 - 방해 금지 시간은 큐 워커가 클레임 시점에 반영
+    async fn enqueue_delivery(&self, notification: &Notification, settings: &NotificationSettings) -> AppResult<()> {
+        let channels = delivery_channels(settings);
+
+        let repo = self.factory.admin_notification_repo();
+        repo.enqueue(notification, &channels)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to enqueue notification: {}", e)))
     }
-}
\ No newline at end of file
+
+    // 가격 인하 알림 생성 - 할인 정보는 data에 담아 클라이언트가 상세로 이동할 수 있게 함
+    pub async fn notify_price_drop(&self, alert: &PriceDropAlert) -> AppResult<Notification> {
+        let (title, content) = price_drop_message(alert);
+        let data = json!({
+            "discount_info_id": alert.discount_info_id,
+            "product_id": alert.product_id,
+            "matched_by": alert.matched_by,
+            "discount_price": alert.discount_price,
+            "discount_rate": alert.discount_rate,
+        });
+
+        // 수신 여부(discount_updates/subscription_changes)는 claim_price_drop_alerts에서 이미 반영됨
+        let notification = self.create_notification(&alert.user_id, &title, &content, "price_drop", Some(data)).await?;
+
+        // 알림이 만들어진 뒤에는 실패로 돌려주지 않음 - 발송 기록이 되돌려지면 다시 클레임될 때 같은 알림이 또 만들어진다.
+        // 외부 채널 발송만 빠지고 앱 내 알림은 남는다.
+        let enqueued = match self.get_notification_settings(&alert.user_id).await {
+            Ok(settings) => self.enqueue_delivery(&notification, &settings).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = enqueued {
+            log::warn!("⚠️ Price drop notification {} created but not queued for delivery: {}", notification.id, e);
+        }
+
+        Ok(notification)
+    }
+}
+
+/// 알림 종류별 수신 여부 - 할인 알림은 discount_updates, 구독 알림은 subscription_changes
+pub fn allows_notification_type(settings: &NotificationSettings, notification_type: &str) -> bool {
+    match notification_type {
+        "discount_update" | "price_drop" => settings.discount_updates,
+        "shop_subscription" | "brand_subscription" | "category_subscription" => settings.subscription_changes,
+        _ => true,
+    }
+}
+
+/// 사용자 설정에서 켜진 외부 발송 채널 (큐의 channel 값)
+pub fn delivery_channels(settings: &NotificationSettings) -> Vec<&'static str> {
+    [
+        ("push", settings.push_enabled),
+        ("email", settings.email_enabled),
+        ("sms", settings.sms_enabled),
+    ]
+    .into_iter()
+    .filter_map(|(channel, enabled)| enabled.then_some(channel))
+    .collect()
+}
+
+/// 가격 인하 알림 제목/본문 (DB/HTTP 의존성 없는 순수 함수)
+pub fn price_drop_message(alert: &PriceDropAlert) -> (String, String) {
+    let currency = alert.currency.as_deref().unwrap_or(DEFAULT_CURRENCY);
+    let title = match alert.change_type.as_str() {
+        "price_drop" => format!("{} 가격 인하", alert.product_name),
+        _ => format!("{} 할인 시작", alert.product_name),
+    };
+
+    // 인하 이전 가격이 없으면(신규/재활성 할인) 정가 기준
+    let from_price = alert.previous_price.unwrap_or(alert.original_price);
+    let content = format!(
+        "{} {} → {} {} ({}% 할인, ~{})",
+        from_price, currency, alert.discount_price, currency, alert.discount_rate, alert.end_at.format("%Y-%m-%d %H:%M UTC")
+    );
+
+    (title, content)
+}
@@ -0,0 +1,247 @@
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::config::SupabaseConfig;
+use crate::repository::RepositoryFactory;
+use crate::service::{retry_delay_secs, NotificationService};
+use crate::error::{AppError, AppResult};
+use crate::utils::constants::{PRICE_ALERT_BATCH_SIZE, PRICE_ALERT_INTERVAL_SECS, PRICE_ALERT_LOCK_SECS, PRICE_ALERT_MAX_ATTEMPTS};
+
+// 가격 인하 알림 매처
+// discount_infos 변경 시 DB 트리거가 남긴 이벤트를 주기적으로 가져가 구독자에게 알림을 만든다.
+// 구독 매칭과 사용자/할인별 중복 제거는 claim_price_drop_alerts RPC에서 처리한다.
+// 발송 기록은 알림을 만든 뒤에 sent 로 바뀌므로, 생성에 실패하거나 서버가 중간에 죽으면 다시 클레임된다 (at-least-once).
+#[derive(Clone)]
+pub struct PriceAlertService {
+    factory: RepositoryFactory,
+    notification_service: NotificationService,
+}
+
+impl PriceAlertService {
+    pub fn new(config: SupabaseConfig, notification_service: NotificationService) -> Self {
+        Self {
+            factory: RepositoryFactory::new(config),
+            notification_service,
+        }
+    }
+
+    // 대기 중인 변경 이벤트를 한 배치 처리하고 생성한 알림 수를 반환
+    pub async fn process_pending(&self) -> AppResult<usize> {
+        let repo = self.factory.admin_notification_repo();
+        let alerts = repo.claim_price_drop_alerts(PRICE_ALERT_BATCH_SIZE, PRICE_ALERT_LOCK_SECS, PRICE_ALERT_MAX_ATTEMPTS)
+            .await
+            .map_err(|e| AppError::internal(format!("Failed to claim price drop alerts: {}", e)))?;
+
+        let mut created = 0;
+        for alert in &alerts {
+            // 한 사용자의 실패가 나머지 알림을 막지 않도록 발송 기록만 되돌리고 계속 진행
+            match self.notification_service.notify_price_drop(alert).await {
+                Ok(notification) => {
+                    created += 1;
+                    // 완료 기록에 실패하면 클레임 만료 후 다시 만들어질 수 있음 (중복 > 누락)
+                    if let Err(e) = repo.complete_price_drop_alert(alert, notification.id).await {
+                        log::warn!("⚠️ Failed to mark price drop alert as sent for user {}: {}", alert.user_id, e);
+                    }
+                }
+                Err(e) => {
+                    log::error!("❌ Failed to create price drop notification for user {}: {}", alert.user_id, e);
+                    // 계속 실패하는 알림이 매처 실행마다 재시도되어 시도 횟수를 바로 소진하지 않도록 큐 워커와 같은 지수 백오프
+                    let retry_at = Utc::now() + chrono::Duration::seconds(retry_delay_secs(alert.attempts) as i64);
+                    if let Err(e) = repo.release_price_drop_alert(alert, &e.to_string(), retry_at).await {
+                        log::warn!("⚠️ Failed to release price drop alert for user {}: {}", alert.user_id, e);
+                    }
+                }
+            }
+        }
+
+        Ok(created)
+    }
+
+    // 백그라운드 매처 시작 - 배치가 가득 차면 쉬지 않고 다음 배치 처리
+    pub fn spawn_matcher(self) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(PRICE_ALERT_INTERVAL_SECS));
+            loop {
+                interval.tick().await;
+                loop {
+                    match self.process_pending().await {
+                        Ok(0) => break,
+                        Ok(count) => {
+                            log::info!("🔔 Created {} price drop notifications", count);
+                            if count < PRICE_ALERT_BATCH_SIZE as usize {
+                                break;
+                            }
+                        }
+                        Err(e) => {
+                            log::warn!("⚠️ Price drop matcher failed: {}", e);
+                            break;
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::postgres::PgPoolOptions;
+    use sqlx::PgPool;
+
+    use crate::domain::entities::notification::PriceDropAlert;
+    use crate::utils::constants::{PRICE_ALERT_LOCK_SECS, PRICE_ALERT_MAX_ATTEMPTS};
+
+    async fn claim(pool: &PgPool, user_ids: &[String]) -> Vec<PriceDropAlert> {
+        let text: String = sqlx::query_scalar("SELECT claim_price_drop_alerts(1000, $1, $2)::text")
+            .bind(PRICE_ALERT_LOCK_SECS as i32)
+            .bind(PRICE_ALERT_MAX_ATTEMPTS as i32)
+            .fetch_one(pool)
+            .await
+            .unwrap();
+        let alerts: Vec<PriceDropAlert> = serde_json::from_str(&text).unwrap();
+        alerts.into_iter().filter(|alert| user_ids.contains(&alert.user_id)).collect()
+    }
+
+    async fn insert_id(pool: &PgPool, sql: &str, name: &str) -> i64 {
+        sqlx::query_scalar(sql).bind(name).fetch_one(pool).await.unwrap()
+    }
+
+    async fn subscribe(pool: &PgPool, table: &str, column: &str, user_id: &str, id: i64) {
+        sqlx::query(&format!("INSERT INTO {} (user_id, {}) VALUES ($1::uuid, $2)", table, column))
+            .bind(user_id)
+            .bind(id)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    // 매처가 클레임하는 구독자 매칭 (claim_price_drop_alerts) - 상품/브랜드/매장/상위 카테고리, 카테고리 최소 할인율,
+    // 사용자/할인당 한 건, 수신 설정 제외
+    // TEST_DATABASE_URL (create_price_drop_alerts.sql, reconcile_notification_settings.sql, retry_price_drop_alerts.sql
+    // 적용된 DB)이 없으면 건너뜀. 대기 중인 다른 변경 이벤트도 함께 처리하므로 테스트 전용 DB 사용.
+    #[tokio::test]
+    async fn claims_one_alert_per_matching_subscriber() {
+        let Ok(database_url) = std::env::var("TEST_DATABASE_URL") else {
+            eprintln!("TEST_DATABASE_URL not set, skipping");
+            return;
+        };
+        let pool = PgPoolOptions::new().max_connections(2).connect(&database_url).await.unwrap();
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+
+        let shop_id = insert_id(&pool, "INSERT INTO shops (name, slug) VALUES ($1, $1) RETURNING id", &format!("alert-shop-{}", suffix)).await;
+        let other_shop_id = insert_id(&pool, "INSERT INTO shops (name, slug) VALUES ($1, $1) RETURNING id", &format!("alert-other-{}", suffix)).await;
+        let brand_id = insert_id(&pool, "INSERT INTO brands (name, slug) VALUES ($1, $1) RETURNING id", &format!("alert-brand-{}", suffix)).await;
+        let parent_id = insert_id(&pool, "INSERT INTO categories (name, slug) VALUES ($1, $1) RETURNING id", &format!("alert-parent-{}", suffix)).await;
+        let category_id: i64 = sqlx::query_scalar("INSERT INTO categories (name, slug, parent_id) VALUES ($1, $1, $2) RETURNING id")
+            .bind(format!("alert-child-{}", suffix))
+            .bind(parent_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let product_id: i64 = sqlx::query_scalar(
+            "INSERT INTO products (shop_id, brand_id, category_id, name) VALUES ($1, $2, $3, 'Alert Coat') RETURNING id",
+        )
+        .bind(shop_id)
+        .bind(brand_id)
+        .bind(category_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let users: Vec<String> = (0..9).map(|_| uuid::Uuid::new_v4().to_string()).collect();
+        for user_id in &users {
+            sqlx::query("INSERT INTO auth.users (id, email) VALUES ($1::uuid, 'alert-test@example.com')")
+                .bind(user_id)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+        let [by_product, by_brand, by_shop, by_parent_category, min_rate_30, several, opted_out, subscriptions_off, other_shop] =
+            <[String; 9]>::try_from(users.clone()).unwrap();
+
+        subscribe(&pool, "product_subscriptions", "product_id", &by_product, product_id).await;
+        subscribe(&pool, "brand_subscriptions", "brand_id", &by_brand, brand_id).await;
+        subscribe(&pool, "shop_subscriptions", "shop_id", &by_shop, shop_id).await;
+        sqlx::query("INSERT INTO category_subscriptions (user_id, category_id, min_discount_rate) VALUES ($1::uuid, $2, 20), ($3::uuid, $4, 30)")
+            .bind(&by_parent_category)
+            .bind(parent_id)
+            .bind(&min_rate_30)
+            .bind(category_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        // 여러 구독이 맞아도 한 건 (상품 구독 우선)
+        subscribe(&pool, "category_subscriptions", "category_id", &several, category_id).await;
+        subscribe(&pool, "brand_subscriptions", "brand_id", &several, brand_id).await;
+        subscribe(&pool, "product_subscriptions", "product_id", &several, product_id).await;
+        // 할인 알림을 끈 사용자, 구독 변경 알림을 끈 사용자 (브랜드 매칭만 있음)
+        subscribe(&pool, "product_subscriptions", "product_id", &opted_out, product_id).await;
+        subscribe(&pool, "brand_subscriptions", "brand_id", &subscriptions_off, brand_id).await;
+        sqlx::query("INSERT INTO notification_settings (user_id, discount_updates) VALUES ($1::uuid, false)")
+            .bind(&opted_out)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO notification_settings (user_id, subscription_changes) VALUES ($1::uuid, false)")
+            .bind(&subscriptions_off)
+            .execute(&pool)
+            .await
+            .unwrap();
+        subscribe(&pool, "shop_subscriptions", "shop_id", &other_shop, other_shop_id).await;
+
+        // 20% 할인 시작 → 변경 이벤트 (트리거)
+        let discount_id: i64 = sqlx::query_scalar(
+            "INSERT INTO discount_infos (product_id, shop_id, original_price, discount_price, discount_rate, start_at, end_at)
+             VALUES ($1, $2, 100000, 80000, 20, NOW() - INTERVAL '1 hour', NOW() + INTERVAL '1 day') RETURNING id",
+        )
+        .bind(product_id)
+        .bind(shop_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let first = claim(&pool, &users).await;
+
+        // 30% 로 추가 인하 - 이미 알림 대상이 된 사용자는 제외, 최소 할인율(30%)에 이제 도달한 사용자만
+        sqlx::query("UPDATE discount_infos SET discount_price = 70000, discount_rate = 30 WHERE id = $1")
+            .bind(discount_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let second = claim(&pool, &users).await;
+
+        for user_id in &users {
+            sqlx::query("DELETE FROM auth.users WHERE id = $1::uuid").bind(user_id).execute(&pool).await.unwrap();
+        }
+        sqlx::query("DELETE FROM shops WHERE id = ANY($1)").bind(vec![shop_id, other_shop_id]).execute(&pool).await.unwrap();
+        sqlx::query("DELETE FROM brands WHERE id = $1").bind(brand_id).execute(&pool).await.unwrap();
+        sqlx::query("DELETE FROM categories WHERE id = ANY($1)").bind(vec![category_id, parent_id]).execute(&pool).await.unwrap();
+
+        let names = [
+            ("by_product", &by_product), ("by_brand", &by_brand), ("by_shop", &by_shop),
+            ("by_parent_category", &by_parent_category), ("several", &several),
+        ];
+        let mut matched: Vec<(&str, &str)> = first
+            .iter()
+            .map(|alert| {
+                assert_eq!((alert.discount_info_id, alert.product_id), (discount_id, product_id));
+                assert_eq!((alert.product_name.as_str(), alert.change_type.as_str(), alert.attempts), ("Alert Coat", "created", 1));
+                let name = names.iter().find(|(_, user_id)| **user_id == alert.user_id).map_or(alert.user_id.as_str(), |(name, _)| name);
+                (name, alert.matched_by.as_str())
+            })
+            .collect();
+        matched.sort();
+        assert_eq!(matched, vec![
+            ("by_brand", "brand"),
+            ("by_parent_category", "category"),
+            ("by_product", "product"),
+            ("by_shop", "shop"),
+            ("several", "product"),
+        ]);
+        assert_eq!(second.len(), 1, "{:?}", second);
+        assert_eq!(second[0].user_id, min_rate_30);
+        assert_eq!((second[0].matched_by.as_str(), second[0].change_type.as_str()), ("category", "price_drop"));
+        assert_eq!((second[0].discount_price, second[0].previous_price), (70000.0, Some(80000.0)));
+    }
+}
@@ -1,7 +1,10 @@
+use chrono::{Duration, Utc};
+
 use crate::config::SupabaseConfig;
 use crate::repository::RepositoryFactory;
-use crate::domain::entities::product::Product;
-use crate::domain::dto::pagenation::{Pagenation, PagenationResult};
+use crate::domain::entities::product::{PriceHistory, Product, ProductListItem, ProductSearchHit};
+use crate::domain::dto::pagenation::{Pagenation, PagenationResult, Sort};
+use crate::domain::dto::{PriceSummary, ProductDetailResponse, ProductFacets, ProductFilter};
 
 #[derive(Clone)]
 pub struct ProductService {
@@ -16,24 +19,46 @@ impl ProductService {
     }
 
     // 기본 기능만 남김 - Repository에 실제로 존재하는 메서드만 사용
-    pub async fn get_product_by_id(&self, product_id: i64) -> Result<Option<Product>, Box<dyn std::error::Error>> {
-        log::info!("📦 Getting product by ID: {}", product_id);
+    // 상품 상세 조회 - 요청 언어 번역과 현재 진행 중인 할인 포함
+    pub async fn get_product_detail(&self, product_id: i64, locale: &str) -> Result<Option<ProductDetailResponse>, Box<dyn std::error::Error>> {
+        log::info!("📦 Getting product detail - ID: {}, locale: {}", product_id, locale);
         let repo = self.factory.public_product_repo();
-        repo.find_by_id(product_id).await
+        let now = Utc::now();
+        let Some(detail) = repo.find_detail(product_id, locale, now).await? else {
+            return Ok(None);
+        };
+
+        let mut response = ProductDetailResponse::from_detail(detail, now);
+        response.price_summary = Some(repo.find_price_summary(product_id).await?);
+        Ok(Some(response))
+    }
+
+    // 가격 이력 조회 - 상품이 없으면 None
+    pub async fn get_price_history(&self, product_id: i64, days: Option<u32>, pagination: Pagenation) -> Result<Option<(PagenationResult<PriceHistory>, PriceSummary)>, Box<dyn std::error::Error>> {
+        log::info!("📈 Getting price history - product: {}, days: {:?}", product_id, days);
+        let repo = self.factory.public_product_repo();
+        if repo.find_by_id(product_id).await?.is_none() {
+            return Ok(None);
+        }
+
+        let since = days.map(|days| Utc::now() - Duration::days(days as i64));
+        let history = repo.find_price_history(product_id, since, pagination).await?;
+        let summary = repo.find_price_summary(product_id).await?;
+        Ok(Some((history, summary)))
     }
 
-    // 나라별 상품 목록 조회 
-    pub async fn get_products_by_country(&self, country: &str, pagination: Pagenation) -> Result<PagenationResult<Product>, Box<dyn std::error::Error>> {
-        log::info!("🌍 Getting products for country: {}", country);
+    // 패싯 필터 상품 목록 조회 (필터가 없으면 전체 상품)
+    pub async fn get_filtered_products(&self, filter: &ProductFilter, sort: &Sort, pagination: Pagenation) -> Result<(PagenationResult<ProductListItem>, ProductFacets), Box<dyn std::error::Error>> {
+        log::info!("📦 Getting products with filter: {:?}, sort: {}", filter, sort.to_postgrest_order());
         let repo = self.factory.public_product_repo();
-        repo.find_by_country(country, pagination).await
+        repo.find_filtered(filter, sort, pagination).await
     }
 
-    // 전체 상품 목록 조회 (나라별 필터링 없음)
-    pub async fn get_all_products(&self, pagination: Pagenation) -> Result<PagenationResult<Product>, Box<dyn std::error::Error>> {
-        log::info!("📦 Getting all products");
+    // 상품 검색 (관련도 순)
+    pub async fn search_products(&self, query: &str, locale: &str, pagination: Pagenation) -> Result<PagenationResult<ProductSearchHit>, Box<dyn std::error::Error>> {
+        log::info!("🔍 Searching products: '{}' (locale: {})", query, locale);
         let repo = self.factory.public_product_repo();
-        repo.find_all_paginated(pagination).await
+        repo.search(query, locale, pagination).await
     }
 
     // 인기 상품 조회 (클릭 수 기준)
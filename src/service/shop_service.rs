@@ -1,7 +1,7 @@
 use crate::config::SupabaseConfig;
 use crate::repository::RepositoryFactory;
 use crate::domain::entities::shop::{Shop, Brand, Category};
-use crate::domain::dto::pagenation::{Pagenation, PagenationResult};
+use crate::domain::dto::pagenation::{Pagenation, PagenationResult, Sort};
 
 #[derive(Clone)]
 pub struct ShopService {
@@ -22,10 +22,10 @@ impl ShopService {
         repo.find_shop_by_id(shop_id).await
     }
 
-    pub async fn get_shops_paginated(&self, pagination: Pagenation) -> Result<PagenationResult<Shop>, Box<dyn std::error::Error>> {
-        log::info!("🏪 Getting shops list with pagination");
+    pub async fn get_shops_paginated(&self, pagination: Pagenation, sort: &Sort) -> Result<PagenationResult<Shop>, Box<dyn std::error::Error>> {
+        log::info!("🏪 Getting shops list with pagination (sort: {})", sort.to_postgrest_order());
         let repo = self.factory.public_shop_repo();
-        repo.find_shops_paginated(pagination).await
+        repo.find_shops_paginated(pagination, sort).await
     }
 
     // 브랜드 관리 기능들
@@ -35,10 +35,10 @@ impl ShopService {
         repo.find_brand_by_id(brand_id).await
     }
 
-    pub async fn get_brands_paginated(&self, pagination: Pagenation) -> Result<PagenationResult<Brand>, Box<dyn std::error::Error>> {
-        log::info!("🏷️ Getting brands list with pagination");
+    pub async fn get_brands_paginated(&self, pagination: Pagenation, sort: &Sort) -> Result<PagenationResult<Brand>, Box<dyn std::error::Error>> {
+        log::info!("🏷️ Getting brands list with pagination (sort: {})", sort.to_postgrest_order());
         let repo = self.factory.public_shop_repo();
-        repo.find_brands_paginated(pagination).await
+        repo.find_brands_paginated(pagination, sort).await
     }
 
     // 카테고리 관리 기능들 (계층형)
@@ -3,21 +3,78 @@ pub const API_VERSION: &str = "v1";
 pub const MAX_PAGE_SIZE: u32 = 100;
 pub const DEFAULT_PAGE_SIZE: u32 = 20;
 
+// 정렬 관련 상수 (sort=field:direction, 쉼표로 여러 키 지정)
+pub const MAX_SORT_KEYS: usize = 3;
+pub const DEFAULT_SORT: &str = "created_at:desc";
+pub const PRODUCT_SORT_FIELDS: &[&str] = &["created_at", "updated_at", "name", "click_count", "current_discount_rate", "id"];
+pub const SHOP_SORT_FIELDS: &[&str] = &["created_at", "updated_at", "name", "id"];
+pub const BRAND_SORT_FIELDS: &[&str] = &["created_at", "updated_at", "name", "id"];
+pub const DISCOUNT_SORT_FIELDS: &[&str] = &["created_at", "start_at", "end_at", "discount_rate", "discount_price", "original_price", "click_count", "id"];
+pub const NOTIFICATION_SORT_FIELDS: &[&str] = &["created_at", "is_read", "id"];
+
+// 할인 목록 프리셋 / 임베딩
+pub const DISCOUNT_ENDING_SOON_HOURS: i64 = 48;
+pub const DISCOUNT_NEWLY_STARTED_HOURS: i64 = 24;
+pub const DISCOUNT_EMBEDS: &[&str] = &["product", "shop", "brand"];
+
+// 가격 이력 조회 기간 상한 (days 파라미터)
+pub const MAX_PRICE_HISTORY_DAYS: u32 = 3650;
+
 // 알림 관련 상수
 pub const NOTIFICATION_TYPES: &[&str] = &[
     "discount_update",
     "shop_subscription", 
     "brand_subscription",
     "category_subscription",
+    "price_drop",
 ];
 
-pub const NOTIFICATION_SETTINGS_TYPES: &[&str] = &[
-    "push",
-    "discount",
-    "shop", 
-    "brand",
-    "category",
-];
+// 알림 발송 채널 (notification_settings 의 *_enabled / quiet_hours 키, notification_queue.channel)
+pub const NOTIFICATION_DELIVERY_CHANNELS: &[&str] = &["push", "email", "sms"];
+
+// 가격 인하 알림 매처 (discount_change_events 폴링)
+pub const PRICE_ALERT_INTERVAL_SECS: u64 = 30;
+pub const PRICE_ALERT_BATCH_SIZE: u32 = 100;
+pub const PRICE_ALERT_LOCK_SECS: u64 = 300; // 클레임 후 결과 기록이 없으면 다시 클레임되는 시간
+pub const PRICE_ALERT_MAX_ATTEMPTS: u32 = 5;
+
+// 알림 발송 큐 워커 (notification_queue 폴링, DATABASE_URL 직접 연결)
+pub const DATABASE_MAX_CONNECTIONS: u32 = 5;
+pub const NOTIFICATION_QUEUE_INTERVAL_SECS: u64 = 5;
+pub const NOTIFICATION_QUEUE_BATCH_SIZE: u32 = 50;
+pub const NOTIFICATION_QUEUE_LOCK_SECS: u64 = 300; // 클레임 후 결과 기록이 없으면 다시 클레임되는 시간
+pub const NOTIFICATION_RETRY_BASE_SECS: u64 = 30;  // 재시도 간격: 30s, 60s, 120s ... (지수 백오프)
+pub const NOTIFICATION_RETRY_MAX_SECS: u64 = 3600;
+
+// 알림 발송 방식 (NOTIFICATION_CHANNELS 의 큐 채널=발송 방식)
+pub const NOTIFICATION_SENDER_KINDS: &[&str] = &["smtp", "web_push", "webhook", "memory"];
+pub const NOTIFICATION_SEND_TIMEOUT_SECS: u64 = 30;
+pub const WEB_PUSH_TTL_SECS: u64 = 86400;
+pub const VAPID_TOKEN_EXPIRY_SECS: i64 = 12 * 3600; // 푸시 서비스 허용 최대 24시간
+// TLS 없이 SMTP AUTH 를 허용하는 로컬 SMTP 캐처 호스트 (docker compose 서비스 이름, loopback 은 항상 허용)
+pub const SMTP_LOCAL_CATCHER_HOSTS: &[&str] = &["mailhog", "mailpit"];
+
+// 실시간 전달 (내부 이벤트 버스, Supabase Realtime, /api/v1/ws)
+pub const REALTIME_BUS_CAPACITY: usize = 1024; // 느린 구독자는 이만큼 밀리면 lagged
+pub const REALTIME_REPLAY_CAPACITY: usize = 1000; // Last-Event-ID 재전송용으로 보관하는 최근 이벤트 수
+pub const SSE_HEARTBEAT_SECS: u64 = 15;
+pub const REALTIME_CHANNEL_TOPIC: &str = "realtime:duk";
+pub const REALTIME_HEARTBEAT_SECS: u64 = 25; // Supabase Realtime 은 60초 안에 heartbeat 가 없으면 연결 종료
+pub const REALTIME_RECONNECT_BASE_SECS: u64 = 1;
+pub const REALTIME_RECONNECT_MAX_SECS: u64 = 60;
+pub const WS_PING_INTERVAL_SECS: u64 = 30;
+pub const WS_SUBSCRIPTION_REFRESH_SECS: u64 = 60; // 연결 중 상품 구독 목록 재조회 주기
+
+// 상점 크롤러
+pub const CRAWLER_DEFAULT_USER_AGENT: &str = "DukBot/0.1";
+pub const CRAWLER_REQUEST_TIMEOUT_SECS: u64 = 20;
+pub const CRAWLER_DISCOUNT_VALID_DAYS: u32 = 7; // 자동 발견 할인 종료일 (크롤링할 때마다 연장)
+pub const CRAWLER_DEFAULT_CURRENCY: &str = "KRW";
+pub const CRAWLER_MAX_LISTING_PAGES: u32 = 50; // 목록 URL 하나에서 따라가는 최대 페이지 수
+pub const CRAWLER_DOMAIN_CONCURRENCY: usize = 2; // 상점 도메인별 동시 요청 수
+pub const CRAWLER_DOMAIN_DELAY_MS: u64 = 1000; // 상점 도메인별 요청 시작 간격
+pub const CRAWLER_MAX_CRAWL_DELAY_SECS: u64 = 60; // robots.txt Crawl-delay 상한
+pub const CRAWLER_RUN_ERROR_LIMIT: usize = 50; // crawl_runs.errors 에 남기는 최대 페이지 오류 수
 
 // 지원 언어
 pub const SUPPORTED_LANGUAGES: &[&str] = &["en", "ko", "ja", "zh"];
@@ -26,10 +83,14 @@ pub const DEFAULT_LANGUAGE: &str = "en";
 // 지원 국가
 pub const SUPPORTED_COUNTRIES: &[&str] = &["KR", "JP", "US", "CN"];
 pub const DEFAULT_COUNTRY: &str = "KR";
+pub const DEFAULT_CURRENCY: &str = "KRW";
 
 // JWT 관련
 pub const JWT_EXPIRY_HOURS: u64 = 24;
 pub const REFRESH_TOKEN_EXPIRY_DAYS: u64 = 30;
+pub const JWT_DEFAULT_AUDIENCE: &str = "authenticated";
+pub const JWKS_REFRESH_MIN_INTERVAL_SECS: u64 = 60; // 모르는 kid 로 인한 JWKS 재조회 최소 간격
+pub const ADMIN_ROLE: &str = "admin";
 
 // 캐시 관련
 pub const CACHE_TTL_SECONDS: u64 = 300; // 5분
@@ -105,4 +105,61 @@ pub fn log_realtime_event(event_type: &str, user_id: &str, data: Option<serde_js
         data = ?data,
         "🔴 Realtime event"
     );
-}
\ No newline at end of file
+}
+// HTTP 요청 span (TraceLayer) - 실시간 연결의 ?access_token= (JWT) 은 가려서 기록
+pub fn make_request_span<B>(request: &axum::http::Request<B>) -> tracing::Span {
+    tracing::debug_span!(
+        "request",
+        method = %request.method(),
+        uri = %redact_access_token(request.uri()),
+        version = ?request.version(),
+    )
+}
+
+// 쿼리의 access_token 값을 가린 URI (axum Query 와 같이 디코딩한 키로 비교해 access%5Ftoken 같은 표기도 가림)
+pub fn redact_access_token(uri: &axum::http::Uri) -> String {
+    let Some(query) = uri.query() else {
+        return uri.to_string();
+    };
+    let Ok(mut url) = reqwest::Url::parse(&format!("http://localhost/?{}", query)) else {
+        return uri.path().to_string();
+    };
+    if !url.query_pairs().any(|(key, _)| key == "access_token") {
+        return uri.to_string();
+    }
+
+    let pairs: Vec<(String, String)> = url.query_pairs()
+        .map(|(key, value)| {
+            let value = if key == "access_token" { "[REDACTED]".to_string() } else { value.into_owned() };
+            (key.into_owned(), value)
+        })
+        .collect();
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+    format!("{}?{}", uri.path(), url.query().unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn redact(uri: &str) -> String {
+        redact_access_token(&uri.parse().unwrap())
+    }
+
+    #[test]
+    fn redacts_access_token_and_keeps_other_params() {
+        assert_eq!(
+            redact("/api/v1/notifications/stream?last_event_id=3&access_token=eyJhbGciOi.payload.sig"),
+            "/api/v1/notifications/stream?last_event_id=3&access_token=%5BREDACTED%5D",
+        );
+        assert_eq!(redact("/api/v1/ws?access%5Ftoken=eyJ.x.y"), "/api/v1/ws?access_token=%5BREDACTED%5D");
+        assert_eq!(redact("/api/v1/ws?access_token=a&access_token=b"), "/api/v1/ws?access_token=%5BREDACTED%5D&access_token=%5BREDACTED%5D");
+    }
+
+    #[test]
+    fn leaves_uris_without_access_token_unchanged() {
+        assert_eq!(redact("/api/v1/discounts/stream?product_id=5"), "/api/v1/discounts/stream?product_id=5");
+        assert_eq!(redact("/api/v1/discounts?search=a%20b"), "/api/v1/discounts?search=a%20b");
+        assert_eq!(redact("/health"), "/health");
+    }
+}
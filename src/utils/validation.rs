@@ -1,5 +1,14 @@
+use std::collections::HashMap;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, NaiveTime, Utc};
+
 use crate::utils::constants::*;
 use crate::error::AppError;
+use crate::domain::dto::pagenation::{Cursor, Sort, SortKey};
+use crate::domain::dto::request::{DiscountEmbed, DiscountPreset, PushSubscriptionRequest};
+use crate::domain::entities::notification::QuietHours;
 
 // 페이지네이션 검증
 pub fn validate_pagination(page: u32, limit: u32) -> Result<(u32, u32), AppError> {
@@ -31,19 +40,68 @@ pub fn validate_language(language: &str) -> Result<String, AppError> {
     }
 }
 
+// 요청 언어 결정 - 명시적 locale 파라미터 > Accept-Language 헤더 > 기본 언어
+pub fn resolve_locale(requested: Option<&str>, accept_language: Option<&str>) -> Result<String, AppError> {
+    if let Some(locale) = requested.filter(|l| !l.is_empty()) {
+        return validate_language(locale);
+    }
+
+    // "ko-KR,ko;q=0.9,en;q=0.8" 형식 - q 값이 높은 순으로 지원 언어 선택
+    let mut candidates: Vec<(&str, f32)> = accept_language
+        .unwrap_or("")
+        .split(',')
+        .filter_map(|part| {
+            let mut pieces = part.trim().split(';');
+            let tag = pieces.next()?.trim();
+            let primary = tag.split('-').next()?;
+            let quality = pieces
+                .find_map(|p| p.trim().strip_prefix("q="))
+                .and_then(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            Some((primary, quality))
+        })
+        .collect();
+    candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let locale = candidates
+        .into_iter()
+        .map(|(tag, _)| tag.to_lowercase())
+        .find(|tag| SUPPORTED_LANGUAGES.contains(&tag.as_str()))
+        .unwrap_or_else(|| DEFAULT_LANGUAGE.to_string());
+    Ok(locale)
+}
+
 // 국가 코드 검증
 pub fn validate_country(country: &str) -> Result<String, AppError> {
     if country.is_empty() {
         return Ok(DEFAULT_COUNTRY.to_string());
     }
     
-    if SUPPORTED_COUNTRIES.contains(&country) {
-        Ok(country.to_string())
+    let country = country.to_uppercase();
+    if SUPPORTED_COUNTRIES.contains(&country.as_str()) {
+        Ok(country)
     } else {
         Err(AppError::validation(format!("Unsupported country: {}. Supported: {:?}", country, SUPPORTED_COUNTRIES)))
     }
 }
 
+// 할인율 범위 검증 (0~100, min <= max)
+pub fn validate_discount_rate_range(min: Option<f64>, max: Option<f64>) -> Result<(), AppError> {
+    for rate in [min, max].into_iter().flatten() {
+        if !(0.0..=100.0).contains(&rate) {
+            return Err(AppError::validation(format!("Discount rate must be between 0 and 100: {}", rate)));
+        }
+    }
+
+    if let (Some(min), Some(max)) = (min, max)
+        && min > max
+    {
+        return Err(AppError::validation("min_discount_rate cannot exceed max_discount_rate".to_string()));
+    }
+
+    Ok(())
+}
+
 // 알림 타입 검증
 pub fn validate_notification_type(notification_type: &str) -> Result<String, AppError> {
     if NOTIFICATION_TYPES.contains(&notification_type) {
@@ -53,13 +111,23 @@ pub fn validate_notification_type(notification_type: &str) -> Result<String, App
     }
 }
 
-// 알림 설정 타입 검증
-pub fn validate_notification_setting_type(setting_type: &str) -> Result<String, AppError> {
-    if NOTIFICATION_SETTINGS_TYPES.contains(&setting_type) {
-        Ok(setting_type.to_string())
-    } else {
-        Err(AppError::validation(format!("Invalid notification setting type: {}. Supported: {:?}", setting_type, NOTIFICATION_SETTINGS_TYPES)))
+// 방해 금지 시간 검증 - 채널은 push/email/sms, 시각은 HH:MM
+pub fn validate_quiet_hours(quiet_hours: &HashMap<String, Option<QuietHours>>) -> Result<(), AppError> {
+    for (channel, window) in quiet_hours {
+        if !NOTIFICATION_DELIVERY_CHANNELS.contains(&channel.as_str()) {
+            return Err(AppError::validation(format!("Invalid quiet hours channel: {}. Supported: {:?}", channel, NOTIFICATION_DELIVERY_CHANNELS)));
+        }
+
+        if let Some(window) = window {
+            for time in [&window.start, &window.end] {
+                if NaiveTime::parse_from_str(time, "%H:%M").is_err() {
+                    return Err(AppError::validation(format!("Invalid quiet hours time: {} (expected HH:MM)", time)));
+                }
+            }
+        }
     }
+
+    Ok(())
 }
 
 // UUID 검증
@@ -86,19 +154,23 @@ pub fn validate_email(email: &str) -> Result<String, AppError> {
 
 // 검색 쿼리 검증
 pub fn validate_search_query(query: &str) -> Result<String, AppError> {
-    if query.is_empty() {
+    let query = query.trim();
+    // 한글 등 멀티바이트 문자를 고려해 바이트가 아닌 문자 수로 검사
+    let length = query.chars().count();
+
+    if length == 0 {
         return Err(AppError::validation("Search query cannot be empty".to_string()));
     }
     
-    if query.len() < 2 {
+    if length < 2 {
         return Err(AppError::validation("Search query must be at least 2 characters".to_string()));
     }
     
-    if query.len() > 100 {
+    if length > 100 {
         return Err(AppError::validation("Search query cannot exceed 100 characters".to_string()));
     }
     
-    Ok(query.trim().to_string())
+    Ok(query.to_string())
 }
 
 // 정렬 옵션 검증
@@ -124,4 +196,101 @@ pub fn validate_sort_option(sort: &str, allowed_fields: &[&str]) -> Result<(Stri
     }
     
     Ok((field.to_string(), direction.to_string()))
-}
\ No newline at end of file
+}
+
+// 다중 정렬 검증 - "discount_rate:desc,name:asc" 형식, 엔티티별 허용 필드만 가능
+pub fn validate_sort(sort: Option<&str>, allowed_fields: &[&str]) -> Result<Sort, AppError> {
+    let sort = sort.map(str::trim).filter(|s| !s.is_empty()).unwrap_or(DEFAULT_SORT);
+
+    let parts: Vec<&str> = sort.split(',').map(str::trim).collect();
+    if parts.len() > MAX_SORT_KEYS {
+        return Err(AppError::validation(format!("At most {} sort keys are allowed", MAX_SORT_KEYS)));
+    }
+
+    let mut keys: Vec<SortKey> = Vec::with_capacity(parts.len());
+    for part in parts {
+        if part.is_empty() {
+            return Err(AppError::validation("Sort key cannot be empty".to_string()));
+        }
+
+        let (field, direction) = validate_sort_option(part, allowed_fields)?;
+        if keys.iter().any(|key| key.field == field) {
+            return Err(AppError::validation(format!("Duplicate sort field: {}", field)));
+        }
+        keys.push(SortKey { field, direction });
+    }
+
+    Ok(Sort(keys))
+}
+
+// 키셋 커서 검증 - 빈 값은 첫 페이지
+pub fn validate_cursor(cursor: &str) -> Result<Option<Cursor>, AppError> {
+    if cursor.is_empty() {
+        return Ok(None);
+    }
+
+    Cursor::decode(cursor)
+        .map(Some)
+        .ok_or_else(|| AppError::validation("Invalid cursor".to_string()))
+}
+
+// 통화 코드 검증 (ISO 4217 3자리)
+pub fn validate_currency(currency: &str) -> Result<String, AppError> {
+    let currency = currency.trim().to_uppercase();
+    if currency.len() == 3 && currency.chars().all(|c| c.is_ascii_alphabetic()) {
+        Ok(currency)
+    } else {
+        Err(AppError::validation(format!("Invalid currency code: {}", currency)))
+    }
+}
+
+// 기간 필터 검증 (start <= end)
+pub fn validate_date_range(start: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>) -> Result<(), AppError> {
+    if let (Some(start), Some(end)) = (start, end)
+        && start > end
+    {
+        return Err(AppError::validation("start_date cannot be after end_date".to_string()));
+    }
+
+    Ok(())
+}
+
+// 할인 목록 프리셋 검증
+pub fn validate_discount_preset(preset: &str) -> Result<DiscountPreset, AppError> {
+    DiscountPreset::parse(preset.trim())
+        .ok_or_else(|| AppError::validation(format!("Invalid preset: {}. Allowed: ending_soon, newly_started", preset)))
+}
+
+// 임베딩 관계 검증 - "product,shop,brand" 형식
+pub fn validate_discount_embed(embed: &str) -> Result<DiscountEmbed, AppError> {
+    let mut result = DiscountEmbed::default();
+    for name in embed.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+        match name {
+            "product" => result.product = true,
+            "shop" => result.shop = true,
+            "brand" => result.brand = true,
+            other => {
+                return Err(AppError::validation(format!("Invalid embed: {}. Allowed: {:?}", other, DISCOUNT_EMBEDS)));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+// Web Push 구독 검증 - https endpoint, p256dh는 65바이트 비압축 P-256 공개키, auth는 16바이트
+pub fn validate_push_subscription(request: &PushSubscriptionRequest) -> Result<(), AppError> {
+    if !request.endpoint.starts_with("https://") {
+        return Err(AppError::validation("Push endpoint must be an https URL"));
+    }
+
+    let decoded_len = |value: &str| URL_SAFE_NO_PAD.decode(value.trim_end_matches('=')).map(|bytes| bytes.len()).ok();
+    if decoded_len(&request.keys.p256dh) != Some(65) {
+        return Err(AppError::validation("Invalid p256dh key"));
+    }
+    if decoded_len(&request.keys.auth) != Some(16) {
+        return Err(AppError::validation("Invalid auth secret"));
+    }
+
+    Ok(())
+}